@@ -33,7 +33,7 @@ async fn main() {
     );
     println!("AccountSet transaction before signing: {:?}", account_set);
     // Sign and submit the transaction
-    sign_and_submit(&mut account_set, &client, &wallet, true, true)
+    sign_and_submit(&mut account_set, &client, &wallet, true, true, None)
         .await
         .unwrap();
     println!("AccountSet transaction after signing: {:?}", account_set);
@@ -122,6 +122,63 @@ pub(crate) mod lgr_obj_flags {
     }
 }
 
+/// A `mod` to be used on `UInt64` ledger fields, e.g. `IndexNext`. rippled represents them as
+/// hex strings in JSON, which this accepts along with plain numbers. They are serialized back as
+/// 16-digit hex strings, as the binary codec decodes them.
+pub(crate) mod uint64_hex {
+    use alloc::format;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use serde_json::Value;
+
+    pub fn serialize<S>(value: &u64, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_str(&format!("{:016X}", value))
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<u64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Value::deserialize(d)? {
+            Value::String(hex) => u64::from_str_radix(&hex, 16).map_err(de::Error::custom),
+            Value::Number(number) => number.as_u64().ok_or_else(|| {
+                de::Error::custom(format!("{} is not a valid UInt64 value", number))
+            }),
+            value => Err(de::Error::custom(format!(
+                "expected a hex string for a UInt64 field, found {}",
+                value
+            ))),
+        }
+    }
+
+    /// The same for `Option<u64>` fields.
+    pub(crate) mod option {
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S>(value: &Option<u64>, s: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match value {
+                Some(value) => super::serialize(value, s),
+                None => s.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<Option<u64>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            #[derive(Deserialize)]
+            struct Helper(#[serde(with = "super")] u64);
+
+            Ok(Option::<Helper>::deserialize(d)?.map(|Helper(value)| value))
+        }
+    }
+}
+
 /// A macro to tag a struct externally. With `serde` attributes, unfortunately it is not possible to
 /// serialize a struct to json with its name as `key` and its fields as `value`. Example:
 /// `{"Example":{"Field1":"hello","Field2":"world"}}`
@@ -129,6 +186,9 @@ pub(crate) mod lgr_obj_flags {
 /// Several models need to be serialized in that format. This macro uses a helper to serialize and
 /// deserialize to/from that format.
 ///
+/// The model name is deserialized as a `Cow` so that it does not have to be borrowed from the
+/// input, e.g. when deserializing from a `serde_json::Value`.
+///
 /// Resource: https://github.com/serde-rs/serde/issues/554#issuecomment-249211775
 // TODO: Find a way to `#[skip_serializing_none]`
 #[macro_export]
@@ -192,7 +252,7 @@ macro_rules! serde_with_tag {
                     )*
                 }
 
-                let hash_map: $crate::_serde::HashMap<&$lt str, Helper<$lt>> = $crate::_serde::HashMap::deserialize(deserializer)?;
+                let hash_map: $crate::_serde::HashMap<::alloc::borrow::Cow<'de, str>, Helper<$lt>> = $crate::_serde::HashMap::deserialize(deserializer)?;
                 let helper_result = hash_map.get(stringify!($name));
 
                 match helper_result {
@@ -269,7 +329,7 @@ macro_rules! serde_with_tag {
                     )*
                 }
 
-                let hash_map: $crate::_serde::HashMap<&'de str, Helper> = $crate::_serde::HashMap::deserialize(deserializer)?;
+                let hash_map: $crate::_serde::HashMap<::alloc::borrow::Cow<'de, str>, Helper> = $crate::_serde::HashMap::deserialize(deserializer)?;
                 let helper_result = hash_map.get(stringify!($name));
 
                 match helper_result {
@@ -1,21 +1,47 @@
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use bigdecimal::BigDecimal;
 use embassy_futures::block_on;
+use hashbrown::HashMap;
+use serde::de::DeserializeOwned;
 
 use crate::{
     asynch::{
         account::{
+            can_delete_account as async_can_delete_account,
             does_account_exist as async_does_account_exist,
+            get_account_objects_typed as async_get_account_objects_typed,
             get_account_root as async_get_account_root,
+            get_account_transactions_since as async_get_account_transactions_since,
             get_latest_transaction as async_get_latest_transaction,
             get_next_valid_seq_number as async_get_next_valid_seq_number,
+            get_required_reserve as async_get_required_reserve,
+            get_signer_list as async_get_signer_list,
+            get_trustline_balances as async_get_trustline_balances,
             get_xrp_balance as async_get_xrp_balance,
+            get_xrp_balance_drops as async_get_xrp_balance_drops,
+            is_deposit_authorized as async_is_deposit_authorized,
         },
         clients::XRPLClient,
         exceptions::XRPLHelperResult,
     },
-    models::{ledger::objects::AccountRoot, results::account_tx::AccountTx, XRPAmount},
+    models::{
+        ledger::objects::{signer_list::SignerList, AccountRoot},
+        requests::account_objects::AccountObjectType,
+        results::account_tx::{AccountTx, AccountTxEntry},
+        XRPAmount,
+    },
 };
 
+pub fn can_delete_account<'a, C>(
+    address: Cow<'a, str>,
+    client: &C,
+) -> XRPLHelperResult<Result<(), Vec<String>>>
+where
+    C: XRPLClient,
+{
+    block_on(async_can_delete_account(address, client))
+}
+
 pub fn does_account_exist<C>(
     address: Cow<'_, str>,
     client: &C,
@@ -53,6 +79,17 @@ where
     block_on(async_get_xrp_balance(address, client, ledger_index))
 }
 
+pub fn get_xrp_balance_drops<'a, C>(
+    address: Cow<'a, str>,
+    client: &C,
+    ledger_index: Option<Cow<'a, str>>,
+) -> XRPLHelperResult<u64>
+where
+    C: XRPLClient,
+{
+    block_on(async_get_xrp_balance_drops(address, client, ledger_index))
+}
+
 pub fn get_account_root<'a: 'b, 'b, C>(
     address: Cow<'a, str>,
     client: &C,
@@ -73,3 +110,84 @@ where
 {
     block_on(async_get_latest_transaction(address, client))
 }
+
+pub fn get_account_transactions_since<'a, C>(
+    address: Cow<'a, str>,
+    client: &C,
+    min_ledger: u32,
+    forward: bool,
+) -> XRPLHelperResult<Vec<AccountTxEntry<'static>>>
+where
+    C: XRPLClient,
+{
+    block_on(async_get_account_transactions_since(
+        address, client, min_ledger, forward,
+    ))
+}
+
+pub fn get_required_reserve<'a, C>(
+    address: Cow<'a, str>,
+    client: &C,
+) -> XRPLHelperResult<XRPAmount<'static>>
+where
+    C: XRPLClient,
+{
+    block_on(async_get_required_reserve(address, client))
+}
+
+pub fn get_signer_list<'a: 'b, 'b, C>(
+    address: Cow<'a, str>,
+    client: &C,
+    ledger_index: Option<Cow<'a, str>>,
+) -> XRPLHelperResult<Option<SignerList<'b>>>
+where
+    C: XRPLClient,
+{
+    block_on(async_get_signer_list(address, client, ledger_index))
+}
+
+pub fn get_account_objects_typed<'a, T, C>(
+    address: Cow<'a, str>,
+    client: &C,
+    object_type: AccountObjectType,
+    ledger_index: Option<Cow<'a, str>>,
+) -> XRPLHelperResult<Vec<T>>
+where
+    T: DeserializeOwned,
+    C: XRPLClient,
+{
+    block_on(async_get_account_objects_typed(
+        address,
+        client,
+        object_type,
+        ledger_index,
+    ))
+}
+
+pub fn get_trustline_balances<'a, C>(
+    address: Cow<'a, str>,
+    client: &C,
+    ledger_index: Option<Cow<'a, str>>,
+) -> XRPLHelperResult<HashMap<String, BigDecimal>>
+where
+    C: XRPLClient,
+{
+    block_on(async_get_trustline_balances(address, client, ledger_index))
+}
+
+pub fn is_deposit_authorized<'a, C>(
+    source: Cow<'a, str>,
+    destination: Cow<'a, str>,
+    client: &C,
+    ledger_index: Option<Cow<'a, str>>,
+) -> XRPLHelperResult<bool>
+where
+    C: XRPLClient,
+{
+    block_on(async_is_deposit_authorized(
+        source,
+        destination,
+        client,
+        ledger_index,
+    ))
+}
@@ -1,16 +1,38 @@
-use alloc::borrow::Cow;
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bigdecimal::BigDecimal;
+use core::str::FromStr;
+use hashbrown::HashMap;
+use serde::de::DeserializeOwned;
 
 use crate::{
     core::addresscodec::{is_valid_xaddress, xaddress_to_classic_address},
     models::{
-        ledger::objects::AccountRoot,
-        requests::{account_info::AccountInfo, account_tx::AccountTx},
-        results::{self},
-        XRPAmount,
+        ledger::objects::{signer_list::SignerList, AccountRoot},
+        requests::{
+            account_info::AccountInfo,
+            account_lines::AccountLines,
+            account_objects::{AccountObjectType, AccountObjects},
+            account_tx::AccountTx,
+            deposit_authorize::DepositAuthorized,
+            server_state::ServerState,
+        },
+        results::{self, account_tx::AccountTxEntry},
+        XRPAmount, XRPLModelException,
     },
 };
 
-use super::{clients::XRPLAsyncClient, exceptions::XRPLHelperResult};
+use super::{
+    clients::XRPLAsyncClient, exceptions::XRPLHelperResult, ledger::get_latest_open_ledger_sequence,
+};
+
+/// The maximum number of objects an account may own and still be
+/// deleted, as deleting it removes them in the same transaction.
+const MAX_DELETABLE_OWNER_COUNT: u32 = 1000;
 
 pub async fn does_account_exist<C>(
     address: Cow<'_, str>,
@@ -52,6 +74,24 @@ where
     }
 }
 
+/// Like [`get_xrp_balance`], but returns the balance in drops as an
+/// integer. Errors if the balance does not fit into a `u64`.
+pub async fn get_xrp_balance_drops<'a, C>(
+    address: Cow<'a, str>,
+    client: &C,
+    ledger_index: Option<Cow<'a, str>>,
+) -> XRPLHelperResult<u64>
+where
+    C: XRPLAsyncClient,
+{
+    let balance = get_xrp_balance(address, client, ledger_index).await?;
+
+    Ok(balance
+        .0
+        .parse::<u64>()
+        .map_err(XRPLModelException::ParseIntError)?)
+}
+
 pub async fn get_account_root<'a: 'b, 'b, C>(
     address: Cow<'a, str>,
     client: &C,
@@ -81,6 +121,220 @@ where
         .account_data)
 }
 
+/// Returns the signer list of an account, or `None` if the account
+/// cannot be multi-signed. Its entries and quorum tell which signatures
+/// a valid multi-signed transaction needs.
+pub async fn get_signer_list<'a: 'b, 'b, C>(
+    mut address: Cow<'a, str>,
+    client: &C,
+    ledger_index: Option<Cow<'a, str>>,
+) -> XRPLHelperResult<Option<SignerList<'b>>>
+where
+    C: XRPLAsyncClient,
+{
+    if is_valid_xaddress(&address) {
+        address = xaddress_to_classic_address(&address)?.0.into();
+    }
+    let request = AccountInfo::new(
+        None,
+        address,
+        None,
+        Some(ledger_index.unwrap_or("validated".into())),
+        None,
+        None,
+        Some(true),
+    );
+    let response = client.request(request.into()).await?;
+    let account_info = response.try_into_result::<results::account_info::AccountInfo<'_>>()?;
+
+    Ok(account_info
+        .signer_lists
+        .and_then(|signer_lists| signer_lists.into_iter().next()))
+}
+
+/// Checks whether an account could be deleted with an `AccountDelete`
+/// transaction right now. Returns `Ok(())` if nothing prevents it,
+/// otherwise the reasons it would fail:
+///
+/// * the account owns objects that block deletion, like trust lines,
+///   escrows or payment channels,
+/// * the account owns more than 1000 objects, or
+/// * its sequence number plus 256 is not less than the current ledger
+///   index.
+///
+/// Only the first page of blocking objects is inspected.
+pub async fn can_delete_account<'a, C>(
+    mut address: Cow<'a, str>,
+    client: &C,
+) -> XRPLHelperResult<Result<(), Vec<String>>>
+where
+    C: XRPLAsyncClient,
+{
+    if is_valid_xaddress(&address) {
+        address = xaddress_to_classic_address(&address)?.0.into();
+    }
+    let account_root = get_account_root(address.clone(), client, "validated".into()).await?;
+    let request = AccountObjects::new(
+        None,
+        address,
+        None,
+        Some("validated".into()),
+        None,
+        Some(true),
+        None,
+        None,
+    );
+    let response = client.request(request.into()).await?;
+    let blockers = response.try_into_result::<results::account_objects::AccountObjects<'_>>()?;
+    let current_ledger = get_latest_open_ledger_sequence(client).await?;
+
+    let mut reasons = Vec::new();
+    for object in blockers.account_objects.iter() {
+        let ledger_entry_type = object["LedgerEntryType"].as_str().unwrap_or("unknown");
+        let index = object["index"].as_str().unwrap_or_default();
+        reasons.push(format!(
+            "The account owns a {} ({}) that blocks deletion",
+            ledger_entry_type, index
+        ));
+    }
+    if account_root.owner_count > MAX_DELETABLE_OWNER_COUNT {
+        reasons.push(format!(
+            "The account owns {} objects, more than the {} that can be deleted with it",
+            account_root.owner_count, MAX_DELETABLE_OWNER_COUNT
+        ));
+    }
+    if account_root.sequence as u64 + 256 >= current_ledger as u64 {
+        reasons.push(format!(
+            "The account sequence {} plus 256 must be less than the current ledger index {}",
+            account_root.sequence, current_ledger
+        ));
+    }
+    if reasons.is_empty() {
+        Ok(Ok(()))
+    } else {
+        Ok(Err(reasons))
+    }
+}
+
+/// Returns the XRP an account must hold in reserve for itself and the
+/// objects it owns, which wallets usually show as "reserved XRP".
+pub async fn get_required_reserve<'a, C>(
+    address: Cow<'a, str>,
+    client: &C,
+) -> XRPLHelperResult<XRPAmount<'static>>
+where
+    C: XRPLAsyncClient,
+{
+    let account_root = get_account_root(address, client, "validated".into()).await?;
+    let response = client.request(ServerState::new(None).into()).await?;
+    let server_state = response.try_into_result::<results::server_state::ServerState<'_>>()?;
+
+    Ok(server_state.required_reserve(account_root.owner_count)?)
+}
+
+/// Returns the ledger objects of the given type owned by an account,
+/// deserialized into `T`, e.g. [`Offer`] for [`AccountObjectType::Offer`]
+/// or [`RippleState`] for [`AccountObjectType::State`].
+///
+/// Only the first page of objects is returned.
+///
+/// [`Offer`]: crate::models::ledger::objects::Offer
+/// [`RippleState`]: crate::models::ledger::objects::RippleState
+pub async fn get_account_objects_typed<'a, T, C>(
+    mut address: Cow<'a, str>,
+    client: &C,
+    object_type: AccountObjectType,
+    ledger_index: Option<Cow<'a, str>>,
+) -> XRPLHelperResult<Vec<T>>
+where
+    T: DeserializeOwned,
+    C: XRPLAsyncClient,
+{
+    if is_valid_xaddress(&address) {
+        address = xaddress_to_classic_address(&address)?.0.into();
+    }
+    let request = AccountObjects::new(
+        None,
+        address,
+        None,
+        Some(ledger_index.unwrap_or("validated".into())),
+        Some(object_type),
+        None,
+        None,
+        None,
+    );
+    let response = client.request(request.into()).await?;
+    let account_objects =
+        response.try_into_result::<results::account_objects::AccountObjects<'_>>()?;
+
+    account_objects
+        .account_objects
+        .into_iter()
+        .map(|object| Ok(serde_json::from_value(object).map_err(XRPLModelException::from)?))
+        .collect()
+}
+
+/// Returns the net balance of each currency held on an account's trust
+/// lines, keyed by currency code and summed across all peers. Negative
+/// balances, which the account owes, reduce the total.
+///
+/// Only the first page of trust lines is considered.
+pub async fn get_trustline_balances<'a, C>(
+    mut address: Cow<'a, str>,
+    client: &C,
+    ledger_index: Option<Cow<'a, str>>,
+) -> XRPLHelperResult<HashMap<String, BigDecimal>>
+where
+    C: XRPLAsyncClient,
+{
+    if is_valid_xaddress(&address) {
+        address = xaddress_to_classic_address(&address)?.0.into();
+    }
+    let request = AccountLines::new(
+        None,
+        address,
+        None,
+        Some(ledger_index.unwrap_or("validated".into())),
+        None,
+        None,
+    );
+    let response = client.request(request.into()).await?;
+    let account_lines = response.try_into_result::<results::account_lines::AccountLines<'_>>()?;
+    let mut balances: HashMap<String, BigDecimal> = HashMap::new();
+    for line in account_lines.lines {
+        let balance = BigDecimal::from_str(&line.balance).map_err(XRPLModelException::from)?;
+        *balances.entry(line.currency.to_string()).or_default() += balance;
+    }
+
+    Ok(balances)
+}
+
+/// Returns whether `source` may send payments directly to `destination`.
+/// This is always true unless `destination` requires deposit
+/// authorization and has not preauthorized `source`.
+pub async fn is_deposit_authorized<'a, C>(
+    source: Cow<'a, str>,
+    destination: Cow<'a, str>,
+    client: &C,
+    ledger_index: Option<Cow<'a, str>>,
+) -> XRPLHelperResult<bool>
+where
+    C: XRPLAsyncClient,
+{
+    let request = DepositAuthorized::new(
+        None,
+        destination,
+        source,
+        None,
+        Some(ledger_index.unwrap_or("validated".into())),
+    );
+    let response = client.request(request.into()).await?;
+
+    Ok(response
+        .try_into_result::<results::deposit_authorized::DepositAuthorized<'_>>()?
+        .deposit_authorized)
+}
+
 pub async fn get_latest_transaction<'a: 'b, 'b, C>(
     mut address: Cow<'a, str>,
     client: &C,
@@ -107,3 +361,560 @@ where
 
     Ok(response.try_into_result::<results::account_tx::AccountTx<'_>>()?)
 }
+
+/// Returns every validated transaction of an account from `min_ledger`
+/// onwards, following the result markers across pages. With `forward`
+/// the oldest transactions come first, otherwise the newest.
+///
+/// Every page is requested with the same ledger range and ordering, as
+/// a marker is only valid with the parameters of the request that
+/// returned it.
+pub async fn get_account_transactions_since<'a, C>(
+    mut address: Cow<'a, str>,
+    client: &C,
+    min_ledger: u32,
+    forward: bool,
+) -> XRPLHelperResult<Vec<AccountTxEntry<'static>>>
+where
+    C: XRPLAsyncClient,
+{
+    if is_valid_xaddress(&address) {
+        address = xaddress_to_classic_address(&address)?.0.into();
+    }
+    let mut entries = Vec::new();
+    let mut marker = None;
+    loop {
+        let request = AccountTx::new(
+            None,
+            address.clone(),
+            None,
+            None,
+            None,
+            Some(forward),
+            Some(min_ledger),
+            None,
+            None,
+            marker.take(),
+        );
+        let response = client.request(request.into()).await?;
+        let page = response.try_into_result::<results::account_tx::AccountTx<'_>>()?;
+        entries.extend(page.entries()?);
+        match page.marker {
+            Some(next) if !next.is_null() => marker = Some(next),
+            _ => break,
+        }
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod test_get_xrp_balance_drops {
+    use alloc::borrow::Cow;
+    use bigdecimal::BigDecimal;
+    use serde_json::json;
+
+    use super::{get_xrp_balance, get_xrp_balance_drops};
+    use crate::{
+        asynch::{
+            clients::MockClient,
+            test_cases::{account_info_result, account_root, ACCOUNT},
+        },
+        models::requests::RequestMethod,
+    };
+
+    fn client(balance: &str) -> MockClient {
+        let client = MockClient::new();
+        let mut account_root = account_root(ACCOUNT);
+        account_root["Balance"] = json!(balance);
+        client.add_result(
+            RequestMethod::AccountInfo,
+            account_info_result(account_root),
+        );
+
+        client
+    }
+
+    #[tokio::test]
+    async fn test_get_xrp_balance_drops() {
+        let client = client("99999999999996310");
+        let balance = get_xrp_balance(ACCOUNT.into(), &client, None)
+            .await
+            .unwrap();
+        let drops = get_xrp_balance_drops(Cow::from(ACCOUNT), &client, None)
+            .await
+            .unwrap();
+
+        let balance: BigDecimal = balance.try_into().unwrap();
+
+        assert_eq!(drops, 99999999999996310);
+        assert_eq!(BigDecimal::from(drops), balance);
+    }
+
+    #[tokio::test]
+    async fn test_get_xrp_balance_drops_overflow() {
+        let client = client("18446744073709551616");
+
+        assert!(get_xrp_balance_drops(ACCOUNT.into(), &client, None)
+            .await
+            .is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_get_account_objects_typed {
+    use serde_json::{json, Value};
+
+    use super::get_account_objects_typed;
+    use crate::{
+        asynch::clients::MockClient,
+        models::{
+            ledger::objects::{Offer, RippleState},
+            requests::{account_objects::AccountObjectType, RequestMethod},
+        },
+    };
+
+    const ACCOUNT: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+
+    fn client(account_objects: Value) -> MockClient {
+        let client = MockClient::new();
+        client.add_result(
+            RequestMethod::AccountObjects,
+            serde_json::from_value(json!({
+                "account": ACCOUNT,
+                "account_objects": [account_objects],
+                "ledger_index": 14524914,
+                "validated": true
+            }))
+            .unwrap(),
+        );
+
+        client
+    }
+
+    #[tokio::test]
+    async fn test_offer_objects() {
+        let client = client(json!({
+            "Account": ACCOUNT,
+            "BookDirectory": "50AD0A9E54D2B381288D535EB724E4275FFBF41580D28A925D038D7EA4C68000",
+            "BookNode": "0000000000000000",
+            "Flags": 65536,
+            "LedgerEntryType": "Offer",
+            "OwnerNode": "0000000000000000",
+            "PreviousTxnID": "F0AB71E777B2DA54B86231E19B82554EF1F8211F92ECA473121C655BFC5329BF",
+            "PreviousTxnLgrSeq": 14524914,
+            "Sequence": 866,
+            "TakerGets": {
+                "currency": "XAG",
+                "issuer": "r9Dr5xwkeLegBeXq6ujinjSBLQzQ1zQGjH",
+                "value": "37"
+            },
+            "TakerPays": "79550000000",
+            "index": "96F76F27D8A327FC48753167EC04A46AA0E382E6F57F32FD12274144D00F1797"
+        }));
+        let offers: alloc::vec::Vec<Offer> =
+            get_account_objects_typed(ACCOUNT.into(), &client, AccountObjectType::Offer, None)
+                .await
+                .unwrap();
+
+        assert_eq!(offers.len(), 1);
+        assert_eq!(offers[0].sequence, 866);
+        let request = client.last_request(&RequestMethod::AccountObjects).unwrap();
+        assert_eq!(request["type"], "offer");
+    }
+
+    #[tokio::test]
+    async fn test_ripple_state_objects() {
+        let client = client(json!({
+            "Balance": {
+                "currency": "USD",
+                "issuer": "rrrrrrrrrrrrrrrrrrrrBZbvji",
+                "value": "-10"
+            },
+            "Flags": 393216,
+            "HighLimit": {
+                "currency": "USD",
+                "issuer": ACCOUNT,
+                "value": "110"
+            },
+            "HighNode": "0000000000000000",
+            "LedgerEntryType": "RippleState",
+            "LowLimit": {
+                "currency": "USD",
+                "issuer": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+                "value": "0"
+            },
+            "LowNode": "0000000000000000",
+            "PreviousTxnID": "E3FE6EA3D48F0C2B639448020EA4F03D4F4F8FFDB243A852A0F59177921B4879",
+            "PreviousTxnLgrSeq": 14090896,
+            "index": "9CA88CDEDFF9252B3DE183CE35B038F57282BC9503CDFA1923EF9A95DF0D6F7B"
+        }));
+        let lines: alloc::vec::Vec<RippleState> =
+            get_account_objects_typed(ACCOUNT.into(), &client, AccountObjectType::State, None)
+                .await
+                .unwrap();
+
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].previous_txn_lgr_seq, 14090896);
+        let request = client.last_request(&RequestMethod::AccountObjects).unwrap();
+        assert_eq!(request["type"], "state");
+    }
+}
+
+#[cfg(test)]
+mod test_get_trustline_balances {
+    use bigdecimal::BigDecimal;
+    use core::str::FromStr;
+    use serde_json::json;
+
+    use super::get_trustline_balances;
+    use crate::{asynch::clients::MockClient, models::requests::RequestMethod};
+
+    const ACCOUNT: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+
+    fn line(peer: &str, currency: &str, balance: &str) -> serde_json::Value {
+        json!({
+            "account": peer,
+            "balance": balance,
+            "currency": currency,
+            "limit": "1000",
+            "limit_peer": "0",
+            "quality_in": 0,
+            "quality_out": 0
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_trustline_balances() {
+        let client = MockClient::new();
+        client.add_result(
+            RequestMethod::AccountLines,
+            serde_json::from_value(json!({
+                "account": ACCOUNT,
+                "lines": [
+                    line("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW", "USD", "150.25"),
+                    line("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe", "USD", "-50.5"),
+                    line("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe", "EUR", "-10"),
+                ],
+                "ledger_index": 1000,
+                "validated": true
+            }))
+            .unwrap(),
+        );
+        let balances = get_trustline_balances(ACCOUNT.into(), &client, None)
+            .await
+            .unwrap();
+
+        assert_eq!(balances.len(), 2);
+        assert_eq!(balances["USD"], BigDecimal::from_str("99.75").unwrap());
+        assert_eq!(balances["EUR"], BigDecimal::from(-10));
+    }
+}
+
+#[cfg(test)]
+mod test_is_deposit_authorized {
+    use serde_json::json;
+
+    use super::is_deposit_authorized;
+    use crate::{asynch::clients::MockClient, models::requests::RequestMethod};
+
+    const SOURCE: &str = "rEhxGqkqPPSxQ3P25J66ft5TwpzV14k2de";
+    const DESTINATION: &str = "rsUiUMpnrgxQp24dJYZDhmV4bE3aBtQyt8";
+
+    fn deposit_authorized(authorized: bool) -> serde_json::Value {
+        json!({
+            "deposit_authorized": authorized,
+            "destination_account": DESTINATION,
+            "ledger_hash": "BD03A10653ED9D77DCA859B7A735BF0580088A8F287FA2C5403E0A19C58EF322",
+            "ledger_index": 8,
+            "source_account": SOURCE,
+            "status": "success",
+            "validated": true
+        })
+    }
+
+    #[tokio::test]
+    async fn test_is_deposit_authorized() {
+        let client = MockClient::new();
+        for authorized in [true, false] {
+            client.add_result(
+                RequestMethod::DepositAuthorized,
+                serde_json::from_value(deposit_authorized(authorized)).unwrap(),
+            );
+        }
+
+        let authorized = is_deposit_authorized(SOURCE.into(), DESTINATION.into(), &client, None)
+            .await
+            .unwrap();
+        assert!(authorized);
+        let authorized = is_deposit_authorized(SOURCE.into(), DESTINATION.into(), &client, None)
+            .await
+            .unwrap();
+        assert!(!authorized);
+        let request = client
+            .last_request(&RequestMethod::DepositAuthorized)
+            .unwrap();
+        assert_eq!(request["source_account"], SOURCE);
+        assert_eq!(request["destination_account"], DESTINATION);
+        assert_eq!(request["ledger_index"], "validated");
+    }
+}
+
+#[cfg(test)]
+mod test_get_account_transactions_since {
+    use serde_json::{json, Value};
+
+    use super::get_account_transactions_since;
+    use crate::{asynch::clients::MockClient, models::requests::RequestMethod};
+
+    const ACCOUNT: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+
+    fn entry(ledger_index: u32, sequence: u32) -> Value {
+        json!({
+            "hash": "1FC4D12C30CE206A6E23F46FAC62BD393BE9A79A1C452C6F3A04A13BC7A5E5A3",
+            "ledger_index": ledger_index,
+            "meta": { "TransactionIndex": 0, "TransactionResult": "tesSUCCESS" },
+            "tx_json": {
+                "Account": ACCOUNT,
+                "Fee": "12",
+                "Sequence": sequence,
+                "TransactionType": "AccountSet"
+            },
+            "validated": true
+        })
+    }
+
+    #[tokio::test]
+    async fn test_forward_pages() {
+        let client = MockClient::new();
+        client.add_result(
+            RequestMethod::AccountTx,
+            serde_json::from_value(json!({
+                "account": ACCOUNT,
+                "ledger_index_min": 100,
+                "ledger_index_max": 200,
+                "limit": 2,
+                "marker": { "ledger": 150, "seq": 0 },
+                "transactions": [entry(101, 1), entry(120, 2)],
+                "validated": true
+            }))
+            .unwrap(),
+        );
+        client.add_result(
+            RequestMethod::AccountTx,
+            serde_json::from_value(json!({
+                "account": ACCOUNT,
+                "ledger_index_min": 100,
+                "ledger_index_max": 200,
+                "limit": 2,
+                "transactions": [entry(150, 3), entry(199, 4)],
+                "validated": true
+            }))
+            .unwrap(),
+        );
+
+        let entries = get_account_transactions_since(ACCOUNT.into(), &client, 100, true)
+            .await
+            .unwrap();
+        let ledgers: alloc::vec::Vec<u32> = entries
+            .iter()
+            .map(|entry| entry.in_ledger().unwrap())
+            .collect();
+
+        assert_eq!(ledgers, [101, 120, 150, 199]);
+        assert!(ledgers.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(client.request_count(&RequestMethod::AccountTx), 2);
+        let request = client.last_request(&RequestMethod::AccountTx).unwrap();
+        assert_eq!(request["marker"], json!({ "ledger": 150, "seq": 0 }));
+        assert_eq!(request["ledger_index_min"], 100);
+        assert_eq!(request["forward"], true);
+    }
+}
+
+#[cfg(test)]
+mod test_get_signer_list {
+    use serde_json::{json, Value};
+
+    use super::get_signer_list;
+    use crate::{asynch::clients::MockClient, models::requests::RequestMethod};
+
+    const ACCOUNT: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+
+    fn client(signer_lists: Value) -> MockClient {
+        let client = MockClient::new();
+        client.add_result(
+            RequestMethod::AccountInfo,
+            serde_json::from_value(json!({
+                "account_data": {
+                    "Account": ACCOUNT,
+                    "Balance": "999999988",
+                    "Flags": 0,
+                    "LedgerEntryType": "AccountRoot",
+                    "OwnerCount": 1,
+                    "PreviousTxnID": "5904C0DC72C58A83AEFED2FFC5386356AA83FCA6A88C89D00646E51E687CDBE4",
+                    "PreviousTxnLgrSeq": 16061435,
+                    "Sequence": 3,
+                    "index": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8",
+                    "signer_lists": signer_lists
+                }
+            }))
+            .unwrap(),
+        );
+
+        client
+    }
+
+    #[tokio::test]
+    async fn test_get_signer_list() {
+        let client = client(json!([{
+            "Flags": 0,
+            "LedgerEntryType": "SignerList",
+            "OwnerNode": "0000000000000000",
+            "PreviousTxnID": "5904C0DC72C58A83AEFED2FFC5386356AA83FCA6A88C89D00646E51E687CDBE4",
+            "PreviousTxnLgrSeq": 16061435,
+            "SignerEntries": [
+                { "SignerEntry": { "Account": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW", "SignerWeight": 1 } }
+            ],
+            "SignerListID": 0,
+            "SignerQuorum": 1,
+            "index": "A9C28A28B85CD533217F5C0A0C7767666B093FA58A0F2D80026FCC4CD932DDC7"
+        }]));
+        let signer_list = get_signer_list(ACCOUNT.into(), &client, None)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(signer_list.signer_quorum, 1);
+        assert_eq!(signer_list.signer_entries.len(), 1);
+        let request = client.last_request(&RequestMethod::AccountInfo).unwrap();
+        assert_eq!(request["signer_lists"], true);
+    }
+
+    #[tokio::test]
+    async fn test_no_signer_list() {
+        let client = client(json!([]));
+
+        assert!(get_signer_list(ACCOUNT.into(), &client, None)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
+
+#[cfg(test)]
+mod test_can_delete_account {
+    use serde_json::{json, Value};
+
+    use super::can_delete_account;
+    use crate::{
+        asynch::{
+            clients::MockClient,
+            test_cases::{account_info_result, account_root, ledger, ledger_result, ACCOUNT},
+        },
+        models::requests::RequestMethod,
+    };
+
+    fn client(owner_count: u32, account_objects: Value) -> MockClient {
+        let client = MockClient::new();
+        let mut account_root = account_root(ACCOUNT);
+        account_root["OwnerCount"] = json!(owner_count);
+        client.add_result(
+            RequestMethod::AccountInfo,
+            account_info_result(account_root),
+        );
+        client.add_result(
+            RequestMethod::AccountObjects,
+            serde_json::from_value(json!({
+                "account": ACCOUNT,
+                "account_objects": account_objects,
+                "ledger_index": 1000,
+                "validated": true
+            }))
+            .unwrap(),
+        );
+        client.add_result(RequestMethod::Ledger, ledger_result(ledger(1001)));
+
+        client
+    }
+
+    #[tokio::test]
+    async fn test_blocked_by_trust_lines() {
+        let client = client(
+            1,
+            json!([{
+                "Balance": {
+                    "currency": "USD",
+                    "issuer": "rrrrrrrrrrrrrrrrrrrrBZbvji",
+                    "value": "0"
+                },
+                "Flags": 65536,
+                "HighLimit": {
+                    "currency": "USD",
+                    "issuer": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+                    "value": "0"
+                },
+                "HighNode": "0000000000000000",
+                "LedgerEntryType": "RippleState",
+                "LowLimit": {
+                    "currency": "USD",
+                    "issuer": ACCOUNT,
+                    "value": "100"
+                },
+                "LowNode": "0000000000000000",
+                "PreviousTxnID": "5CF9FA6D7C4B2B7E0E3E5E4A1B5E5C4E2E1A5E3A5B1C0E2E1A5E3A5B1C0E2E1A",
+                "PreviousTxnLgrSeq": 900,
+                "index": "9CA88CDEDFF9252B3DE183CE35B038F57282BC9503CDFA1923EF9A95DF0D6F7B"
+            }]),
+        );
+        let reasons = can_delete_account(ACCOUNT.into(), &client)
+            .await
+            .unwrap()
+            .unwrap_err();
+
+        assert_eq!(reasons.len(), 1);
+        assert!(reasons[0].contains("RippleState"));
+        let request = client.last_request(&RequestMethod::AccountObjects).unwrap();
+        assert_eq!(request["deletion_blockers_only"], true);
+    }
+
+    #[tokio::test]
+    async fn test_empty_account() {
+        let client = client(0, json!([]));
+
+        assert_eq!(
+            can_delete_account(ACCOUNT.into(), &client).await.unwrap(),
+            Ok(())
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_get_required_reserve {
+    use serde_json::json;
+
+    use super::get_required_reserve;
+    use crate::{
+        asynch::{
+            clients::MockClient,
+            test_cases::{account_info_result, account_root, server_state_result, ACCOUNT},
+        },
+        models::{requests::RequestMethod, XRPAmount},
+    };
+
+    #[tokio::test]
+    async fn test_get_required_reserve() {
+        let client = MockClient::new();
+        let mut account_root = account_root(ACCOUNT);
+        account_root["OwnerCount"] = json!(3);
+        client.add_result(
+            RequestMethod::AccountInfo,
+            account_info_result(account_root),
+        );
+        client.add_result(RequestMethod::ServerState, server_state_result());
+
+        assert_eq!(
+            get_required_reserve(ACCOUNT.into(), &client).await.unwrap(),
+            XRPAmount::from("1600000")
+        );
+    }
+}
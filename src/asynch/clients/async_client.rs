@@ -14,6 +14,9 @@ pub trait XRPLAsyncClient: XRPLClient {
     }
 
     async fn get_common_fields(&self) -> XRPLClientResult<CommonFields<'_>> {
+        if let Some(common_fields) = self.get_cached_common_fields() {
+            return Ok(common_fields);
+        }
         let server_state = self.request(ServerState::new(None).into()).await?;
         let state = server_state
             .try_into_result::<ServerStateResult<'_>>()?
@@ -22,6 +25,7 @@ pub trait XRPLAsyncClient: XRPLClient {
             network_id: state.network_id,
             build_version: Some(state.build_version),
         };
+        self.cache_common_fields(&common_fields);
 
         Ok(common_fields)
     }
@@ -5,7 +5,7 @@ use crate::models::{
 use alloc::borrow::Cow;
 use url::Url;
 
-use super::exceptions::XRPLClientResult;
+use super::{exceptions::XRPLClientResult, CommonFields};
 
 #[allow(async_fn_in_trait)]
 pub trait XRPLClient {
@@ -16,6 +16,15 @@ pub trait XRPLClient {
 
     fn get_host(&self) -> Url;
 
+    /// Returns the network's common fields if the client has a fresh
+    /// copy cached. Clients don't cache by default.
+    fn get_cached_common_fields(&self) -> Option<CommonFields<'static>> {
+        None
+    }
+
+    /// Stores freshly fetched common fields for clients that cache them.
+    fn cache_common_fields(&self, _common_fields: &CommonFields<'_>) {}
+
     fn set_request_id(&self, request: &mut XRPLRequest<'_>) {
         let common_fields = request.get_common_fields_mut();
         common_fields.id = match &common_fields.id {
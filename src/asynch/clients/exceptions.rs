@@ -29,6 +29,25 @@ pub enum XRPLClientException {
     XRPLJsonRpcError(#[from] XRPLJsonRpcException),
     #[error("URL parse error: {0}")]
     UrlParseError(#[from] url::ParseError),
+    #[error("The server responded with HTTP status {code}: {body_snippet}")]
+    HttpStatus {
+        code: u16,
+        /// The beginning of the response body.
+        body_snippet: alloc::string::String,
+    },
+    #[error("The server is rate limiting this client: {message}")]
+    RateLimited { message: alloc::string::String },
+    #[error("The server sent `{code}`: {message}")]
+    ServerWarning {
+        /// The `error` or `warning` code, e.g. `noPermission`.
+        code: alloc::string::String,
+        message: alloc::string::String,
+    },
+    #[error("The response does not fit into the receive buffer of {buffer_size} bytes")]
+    ResponseTooLarge { buffer_size: usize },
+    #[cfg(any(test, feature = "test-util"))]
+    #[error("No mock response registered for request method `{0}`")]
+    MockResponseMissing(alloc::string::String),
     #[cfg(feature = "std")]
     #[error("I/O error: {0}")]
     IoError(#[from] alloc::io::Error),
@@ -57,7 +76,25 @@ impl From<tokio_tungstenite::tungstenite::Error> for XRPLClientException {
 #[cfg(all(feature = "std", feature = "json-rpc"))]
 impl From<reqwest::Error> for XRPLClientException {
     fn from(error: reqwest::Error) -> Self {
-        XRPLClientException::XRPLJsonRpcError(XRPLJsonRpcException::ReqwestError(error))
+        XRPLClientException::XRPLJsonRpcError(XRPLJsonRpcException::from(error))
+    }
+}
+
+impl XRPLClientException {
+    /// Whether the request may succeed if it is sent again later, e.g.
+    /// after a `503 Service Unavailable`, a timeout or a failed connection.
+    /// Client errors such as `400 Bad Request` are not retryable, while
+    /// a websocket `slowDown` frame is.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            XRPLClientException::HttpStatus { code, .. } => *code == 429 || *code >= 500,
+            XRPLClientException::RateLimited { .. } => true,
+            #[cfg(all(feature = "std", feature = "json-rpc"))]
+            XRPLClientException::XRPLJsonRpcError(
+                XRPLJsonRpcException::ConnectError(_) | XRPLJsonRpcException::TimeoutError(_),
+            ) => true,
+            _ => false,
+        }
     }
 }
 
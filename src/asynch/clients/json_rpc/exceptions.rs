@@ -7,5 +7,29 @@ pub enum XRPLJsonRpcException {
     ReqwlessError(#[from] reqwless::Error),
     #[cfg(feature = "std")]
     #[error("Reqwest error: {0:?}")]
-    ReqwestError(#[from] reqwest::Error),
+    ReqwestError(reqwest::Error),
+    #[cfg(feature = "std")]
+    #[error("Failed to connect to the server: {0:?}")]
+    ConnectError(reqwest::Error),
+    #[cfg(feature = "std")]
+    #[error("The request timed out: {0:?}")]
+    TimeoutError(reqwest::Error),
+    #[cfg(feature = "std")]
+    #[error("Failed to decode the response body: {0:?}")]
+    DecodeError(reqwest::Error),
+}
+
+#[cfg(feature = "std")]
+impl From<reqwest::Error> for XRPLJsonRpcException {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_connect() {
+            XRPLJsonRpcException::ConnectError(error)
+        } else if error.is_timeout() {
+            XRPLJsonRpcException::TimeoutError(error)
+        } else if error.is_decode() || error.is_body() {
+            XRPLJsonRpcException::DecodeError(error)
+        } else {
+            XRPLJsonRpcException::ReqwestError(error)
+        }
+    }
 }
@@ -48,16 +48,44 @@ mod _std {
     use alloc::string::ToString;
 
     use super::*;
+    use crate::asynch::clients::{
+        exceptions::XRPLClientException, CommonFields, CommonFieldsCache,
+    };
+    use core::time::Duration;
     use reqwest::Client as HttpClient;
     use url::Url;
 
+    /// The number of characters of an unsuccessful response's body
+    /// kept in the error.
+    const BODY_SNIPPET_LENGTH: usize = 256;
+
+    fn http_status_error(code: u16, body: &str) -> XRPLClientException {
+        XRPLClientException::HttpStatus {
+            code,
+            body_snippet: body.chars().take(BODY_SNIPPET_LENGTH).collect(),
+        }
+    }
+
+    /// An async JSON-RPC client. It is `Send + Sync`, so it can be
+    /// shared across threads.
     pub struct AsyncJsonRpcClient {
         url: Url,
+        common_fields_cache: CommonFieldsCache,
     }
 
     impl AsyncJsonRpcClient {
         pub fn connect(url: Url) -> Self {
-            Self { url }
+            Self {
+                url,
+                common_fields_cache: CommonFieldsCache::default(),
+            }
+        }
+
+        /// Cache the network's common fields for `ttl` instead of
+        /// fetching them on every autofill.
+        pub fn with_common_fields_ttl(mut self, ttl: Duration) -> Self {
+            self.common_fields_cache = CommonFieldsCache::new(Some(ttl));
+            self
         }
     }
 
@@ -74,12 +102,16 @@ mod _std {
                 .send()
                 .await;
             match response {
-                Ok(response) => match response.text().await {
-                    Ok(response) => {
-                        Ok(serde_json::from_str::<XRPLResponse<'b>>(&response).unwrap())
+                Ok(response) => {
+                    let status = response.status();
+                    match response.text().await {
+                        Ok(body) if !status.is_success() => {
+                            Err(http_status_error(status.as_u16(), &body))
+                        }
+                        Ok(body) => Ok(serde_json::from_str::<XRPLResponse<'b>>(&body)?),
+                        Err(error) => Err(error.into()),
                     }
-                    Err(error) => Err(error.into()),
-                },
+                }
                 Err(error) => Err(error.into()),
             }
         }
@@ -87,6 +119,14 @@ mod _std {
         fn get_host(&self) -> Url {
             self.url.clone()
         }
+
+        fn get_cached_common_fields(&self) -> Option<CommonFields<'static>> {
+            self.common_fields_cache.get()
+        }
+
+        fn cache_common_fields(&self, common_fields: &CommonFields<'_>) {
+            self.common_fields_cache.set(common_fields)
+        }
     }
 
     #[cfg(feature = "helpers")]
@@ -106,10 +146,12 @@ mod _std {
                 .await;
             match response {
                 Ok(response) => {
-                    if response.status().is_success() {
+                    let status = response.status();
+                    if status.is_success() {
                         Ok(())
                     } else {
-                        todo!()
+                        let body = response.text().await.unwrap_or_default();
+                        Err(http_status_error(status.as_u16(), &body))
                     }
                 }
                 Err(error) => Err(error.into()),
@@ -120,9 +162,12 @@ mod _std {
 
 #[cfg(all(feature = "json-rpc", not(feature = "std")))]
 mod _no_std {
-    use crate::{asynch::clients::SingleExecutorMutex, models::requests::XRPLRequest};
     #[cfg(feature = "helpers")]
     use crate::{asynch::clients::XRPLFaucet, models::requests::FundFaucet};
+    use crate::{
+        asynch::clients::{parse_buffered_response, SingleExecutorMutex},
+        models::requests::XRPLRequest,
+    };
 
     use super::*;
     use alloc::sync::Arc;
@@ -191,7 +236,7 @@ mod _no_std {
                     {
                         Err(error.into())
                     } else {
-                        Ok(serde_json::from_slice::<XRPLResponse<'_>>(&rx_buffer)?)
+                        parse_buffered_response(&rx_buffer)
                     }
                 }
                 Err(error) => Err(error.into()),
@@ -254,3 +299,88 @@ mod _no_std {
 pub use _no_std::AsyncJsonRpcClient;
 #[cfg(all(feature = "json-rpc", feature = "std"))]
 pub use _std::AsyncJsonRpcClient;
+
+#[cfg(all(test, feature = "json-rpc", feature = "std"))]
+mod test {
+    use alloc::format;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+    use url::Url;
+
+    use super::AsyncJsonRpcClient;
+    use crate::{
+        asynch::clients::{exceptions::XRPLClientException, XRPLAsyncClient},
+        models::requests::ping::Ping,
+    };
+
+    /// Serves a single HTTP response with the given status line and body.
+    async fn serve_once(status: &'static str, body: &'static str) -> Url {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buffer = [0; 4096];
+            let _ = stream.read(&mut buffer).await.unwrap();
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+        });
+
+        Url::parse(&format!("http://{}", address)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_http_status_errors() {
+        let url = serve_once("503 Service Unavailable", "<html>busy</html>").await;
+        let error = AsyncJsonRpcClient::connect(url)
+            .request(Ping::new(None).into())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            XRPLClientException::HttpStatus { code: 503, .. }
+        ));
+        assert!(error.is_retryable());
+
+        let url = serve_once("400 Bad Request", "<html>bad</html>").await;
+        let error = AsyncJsonRpcClient::connect(url)
+            .request(Ping::new(None).into())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            XRPLClientException::HttpStatus { code: 400, .. }
+        ));
+        assert!(!error.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_http_status_error_body_snippet() {
+        let url = serve_once(
+            "500 Internal Server Error",
+            "<html><body>Internal Server Error</body></html>",
+        )
+        .await;
+        let error = AsyncJsonRpcClient::connect(url)
+            .request(Ping::new(None).into())
+            .await
+            .unwrap_err();
+
+        match error {
+            XRPLClientException::HttpStatus { code, body_snippet } => {
+                assert_eq!(code, 500);
+                assert_eq!(
+                    body_snippet,
+                    "<html><body>Internal Server Error</body></html>"
+                );
+            }
+            error => panic!("unexpected error: {:?}", error),
+        }
+    }
+}
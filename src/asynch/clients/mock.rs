@@ -0,0 +1,211 @@
+use alloc::{collections::VecDeque, string::ToString, vec::Vec};
+use core::cell::RefCell;
+#[cfg(feature = "std")]
+use core::time::Duration;
+use serde_json::Value;
+use url::Url;
+
+use crate::models::{
+    requests::{Request, RequestMethod, XRPLRequest},
+    results::{ResponseStatus, ResponseType, XRPLResponse, XRPLResult},
+};
+
+use super::{
+    client::XRPLClient,
+    exceptions::{XRPLClientException, XRPLClientResult},
+};
+#[cfg(feature = "std")]
+use super::{CommonFields, CommonFieldsCache};
+
+const MOCK_HOST: &str = "http://localhost:5005";
+
+/// An in-memory client that answers requests with canned responses,
+/// keyed by request method, so helpers can be tested offline.
+///
+/// Responses registered for the same method are returned in order.
+/// The last one keeps being returned once the others are used up.
+///
+/// Available with the `test-util` feature.
+#[derive(Debug, Default)]
+pub struct MockClient {
+    responses: RefCell<Vec<(RequestMethod, VecDeque<XRPLResponse<'static>>)>>,
+    requests: RefCell<Vec<(RequestMethod, Value)>>,
+    #[cfg(feature = "std")]
+    common_fields_cache: CommonFieldsCache,
+}
+
+impl MockClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cache the network's common fields for `ttl`, like the
+    /// network clients can.
+    #[cfg(feature = "std")]
+    pub fn with_common_fields_ttl(mut self, ttl: Duration) -> Self {
+        self.common_fields_cache = CommonFieldsCache::new(Some(ttl));
+        self
+    }
+
+    /// Queue a full response for requests with the given method.
+    pub fn add_response(&self, method: RequestMethod, response: XRPLResponse<'static>) {
+        let mut responses = self.responses.borrow_mut();
+        match responses.iter_mut().find(|(m, _)| *m == method) {
+            Some((_, queue)) => queue.push_back(response),
+            None => responses.push((method, VecDeque::from([response]))),
+        }
+    }
+
+    /// Queue a successful response carrying `result` for requests
+    /// with the given method.
+    pub fn add_result(&self, method: RequestMethod, result: XRPLResult<'static>) {
+        self.add_response(
+            method,
+            XRPLResponse {
+                id: None,
+                error: None,
+                error_code: None,
+                error_message: None,
+                forwarded: None,
+                request: None,
+                result: Some(result),
+                status: Some(ResponseStatus::Success),
+                r#type: Some(ResponseType::Response),
+                warning: None,
+                warnings: None,
+            },
+        );
+    }
+
+    /// The methods of all requests made so far, in order.
+    pub fn requests(&self) -> Vec<RequestMethod> {
+        self.requests
+            .borrow()
+            .iter()
+            .map(|(method, _)| method.clone())
+            .collect()
+    }
+
+    /// The number of requests made so far with the given method.
+    pub fn request_count(&self, method: &RequestMethod) -> usize {
+        self.requests
+            .borrow()
+            .iter()
+            .filter(|(m, _)| m == method)
+            .count()
+    }
+
+    /// The most recent request made with the given method, as JSON.
+    pub fn last_request(&self, method: &RequestMethod) -> Option<Value> {
+        self.requests
+            .borrow()
+            .iter()
+            .rev()
+            .find(|(m, _)| m == method)
+            .map(|(_, request)| request.clone())
+    }
+}
+
+impl XRPLClient for MockClient {
+    async fn request_impl<'a: 'b, 'b>(
+        &self,
+        request: XRPLRequest<'a>,
+    ) -> XRPLClientResult<XRPLResponse<'b>> {
+        let method = request.get_common_fields().command.clone();
+        self.requests
+            .borrow_mut()
+            .push((method.clone(), serde_json::to_value(&request)?));
+        let mut responses = self.responses.borrow_mut();
+        let queue = responses
+            .iter_mut()
+            .find(|(m, _)| *m == method)
+            .map(|(_, queue)| queue)
+            .ok_or_else(|| XRPLClientException::MockResponseMissing(method.to_string()))?;
+        let response = if queue.len() > 1 {
+            queue.pop_front()
+        } else {
+            queue.front().cloned()
+        };
+
+        response.ok_or_else(|| XRPLClientException::MockResponseMissing(method.to_string()))
+    }
+
+    fn get_host(&self) -> Url {
+        Url::parse(MOCK_HOST).unwrap()
+    }
+
+    #[cfg(feature = "std")]
+    fn get_cached_common_fields(&self) -> Option<CommonFields<'static>> {
+        self.common_fields_cache.get()
+    }
+
+    #[cfg(feature = "std")]
+    fn cache_common_fields(&self, common_fields: &CommonFields<'_>) {
+        self.common_fields_cache.set(common_fields)
+    }
+}
+
+#[cfg(all(test, feature = "std", feature = "helpers"))]
+mod test {
+    use serde_json::json;
+
+    use super::MockClient;
+    use crate::{
+        asynch::{account::get_next_valid_seq_number, clients::XRPLAsyncClient},
+        models::requests::{ping::Ping, RequestMethod},
+    };
+
+    const ACCOUNT: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+
+    fn account_info(sequence: u32) -> serde_json::Value {
+        json!({
+            "account_data": {
+                "Account": ACCOUNT,
+                "Balance": "1000000000",
+                "Flags": 0,
+                "LedgerEntryType": "AccountRoot",
+                "OwnerCount": 0,
+                "PreviousTxnID": "4294BEBE5B569A18C0A2702387C9B1E7146DC3A5850C1E87204951C6FDAA4C42",
+                "PreviousTxnLgrSeq": 3,
+                "Sequence": sequence,
+                "index": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8"
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_next_valid_seq_number() {
+        let client = MockClient::new();
+        client.add_result(
+            RequestMethod::AccountInfo,
+            serde_json::from_value(account_info(42)).unwrap(),
+        );
+        client.add_result(
+            RequestMethod::AccountInfo,
+            serde_json::from_value(account_info(43)).unwrap(),
+        );
+
+        let sequence = get_next_valid_seq_number(ACCOUNT.into(), &client, None)
+            .await
+            .unwrap();
+        assert_eq!(sequence, 42);
+        let sequence = get_next_valid_seq_number(ACCOUNT.into(), &client, None)
+            .await
+            .unwrap();
+        assert_eq!(sequence, 43);
+        // The last response is repeated once the queue is drained.
+        let sequence = get_next_valid_seq_number(ACCOUNT.into(), &client, None)
+            .await
+            .unwrap();
+        assert_eq!(sequence, 43);
+        assert_eq!(client.request_count(&RequestMethod::AccountInfo), 3);
+    }
+
+    #[tokio::test]
+    async fn test_missing_response() {
+        let client = MockClient::new();
+
+        assert!(client.request(Ping::new(None).into()).await.is_err());
+        assert_eq!(client.requests(), [RequestMethod::Ping]);
+    }
+}
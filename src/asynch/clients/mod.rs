@@ -3,13 +3,22 @@ pub mod client;
 pub mod exceptions;
 #[cfg(feature = "json-rpc")]
 mod json_rpc;
+#[cfg(any(test, feature = "test-util"))]
+mod mock;
 #[cfg(feature = "websocket")]
 mod websocket;
 
 use alloc::borrow::Cow;
+#[cfg(feature = "std")]
+use alloc::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
 use embassy_sync::blocking_mutex::raw::{CriticalSectionRawMutex, NoopRawMutex};
 use serde::{Deserialize, Serialize};
 
+use crate::models::results::XRPLResponse;
+
 #[cfg(feature = "helpers")]
 use exceptions::XRPLClientResult;
 #[cfg(feature = "helpers")]
@@ -19,10 +28,16 @@ pub use async_client::*;
 pub use client::*;
 #[cfg(feature = "json-rpc")]
 pub use json_rpc::*;
+#[cfg(any(test, feature = "test-util"))]
+pub use mock::*;
 #[cfg(feature = "websocket")]
 pub use websocket::*;
 
+/// A mutex that can be shared across threads and executors. Clients
+/// using it are `Send + Sync`.
 pub type MultiExecutorMutex = CriticalSectionRawMutex;
+/// A mutex for clients that stay on a single executor. Clients using
+/// it are not `Sync`, so they cannot be shared across threads.
 pub type SingleExecutorMutex = NoopRawMutex;
 
 const TEST_FAUCET_URL: &str = "https://faucet.altnet.rippletest.net/accounts";
@@ -67,3 +82,150 @@ pub struct CommonFields<'a> {
     pub build_version: Option<Cow<'a, str>>,
     pub network_id: Option<u32>,
 }
+
+impl<'a> CommonFields<'a> {
+    pub(crate) fn into_owned(self) -> CommonFields<'static> {
+        CommonFields {
+            build_version: self
+                .build_version
+                .map(|build_version| Cow::Owned(build_version.into_owned())),
+            network_id: self.network_id,
+        }
+    }
+}
+
+/// Parses a response read into a fixed size receive buffer. Unused
+/// space at the end of the buffer is expected to be zeroed.
+///
+/// A response that filled the whole buffer without forming a complete
+/// JSON document was truncated and yields
+/// [`ResponseTooLarge`](exceptions::XRPLClientException::ResponseTooLarge).
+pub(crate) fn parse_buffered_response<'a>(
+    buffer: &[u8],
+) -> exceptions::XRPLClientResult<XRPLResponse<'a>> {
+    let len = buffer
+        .iter()
+        .rposition(|byte| *byte != 0)
+        .map_or(0, |index| index + 1);
+    match serde_json::from_slice(&buffer[..len]) {
+        Ok(response) => Ok(response),
+        Err(error) if error.is_eof() && len == buffer.len() => {
+            Err(exceptions::XRPLClientException::ResponseTooLarge {
+                buffer_size: buffer.len(),
+            })
+        }
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Caches the common fields of a network for a limited time so repeated
+/// autofills don't each need a `server_state` round-trip.
+///
+/// Without a TTL nothing is cached.
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct CommonFieldsCache {
+    ttl: Option<Duration>,
+    entry: Mutex<Option<(Instant, CommonFields<'static>)>>,
+}
+
+#[cfg(feature = "std")]
+impl CommonFieldsCache {
+    pub fn new(ttl: Option<Duration>) -> Self {
+        Self {
+            ttl,
+            entry: Mutex::new(None),
+        }
+    }
+
+    /// Returns the cached common fields if they are younger than the TTL.
+    pub fn get(&self) -> Option<CommonFields<'static>> {
+        let ttl = self.ttl?;
+        let entry = self.entry.lock().ok()?;
+        match entry.as_ref() {
+            Some((cached_at, common_fields)) if cached_at.elapsed() < ttl => {
+                Some(common_fields.clone())
+            }
+            _ => None,
+        }
+    }
+
+    pub fn set(&self, common_fields: &CommonFields<'_>) {
+        if self.ttl.is_none() {
+            return;
+        }
+        if let Ok(mut entry) = self.entry.lock() {
+            *entry = Some((Instant::now(), common_fields.clone().into_owned()));
+        }
+    }
+
+    pub fn invalidate(&self) {
+        if let Ok(mut entry) = self.entry.lock() {
+            *entry = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_parse_buffered_response {
+    use super::{exceptions::XRPLClientException, parse_buffered_response};
+
+    const RESPONSE: &str = r#"{"id":"1","result":{},"status":"success","type":"response"}"#;
+
+    #[test]
+    fn test_response_fits() {
+        let mut buffer = [0; 128];
+        buffer[..RESPONSE.len()].copy_from_slice(RESPONSE.as_bytes());
+        let response = parse_buffered_response(&buffer).unwrap();
+
+        assert_eq!(response.id.as_deref(), Some("1"));
+    }
+
+    #[test]
+    fn test_response_too_large() {
+        let mut buffer = [0; 16];
+        buffer.copy_from_slice(&RESPONSE.as_bytes()[..16]);
+
+        assert!(matches!(
+            parse_buffered_response(&buffer),
+            Err(XRPLClientException::ResponseTooLarge { buffer_size: 16 })
+        ));
+    }
+
+    #[test]
+    fn test_incomplete_response_with_room_left() {
+        let mut buffer = [0; 32];
+        buffer[..16].copy_from_slice(&RESPONSE.as_bytes()[..16]);
+
+        assert!(matches!(
+            parse_buffered_response(&buffer),
+            Err(XRPLClientException::XRPLSerdeJsonError(_))
+        ));
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_send_sync {
+    #[cfg(feature = "websocket")]
+    use super::{AsyncWebSocketClient, MultiExecutorMutex, WebSocketClosed, WebSocketOpen};
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_clients_are_send_sync() {
+        assert_send_sync::<super::CommonFieldsCache>();
+        #[cfg(feature = "json-rpc")]
+        {
+            assert_send_sync::<super::AsyncJsonRpcClient>();
+            assert_send_sync::<crate::clients::json_rpc::JsonRpcClient>();
+        }
+        #[cfg(feature = "websocket")]
+        {
+            assert_send_sync::<AsyncWebSocketClient<MultiExecutorMutex, WebSocketClosed>>();
+            assert_send_sync::<AsyncWebSocketClient<MultiExecutorMutex, WebSocketOpen>>();
+            assert_send_sync::<
+                crate::clients::websocket::WebSocketClient<MultiExecutorMutex, WebSocketOpen>,
+            >();
+        }
+    }
+}
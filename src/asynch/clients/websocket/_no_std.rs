@@ -18,12 +18,15 @@ use super::{WebSocketClosed, WebSocketOpen};
 use crate::{
     asynch::clients::{
         client::XRPLClient as ClientTrait,
-        websocket::websocket_base::{MessageHandler, WebsocketBase},
+        websocket::websocket_base::{MessageBuffer, MessageHandler, WebsocketBase},
     },
     models::results::XRPLResponse,
 };
 use crate::{
-    asynch::clients::{exceptions::XRPLClientResult, SingleExecutorMutex},
+    asynch::clients::{
+        exceptions::{XRPLClientException, XRPLClientResult},
+        SingleExecutorMutex,
+    },
     models::requests::{Request, XRPLRequest},
 };
 
@@ -242,21 +245,20 @@ where
         let request_string = serde_json::to_string(&request)?;
         self.do_write(request_string.as_bytes()).await?;
         // wait for response
+        let mut rx_buffer = [0; BUF];
+        let mut partial = MessageBuffer::new();
         loop {
-            let mut rx_buffer = [0; 1024];
             match self.do_read(&mut rx_buffer).await {
                 Ok(u_size) => {
                     // If the buffer is empty, continue to the next iteration.
                     if u_size == 0 {
                         continue;
                     }
-                    let message_str = match core::str::from_utf8(&rx_buffer[..u_size]) {
-                        Ok(response_str) => response_str,
-                        Err(error) => return Err(XRPLWebSocketException::Utf8(error).into()),
-                    };
-                    websocket_base
-                        .handle_message(message_str.to_string())
-                        .await?;
+                    // Wait for the frames carrying the rest of the message.
+                    if !partial.push(&rx_buffer[..u_size]) {
+                        continue;
+                    }
+                    websocket_base.handle_message(partial.take()?).await?;
                     let message_opt = websocket_base
                         .try_recv_request(request_id.to_string())
                         .await?;
@@ -265,6 +267,10 @@ where
                         return Ok(response);
                     }
                 }
+                Err(
+                    XRPLWebSocketException::FrameTooLarge(_)
+                    | XRPLWebSocketException::RxBufferTooSmall(_),
+                ) => return Err(XRPLClientException::ResponseTooLarge { buffer_size: BUF }),
                 Err(error) => return Err(error.into()),
             }
         }
@@ -2,40 +2,61 @@ use super::exceptions::XRPLWebSocketException;
 use super::{WebSocketClosed, WebSocketOpen};
 use crate::asynch::clients::client::XRPLClient;
 use crate::asynch::clients::exceptions::{XRPLClientException, XRPLClientResult};
-use crate::asynch::clients::websocket::websocket_base::{MessageHandler, WebsocketBase};
-use crate::asynch::clients::SingleExecutorMutex;
+use crate::asynch::clients::websocket::websocket_base::{
+    MessageBuffer, MessageHandler, WebsocketBase,
+};
+use crate::asynch::clients::{CommonFields, CommonFieldsCache, SingleExecutorMutex};
 use crate::models::requests::{Request, XRPLRequest};
 use crate::models::results::XRPLResponse;
 
 use alloc::string::{String, ToString};
 use alloc::sync::Arc;
 use core::marker::PhantomData;
+use core::time::Duration;
 use core::{pin::Pin, task::Poll};
 use embassy_futures::block_on;
 use embassy_sync::blocking_mutex::raw::RawMutex;
 use embassy_sync::mutex::Mutex;
 use futures::{Sink, SinkExt, Stream, StreamExt};
+use tokio::io::{AsyncRead, AsyncWrite};
 use tokio::net::TcpStream;
 use tokio_tungstenite::{tungstenite, MaybeTlsStream, WebSocketStream};
 use url::Url;
 
+use tokio_tungstenite::client_async as tokio_tungstenite_client_async;
 use tokio_tungstenite::connect_async as tokio_tungstenite_connect_async;
 
-type TokioTungsteniteMaybeTlsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
-
-pub struct AsyncWebSocketClient<M = SingleExecutorMutex, Status = WebSocketClosed>
-where
+/// An async WebSocket client for the std runtime.
+///
+/// The client is `Send + Sync` when `M` is [`MultiExecutorMutex`], e.g.
+/// to share it between the handlers of a web server. With the default
+/// [`SingleExecutorMutex`] it must stay on one thread.
+///
+/// `S` is the transport the WebSocket runs over. [`open`] connects a
+/// TCP (or TLS) stream itself; [`open_with_stream`] takes any
+/// already established connection instead.
+///
+/// [`open`]: AsyncWebSocketClient::open
+/// [`open_with_stream`]: AsyncWebSocketClient::open_with_stream
+/// [`MultiExecutorMutex`]: crate::asynch::clients::MultiExecutorMutex
+pub struct AsyncWebSocketClient<
+    M = SingleExecutorMutex,
+    Status = WebSocketClosed,
+    S = MaybeTlsStream<TcpStream>,
+> where
     M: RawMutex,
 {
-    websocket: Arc<Mutex<M, TokioTungsteniteMaybeTlsStream>>,
+    websocket: Arc<Mutex<M, WebSocketStream<S>>>,
     websocket_base: Arc<Mutex<M, WebsocketBase<M>>>,
     uri: Url,
+    common_fields_cache: Arc<CommonFieldsCache>,
     status: PhantomData<Status>,
 }
 
-impl<M> Sink<String> for AsyncWebSocketClient<M, WebSocketOpen>
+impl<M, S> Sink<String> for AsyncWebSocketClient<M, WebSocketOpen, S>
 where
     M: RawMutex,
+    S: AsyncRead + AsyncWrite + Unpin,
 {
     type Error = XRPLClientException;
 
@@ -84,9 +105,10 @@ where
     }
 }
 
-impl<M> Stream for AsyncWebSocketClient<M, WebSocketOpen>
+impl<M, S> Stream for AsyncWebSocketClient<M, WebSocketOpen, S>
 where
     M: RawMutex,
+    S: AsyncRead + AsyncWrite + Unpin,
 {
     type Item = XRPLClientResult<String>;
 
@@ -137,23 +159,60 @@ where
             Ok((stream, _)) => stream,
             Err(error) => return Err(error.into()),
         };
-        Ok(AsyncWebSocketClient {
-            websocket: Arc::new(Mutex::new(stream)),
+        Ok(AsyncWebSocketClient::from_websocket(stream, uri))
+    }
+}
+
+impl<M, S> AsyncWebSocketClient<M, WebSocketClosed, S>
+where
+    M: RawMutex,
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    /// Open the WebSocket over an already established connection, e.g.
+    /// a tunnel or an in-memory stream. `uri` is only used for the
+    /// handshake and is not connected to.
+    pub async fn open_with_stream(
+        uri: Url,
+        stream: S,
+    ) -> XRPLClientResult<AsyncWebSocketClient<M, WebSocketOpen, S>> {
+        let websocket = match tokio_tungstenite_client_async(uri.as_str(), stream).await {
+            Ok((websocket, _)) => websocket,
+            Err(error) => return Err(error.into()),
+        };
+        Ok(AsyncWebSocketClient::from_websocket(websocket, uri))
+    }
+
+    fn from_websocket(
+        websocket: WebSocketStream<S>,
+        uri: Url,
+    ) -> AsyncWebSocketClient<M, WebSocketOpen, S> {
+        AsyncWebSocketClient {
+            websocket: Arc::new(Mutex::new(websocket)),
             websocket_base: Arc::new(Mutex::new(WebsocketBase::new())),
             uri,
+            common_fields_cache: Arc::new(CommonFieldsCache::default()),
             status: PhantomData::<WebSocketOpen>,
-        })
+        }
     }
 }
 
-impl<M> AsyncWebSocketClient<M, WebSocketOpen>
+impl<M, S> AsyncWebSocketClient<M, WebSocketOpen, S>
 where
     M: RawMutex,
+    S: AsyncRead + AsyncWrite + Unpin,
 {
+    /// Cache the network's common fields for `ttl` instead of
+    /// fetching them on every autofill.
+    pub fn with_common_fields_ttl(mut self, ttl: Duration) -> Self {
+        self.common_fields_cache = Arc::new(CommonFieldsCache::new(Some(ttl)));
+        self
+    }
+
     pub async fn close(&self) -> XRPLClientResult<()> {
         let mut websocket = self.websocket.lock().await;
         let mut websocket_base = self.websocket_base.lock().await;
         websocket_base.close();
+        self.common_fields_cache.invalidate();
         match websocket.close(None).await {
             Ok(()) => Ok(()),
             Err(error) => Err(error.into()),
@@ -161,7 +220,7 @@ where
     }
 }
 
-impl<M, Status> AsyncWebSocketClient<M, Status>
+impl<M, Status, S> AsyncWebSocketClient<M, Status, S>
 where
     M: RawMutex,
 {
@@ -170,9 +229,10 @@ where
     }
 }
 
-impl<M> MessageHandler for AsyncWebSocketClient<M, WebSocketOpen>
+impl<M, S> MessageHandler for AsyncWebSocketClient<M, WebSocketOpen, S>
 where
     M: RawMutex,
+    S: AsyncRead + AsyncWrite + Unpin,
 {
     async fn setup_request_future(&mut self, id: String) {
         let mut websocket_base = self.websocket_base.lock().await;
@@ -195,14 +255,23 @@ where
     }
 }
 
-impl<M> XRPLClient for AsyncWebSocketClient<M, WebSocketOpen>
+impl<M, S> XRPLClient for AsyncWebSocketClient<M, WebSocketOpen, S>
 where
     M: RawMutex,
+    S: AsyncRead + AsyncWrite + Unpin,
 {
     fn get_host(&self) -> Url {
         self.uri.clone()
     }
 
+    fn get_cached_common_fields(&self) -> Option<CommonFields<'static>> {
+        self.common_fields_cache.get()
+    }
+
+    fn cache_common_fields(&self, common_fields: &CommonFields<'_>) {
+        self.common_fields_cache.set(common_fields)
+    }
+
     async fn request_impl<'a: 'b, 'b>(
         &self,
         mut request: XRPLRequest<'a>,
@@ -227,34 +296,11 @@ where
             return Err(error.into());
         }
         // wait for response
+        let mut partial = MessageBuffer::new();
         loop {
-            let message = websocket.next().await;
-            match message {
-                Some(Ok(tungstenite::Message::Text(message))) => {
-                    websocket_base.handle_message(message).await?;
-                    let message_opt = websocket_base
-                        .try_recv_request(request_id.to_string())
-                        .await?;
-                    if let Some(message) = message_opt {
-                        let response = match serde_json::from_str(&message) {
-                            Ok(response) => response,
-                            Err(error) => return Err(error.into()),
-                        };
-                        return Ok(response);
-                    }
-                }
-                Some(Ok(tungstenite::Message::Binary(response))) => {
-                    let message = match String::from_utf8(response) {
-                        Ok(string) => string,
-                        Err(error) => {
-                            return Err(XRPLWebSocketException::Utf8(error.utf8_error()).into());
-                        }
-                    };
-                    match serde_json::from_str(&message) {
-                        Ok(response) => return Ok(response),
-                        Err(error) => return Err(error.into()),
-                    }
-                }
+            let frame = match websocket.next().await {
+                Some(Ok(tungstenite::Message::Text(message))) => message.into_bytes(),
+                Some(Ok(tungstenite::Message::Binary(response))) => response,
                 Some(Ok(tungstenite::Message::Close(_))) => {
                     return Err(XRPLWebSocketException::Disconnected.into());
                 }
@@ -263,7 +309,83 @@ where
                 }
                 Some(Err(error)) => return Err(error.into()),
                 None => continue,
+            };
+            // Wait for the frames carrying the rest of the message.
+            if !partial.push(&frame) {
+                continue;
+            }
+            websocket_base.handle_message(partial.take()?).await?;
+            let message_opt = websocket_base
+                .try_recv_request(request_id.to_string())
+                .await?;
+            if let Some(message) = message_opt {
+                let response = match serde_json::from_str(&message) {
+                    Ok(response) => response,
+                    Err(error) => return Err(error.into()),
+                };
+                return Ok(response);
             }
         }
     }
 }
+
+#[cfg(test)]
+mod test_open_with_stream {
+    use alloc::string::ToString;
+    use futures::{SinkExt, StreamExt};
+    use serde_json::{json, Value};
+    use tokio_tungstenite::{accept_async, tungstenite::Message};
+    use url::Url;
+
+    use super::AsyncWebSocketClient;
+    use crate::asynch::clients::{SingleExecutorMutex, WebSocketOpen, XRPLAsyncClient};
+    use crate::models::{requests::fee::Fee, results, XRPAmount};
+
+    #[tokio::test]
+    async fn test_request_over_duplex() {
+        let (client_io, server_io) = tokio::io::duplex(4096);
+        let server = tokio::spawn(async move {
+            let mut websocket = accept_async(server_io).await.unwrap();
+            let request = match websocket.next().await.unwrap().unwrap() {
+                Message::Text(text) => serde_json::from_str::<Value>(&text).unwrap(),
+                message => panic!("unexpected message: {:?}", message),
+            };
+            assert_eq!(request["command"], "fee");
+            let response = json!({
+                "id": request["id"],
+                "result": {
+                    "drops": {
+                        "base_fee": "10",
+                        "median_fee": "5000",
+                        "minimum_fee": "10",
+                        "open_ledger_fee": "10",
+                    },
+                },
+                "status": "success",
+                "type": "response",
+            });
+            websocket
+                .send(Message::Text(response.to_string()))
+                .await
+                .unwrap();
+        });
+
+        let client: AsyncWebSocketClient<SingleExecutorMutex, WebSocketOpen, _> =
+            AsyncWebSocketClient::open_with_stream(
+                Url::parse("ws://localhost:6006").unwrap(),
+                client_io,
+            )
+            .await
+            .unwrap();
+        assert!(client.is_open());
+
+        let fee = client
+            .request(Fee::new(None).into())
+            .await
+            .unwrap()
+            .try_into_result::<results::fee::Fee<'_>>()
+            .unwrap();
+        assert_eq!(fee.drops.median_fee, XRPAmount::from("5000"));
+        server.await.unwrap();
+    }
+}
@@ -1,9 +1,6 @@
 use crate::models::{requests::XRPLRequest, results::XRPLResponse};
-#[cfg(feature = "std")]
 use alloc::string::String;
 #[cfg(not(feature = "std"))]
-use alloc::string::ToString;
-#[cfg(not(feature = "std"))]
 use embedded_io_async::Error;
 #[cfg(not(feature = "std"))]
 use embedded_io_async::{Read as EmbeddedIoRead, Write as EmbeddedIoWrite};
@@ -11,7 +8,7 @@ use embedded_io_async::{Read as EmbeddedIoRead, Write as EmbeddedIoWrite};
 use futures::{Sink, SinkExt, Stream, StreamExt};
 
 mod websocket_base;
-use websocket_base::MessageHandler;
+use websocket_base::{MessageBuffer, MessageHandler};
 
 #[cfg(all(feature = "websocket", not(feature = "std")))]
 mod _no_std;
@@ -34,7 +31,7 @@ pub struct WebSocketClosed;
 pub trait XRPLAsyncWebsocketIO {
     async fn xrpl_send(&mut self, message: XRPLRequest<'_>) -> XRPLClientResult<()>;
 
-    async fn xrpl_receive(&mut self) -> XRPLClientResult<Option<XRPLResponse<'_>>>;
+    async fn xrpl_receive(&mut self) -> XRPLClientResult<Option<XRPLResponse<'static>>>;
 }
 
 #[cfg(not(feature = "std"))]
@@ -49,8 +46,9 @@ impl<T: EmbeddedIoRead + EmbeddedIoWrite + MessageHandler> XRPLAsyncWebsocketIO
         Ok(())
     }
 
-    async fn xrpl_receive(&mut self) -> XRPLClientResult<Option<XRPLResponse<'_>>> {
+    async fn xrpl_receive(&mut self) -> XRPLClientResult<Option<XRPLResponse<'static>>> {
         let mut buffer = [0; 1024];
+        let mut partial = MessageBuffer::new();
         loop {
             match self.read(&mut buffer).await {
                 Ok(u_size) => {
@@ -58,9 +56,11 @@ impl<T: EmbeddedIoRead + EmbeddedIoWrite + MessageHandler> XRPLAsyncWebsocketIO
                     if u_size == 0 {
                         continue;
                     }
-                    let response_str = core::str::from_utf8(&buffer[..u_size])
-                        .map_err(|e| XRPLWebSocketException::Utf8(e))?;
-                    self.handle_message(response_str.to_string()).await?;
+                    // Wait for the frames carrying the rest of the message.
+                    if !partial.push(&buffer[..u_size]) {
+                        continue;
+                    }
+                    self.handle_message(partial.take()?).await?;
                     let message = self.pop_message().await;
 
                     return Ok(serde_json::from_str(&message)?);
@@ -87,16 +87,146 @@ where
         self.send(message).await
     }
 
-    async fn xrpl_receive(&mut self) -> XRPLClientResult<Option<XRPLResponse<'_>>> {
-        match self.next().await {
-            Some(Ok(item)) => {
-                self.handle_message(item).await?;
-                let message = self.pop_message().await;
+    async fn xrpl_receive(&mut self) -> XRPLClientResult<Option<XRPLResponse<'static>>> {
+        let mut partial = MessageBuffer::new();
+        loop {
+            match self.next().await {
+                Some(Ok(item)) => {
+                    // Wait for the frames carrying the rest of the message.
+                    if !partial.push(item.as_bytes()) {
+                        continue;
+                    }
+                    self.handle_message(partial.take()?).await?;
+                    let message = self.pop_message().await;
+
+                    return Ok(serde_json::from_str(&message)?);
+                }
+                Some(Err(error)) => return Err(error),
+                None => return Ok(None),
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_xrpl_receive {
+    use alloc::{collections::VecDeque, string::String};
+    use core::{
+        pin::Pin,
+        task::{Context, Poll},
+    };
+    use futures::{Sink, Stream};
+
+    use super::{
+        websocket_base::{MessageHandler, WebsocketBase},
+        XRPLAsyncWebsocketIO,
+    };
+    use crate::asynch::clients::{
+        exceptions::{XRPLClientException, XRPLClientResult},
+        SingleExecutorMutex,
+    };
 
-                Ok(serde_json::from_str(&message)?)
+    /// A socket that hands out its queued frames.
+    struct FakeSocket {
+        frames: VecDeque<String>,
+        base: WebsocketBase<SingleExecutorMutex>,
+    }
+
+    impl FakeSocket {
+        fn new(frames: &[&str]) -> Self {
+            Self {
+                frames: frames.iter().map(|frame| String::from(*frame)).collect(),
+                base: WebsocketBase::new(),
             }
-            Some(Err(error)) => Err(error),
-            None => Ok(None),
         }
     }
+
+    impl Stream for FakeSocket {
+        type Item = XRPLClientResult<String>;
+
+        fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            Poll::Ready(self.frames.pop_front().map(Ok))
+        }
+    }
+
+    impl Sink<String> for FakeSocket {
+        type Error = XRPLClientException;
+
+        fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<XRPLClientResult<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn start_send(self: Pin<&mut Self>, _: String) -> XRPLClientResult<()> {
+            Ok(())
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<XRPLClientResult<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<XRPLClientResult<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    impl MessageHandler for FakeSocket {
+        async fn setup_request_future(&mut self, id: String) {
+            self.base.setup_request_future(id).await
+        }
+
+        async fn handle_message(&mut self, message: String) -> XRPLClientResult<()> {
+            self.base.handle_message(message).await
+        }
+
+        async fn pop_message(&mut self) -> String {
+            self.base.pop_message().await
+        }
+
+        async fn try_recv_request(&mut self, id: String) -> XRPLClientResult<Option<String>> {
+            self.base.try_recv_request(id).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_split_frames() {
+        let mut socket = FakeSocket::new(&[
+            r#"{"type":"ledgerClosed","fee_base":10,"fee_ref":10,"ledger_hash":"687F604EF6B2F67319E8DCC8C66EF49D84D18A1E18F948421FC24D2C7C3DB464","#,
+            r#""ledger_index":62964739,"ledger_time":638329271,"reserve_base":20000000,"reserve_inc":5000000,"txn_count":5,"validated_ledgers":"32570-62964740"}"#,
+        ]);
+        let response = socket.xrpl_receive().await.unwrap().unwrap();
+
+        let result = serde_json::to_value(response.result).unwrap();
+
+        assert_eq!(result["ledger_index"], 62964739);
+        assert!(socket.xrpl_receive().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_slow_down() {
+        let mut socket = FakeSocket::new(&[
+            r#"{"error":"slowDown","error_message":"You are placing too much load on the server.","status":"error","type":"response"}"#,
+            r#"{"type":"ledgerClosed","fee_base":10,"fee_ref":10,"ledger_hash":"687F604EF6B2F67319E8DCC8C66EF49D84D18A1E18F948421FC24D2C7C3DB464","ledger_index":62964739,"ledger_time":638329271,"reserve_base":20000000,"reserve_inc":5000000,"txn_count":5,"validated_ledgers":"32570-62964740"}"#,
+        ]);
+        let error = socket.xrpl_receive().await.unwrap_err();
+
+        assert!(error.is_retryable());
+        assert!(matches!(
+            error,
+            XRPLClientException::RateLimited { ref message }
+                if message == "You are placing too much load on the server."
+        ));
+        assert!(socket.xrpl_receive().await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_no_permission() {
+        let mut socket = FakeSocket::new(&[
+            r#"{"error":"noPermission","error_message":"You don't have permission for this command.","status":"error","type":"response"}"#,
+        ]);
+
+        assert!(matches!(
+            socket.xrpl_receive().await,
+            Err(XRPLClientException::ServerWarning { ref code, .. }) if code == "noPermission"
+        ));
+    }
 }
@@ -1,10 +1,13 @@
-use alloc::string::{String, ToString};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 use embassy_sync::{blocking_mutex::raw::RawMutex, channel::Channel};
 use futures::channel::oneshot::{self, Receiver, Sender};
 use hashbrown::HashMap;
 use serde_json::Value;
 
-use crate::asynch::clients::exceptions::XRPLClientResult;
+use crate::asynch::clients::exceptions::{XRPLClientException, XRPLClientResult};
 
 use super::exceptions::XRPLWebSocketException;
 
@@ -41,6 +44,101 @@ where
     }
 }
 
+/// Recognizes the frames a server sends unprompted to warn a client or
+/// refuse its requests, e.g. `{"error": "slowDown", ...}` once the client
+/// places too much load on the server.
+fn server_notice(message: &Value) -> Option<XRPLClientException> {
+    if message.get("result").is_some() {
+        return None;
+    }
+    let text = |key| message.get(key).and_then(Value::as_str);
+    let description = text("error_message")
+        .or(text("message"))
+        .unwrap_or_default()
+        .to_string();
+    match (text("error"), text("warning")) {
+        (Some("slowDown"), _) => Some(XRPLClientException::RateLimited {
+            message: description,
+        }),
+        (Some(code), _) | (None, Some(code)) => Some(XRPLClientException::ServerWarning {
+            code: code.to_string(),
+            message: description,
+        }),
+        (None, None) => None,
+    }
+}
+
+/// Collects the frames of a message as raw bytes, so a character split
+/// across frames is only decoded once the message is complete.
+///
+/// Completeness is tracked as the bytes arrive: the message is complete
+/// once the brackets of its top-level value are balanced, ignoring those
+/// inside strings.
+#[derive(Debug, Default)]
+pub(crate) struct MessageBuffer {
+    bytes: Vec<u8>,
+    depth: usize,
+    started: bool,
+    in_string: bool,
+    escaped: bool,
+}
+
+impl MessageBuffer {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a frame and returns whether the message is complete.
+    pub(crate) fn push(&mut self, frame: &[u8]) -> bool {
+        for byte in frame {
+            self.scan(*byte);
+        }
+        self.bytes.extend_from_slice(frame);
+
+        self.is_complete()
+    }
+
+    fn scan(&mut self, byte: u8) {
+        if self.in_string {
+            match byte {
+                _ if self.escaped => self.escaped = false,
+                b'\\' => self.escaped = true,
+                b'"' => self.in_string = false,
+                _ => {}
+            }
+            return;
+        }
+        match byte {
+            b'"' => {
+                self.in_string = true;
+                self.started = true;
+            }
+            b'{' | b'[' => {
+                self.depth += 1;
+                self.started = true;
+            }
+            b'}' | b']' => self.depth = self.depth.saturating_sub(1),
+            byte if !byte.is_ascii_whitespace() => self.started = true,
+            _ => {}
+        }
+    }
+
+    /// Whether a value has started and all its brackets and strings are
+    /// closed.
+    pub(crate) fn is_complete(&self) -> bool {
+        self.started && self.depth == 0 && !self.in_string
+    }
+
+    /// Decodes the collected message and clears the buffer.
+    pub(crate) fn take(&mut self) -> XRPLClientResult<String> {
+        let bytes = core::mem::take(&mut self.bytes);
+        *self = Self::new();
+
+        String::from_utf8(bytes)
+            .map_err(|error| XRPLWebSocketException::Utf8(error.utf8_error()).into())
+    }
+}
+
 #[allow(async_fn_in_trait)]
 pub trait MessageHandler {
     /// Setup an empty future for a request.
@@ -65,6 +163,11 @@ where
 
     async fn handle_message(&mut self, message: String) -> XRPLClientResult<()> {
         let message_value: Value = serde_json::from_str(&message)?;
+        if message_value.get("id").is_none() {
+            if let Some(notice) = server_notice(&message_value) {
+                return Err(notice);
+            }
+        }
         let id = match message_value.get("id") {
             Some(id) => match id.as_str() {
                 Some(id) => id.to_string(),
@@ -106,3 +209,38 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test_message_buffer {
+    use super::MessageBuffer;
+
+    #[test]
+    fn test_split_character() {
+        let message = r#"{"domain":"exämple.com"}"#.as_bytes();
+        let split = message.iter().position(|byte| *byte == 0xC3).unwrap() + 1;
+        let mut buffer = MessageBuffer::new();
+
+        assert!(!buffer.push(&message[..split]));
+        assert!(buffer.push(&message[split..]));
+        assert_eq!(buffer.take().unwrap(), r#"{"domain":"exämple.com"}"#);
+        assert!(!buffer.is_complete());
+    }
+
+    #[test]
+    fn test_brackets_in_strings() {
+        let mut buffer = MessageBuffer::new();
+
+        assert!(!buffer.push(br#"{"memo":"}\"]"#));
+        assert!(!buffer.push(br#"{","#));
+        assert!(buffer.push(br#""ok":[1,{}]}"#));
+        assert_eq!(buffer.take().unwrap(), r#"{"memo":"}\"]{","ok":[1,{}]}"#);
+    }
+
+    #[test]
+    fn test_whitespace() {
+        let mut buffer = MessageBuffer::new();
+
+        assert!(!buffer.push(b"  \n"));
+        assert!(buffer.push(b"{}"));
+    }
+}
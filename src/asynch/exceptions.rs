@@ -4,6 +4,7 @@ use thiserror_no_std::Error;
 use super::clients::exceptions::XRPLClientException;
 #[cfg(feature = "helpers")]
 use super::{
+    ledger::exceptions::XRPLLedgerHelperException,
     transaction::exceptions::{
         XRPLSignTransactionException, XRPLSubmitAndWaitException, XRPLTransactionHelperException,
     },
@@ -31,6 +32,9 @@ pub enum XRPLHelperException {
     #[cfg(feature = "helpers")]
     #[error("XRPL Transaction Helper error: {0}")]
     XRPLTransactionHelperError(#[from] XRPLTransactionHelperException),
+    #[cfg(feature = "helpers")]
+    #[error("XRPL Ledger Helper error: {0}")]
+    XRPLLedgerHelperError(#[from] XRPLLedgerHelperException),
     #[error("XRPL Model error: {0}")]
     XRPLModelError(#[from] XRPLModelException),
     #[cfg(feature = "helpers")]
@@ -0,0 +1,13 @@
+use thiserror_no_std::Error;
+
+#[derive(Error, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum XRPLLedgerHelperException {
+    #[error("Timed out waiting for ledger {target_ledger_index} to be validated. Latest validated ledger: {validated_ledger_index}")]
+    WaitForLedgerTimeout {
+        target_ledger_index: u32,
+        validated_ledger_index: u32,
+    },
+    #[error("The poll interval must be greater than zero")]
+    ZeroPollInterval,
+}
@@ -1,14 +1,32 @@
-use core::{cmp::min, convert::TryInto};
+pub mod exceptions;
 
-use alloc::string::ToString;
+use core::{cmp::min, convert::TryInto, time::Duration};
 
-use crate::models::{
-    requests::{fee::Fee, ledger::Ledger},
-    results::{fee::Drops, fee::Fee as FeeResult, ledger::Ledger as LedgerResult},
-    XRPAmount,
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    models::{
+        requests::{
+            amm_info::AMMInfo, book_offers::BookOffers, fee::Fee, ledger::Ledger,
+            ledger_entry::LedgerEntry,
+        },
+        results::{
+            amm_info::{AMMDescription, AMMInfo as AMMInfoResult},
+            book_offers::{BookOffer, BookOffers as BookOffersResult},
+            fee::Drops,
+            fee::Fee as FeeResult,
+            ledger::Ledger as LedgerResult,
+            ledger_entry::LedgerEntry as LedgerEntryResult,
+        },
+        Currency, XRPAmount,
+    },
+    XRPLSerdeJsonError,
 };
 
-use super::{clients::XRPLAsyncClient, exceptions::XRPLHelperResult};
+use exceptions::XRPLLedgerHelperException;
+
+use super::{clients::XRPLAsyncClient, exceptions::XRPLHelperResult, wait_duration};
 
 pub async fn get_latest_validated_ledger_sequence(
     client: &impl XRPLAsyncClient,
@@ -62,6 +80,52 @@ pub async fn get_latest_open_ledger_sequence(
         .ledger_index)
 }
 
+/// Poll the validated ledger index until it reaches or exceeds
+/// `target_ledger_index`, sleeping `poll_interval` between attempts.
+///
+/// Returns the latest validated ledger index once the target has been
+/// validated, or `XRPLLedgerHelperException::WaitForLedgerTimeout` if
+/// `timeout` elapses first. A zero `poll_interval` is rejected with
+/// `XRPLLedgerHelperException::ZeroPollInterval`.
+///
+/// With the `std` feature the timeout is measured on the clock, so it
+/// includes the time spent on requests. Without it only the time spent
+/// sleeping between requests is counted.
+pub async fn wait_for_ledger(
+    client: &impl XRPLAsyncClient,
+    target_ledger_index: u32,
+    poll_interval: Duration,
+    timeout: Duration,
+) -> XRPLHelperResult<u32> {
+    if poll_interval.is_zero() {
+        return Err(XRPLLedgerHelperException::ZeroPollInterval.into());
+    }
+    #[cfg(feature = "std")]
+    let started = alloc::time::Instant::now();
+    #[cfg(not(feature = "std"))]
+    let mut waited = Duration::ZERO;
+    loop {
+        let validated_ledger_index = get_latest_validated_ledger_sequence(client).await?;
+        if validated_ledger_index >= target_ledger_index {
+            return Ok(validated_ledger_index);
+        }
+        #[cfg(feature = "std")]
+        let waited = started.elapsed();
+        if waited >= timeout {
+            return Err(XRPLLedgerHelperException::WaitForLedgerTimeout {
+                target_ledger_index,
+                validated_ledger_index,
+            }
+            .into());
+        }
+        wait_duration(poll_interval).await;
+        #[cfg(not(feature = "std"))]
+        {
+            waited += poll_interval;
+        }
+    }
+}
+
 pub enum FeeType {
     Open,
     Minimum,
@@ -92,3 +156,379 @@ fn match_fee_type(fee_type: Option<FeeType>, drops: Drops<'_>) -> XRPLHelperResu
         Some(FeeType::Dynamic) => unimplemented!("Dynamic fee calculation not yet implemented"),
     }
 }
+
+/// Fetch a single ledger object and deserialize it into the expected
+/// ledger object model (e.g. `AccountRoot`, `Offer`, `RippleState`).
+pub async fn get_ledger_entry<T>(
+    request: LedgerEntry<'_>,
+    client: &impl XRPLAsyncClient,
+) -> XRPLHelperResult<T>
+where
+    T: DeserializeOwned,
+{
+    let response = client.request(request.into()).await?;
+    let node = response
+        .try_into_result::<LedgerEntryResult<'_>>()?
+        .node
+        .ok_or(XRPLSerdeJsonError::InvalidNoneError("node".to_string()))?;
+
+    Ok(serde_json::from_value(node)?)
+}
+
+/// Both sides of the order book of a trading pair.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderBook<'a> {
+    /// Offers to buy the base currency with the counter currency,
+    /// best first.
+    pub bids: Vec<BookOffer<'a>>,
+    /// Offers to sell the base currency for the counter currency,
+    /// best first.
+    pub asks: Vec<BookOffer<'a>>,
+}
+
+/// Fetch both directions of the order book between `base` and `counter`
+/// with two `book_offers` requests. Each side is sorted by quality, best
+/// offer first. Either currency may be XRP.
+///
+/// `base` and `counter` are issues, which the models represent as a
+/// [`Currency`]: XRP, or a currency code with its issuer.
+///
+/// With `filter_expired`, offers whose `Expiration` is not after the
+/// close time of the ledger at `ledger_index` are dropped. This costs
+/// an extra `ledger` request.
+pub async fn get_order_book<'a>(
+    base: Currency<'a>,
+    counter: Currency<'a>,
+    client: &impl XRPLAsyncClient,
+    limit: Option<u16>,
+    ledger_index: Option<Cow<'a, str>>,
+    filter_expired: bool,
+) -> XRPLHelperResult<OrderBook<'a>> {
+    let close_time = if filter_expired {
+        Some(get_ledger_close_time(client, ledger_index.clone()).await?)
+    } else {
+        None
+    };
+    let asks = get_book_offers(
+        base.clone(),
+        counter.clone(),
+        client,
+        limit,
+        ledger_index.clone(),
+        close_time,
+    )
+    .await?;
+    let bids = get_book_offers(counter, base, client, limit, ledger_index, close_time).await?;
+
+    Ok(OrderBook { bids, asks })
+}
+
+async fn get_book_offers<'a>(
+    taker_gets: Currency<'a>,
+    taker_pays: Currency<'a>,
+    client: &impl XRPLAsyncClient,
+    limit: Option<u16>,
+    ledger_index: Option<Cow<'a, str>>,
+    close_time: Option<u32>,
+) -> XRPLHelperResult<Vec<BookOffer<'a>>> {
+    let request = BookOffers::new(
+        None,
+        taker_gets,
+        taker_pays,
+        None,
+        ledger_index,
+        limit,
+        None,
+    );
+    let response = client.request(request.into()).await?;
+    let offers = response.try_into_result::<BookOffersResult<'_>>()?.offers;
+    let mut offers_by_quality = offers
+        .into_iter()
+        .filter(|offer| !is_offer_expired(offer, close_time))
+        .map(|offer| Ok((offer.get_quality()?, offer)))
+        .collect::<XRPLHelperResult<Vec<_>>>()?;
+    offers_by_quality.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    Ok(offers_by_quality
+        .into_iter()
+        .map(|(_, offer)| offer)
+        .collect())
+}
+
+/// An offer can no longer be taken once a ledger closes at or after its
+/// expiration. Both times are in seconds since the Ripple Epoch.
+fn is_offer_expired(offer: &BookOffer<'_>, close_time: Option<u32>) -> bool {
+    match (offer.offer.expiration, close_time) {
+        (Some(expiration), Some(close_time)) => expiration <= close_time,
+        _ => false,
+    }
+}
+
+async fn get_ledger_close_time(
+    client: &impl XRPLAsyncClient,
+    ledger_index: Option<Cow<'_, str>>,
+) -> XRPLHelperResult<u32> {
+    let request = Ledger::new(
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(ledger_index.unwrap_or("validated".into())),
+        None,
+        None,
+        None,
+    );
+    let response = client.request(request.into()).await?;
+
+    Ok(response
+        .try_into_result::<LedgerResult<'_>>()?
+        .ledger
+        .close_time)
+}
+
+/// Fetch the current state of the AMM pool for `asset` and `asset2`.
+pub async fn get_amm_info<'a>(
+    asset: Currency<'a>,
+    asset2: Currency<'a>,
+    client: &impl XRPLAsyncClient,
+    ledger_index: Option<Cow<'a, str>>,
+) -> XRPLHelperResult<AMMDescription<'a>> {
+    let request = AMMInfo::new(None, None, Some(asset), Some(asset2), None, ledger_index);
+    let response = client.request(request.into()).await?;
+
+    Ok(response.try_into_result::<AMMInfoResult<'_>>()?.amm)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_get_order_book {
+    use alloc::{vec, vec::Vec};
+    use bigdecimal::BigDecimal;
+    use serde_json::{json, Value};
+
+    use super::get_order_book;
+    use crate::{
+        asynch::{
+            clients::MockClient,
+            test_cases::{ledger, ledger_result},
+        },
+        models::{
+            requests::RequestMethod,
+            results::{book_offers::BookOffer, XRPLResult},
+            IssuedCurrency, XRP,
+        },
+    };
+
+    const ISSUER: &str = "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B";
+
+    fn usd(value: &str) -> Value {
+        json!({ "currency": "USD", "issuer": ISSUER, "value": value })
+    }
+
+    fn offer(sequence: u32, taker_gets: Value, taker_pays: Value, quality: &str) -> Value {
+        json!({
+            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "BookDirectory": "DFA3B6DDAB58C7E8E5D944E736DA4B7046C30E4F460FD9DE4E1566CFCFC42800",
+            "BookNode": "0",
+            "Flags": 0,
+            "LedgerEntryType": "Offer",
+            "OwnerNode": "0",
+            "PreviousTxnID": "F0AB71E777B2DA54B86231E19B82554EF1F8211F92ECA473121C655BFC5329BF",
+            "PreviousTxnLgrSeq": 14524914,
+            "Sequence": sequence,
+            "TakerGets": taker_gets,
+            "TakerPays": taker_pays,
+            "index": "9A0B6A14E6F9D9F2A2F5A8BB7E8F7F5BCA3D7AE0A77A2D6D1E1B48F1D2E97C01",
+            "owner_funds": "1000000000",
+            "quality": quality
+        })
+    }
+
+    fn sequences(offers: &[BookOffer]) -> Vec<u32> {
+        offers.iter().map(|offer| offer.offer.sequence).collect()
+    }
+
+    #[tokio::test]
+    async fn test_get_order_book_xrp_base() {
+        let client = MockClient::new();
+        // Asks are requested first, then bids. Both books are out of
+        // quality order.
+        for offers in [
+            vec![
+                offer(2, json!("2000000"), usd("1.1"), "0.00000055"),
+                offer(1, json!("1000000"), usd("0.5"), "0.0000005"),
+            ],
+            vec![
+                offer(4, usd("0.4"), json!("1000000"), "2500000"),
+                offer(3, usd("1"), json!("2000000"), "2000000"),
+                offer(5, usd("1"), json!("3000000"), "3000000"),
+            ],
+        ] {
+            client.add_result(
+                RequestMethod::BookOffers,
+                XRPLResult::BookOffers(
+                    serde_json::from_value(json!({ "offers": offers, "validated": true })).unwrap(),
+                ),
+            );
+        }
+        let order_book = get_order_book(
+            XRP::new().into(),
+            IssuedCurrency::new("USD".into(), ISSUER.into()).into(),
+            &client,
+            Some(10),
+            Some("validated".into()),
+            false,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(sequences(&order_book.asks), [1, 2]);
+        assert_eq!(sequences(&order_book.bids), [3, 4, 5]);
+        assert_eq!(
+            client.requests(),
+            [RequestMethod::BookOffers, RequestMethod::BookOffers]
+        );
+        let request = client.last_request(&RequestMethod::BookOffers).unwrap();
+        assert_eq!(
+            request["taker_gets"],
+            json!({ "currency": "USD", "issuer": ISSUER })
+        );
+        assert_eq!(request["taker_pays"], json!({ "currency": "XRP" }));
+        assert_eq!(request["limit"], 10);
+        assert_eq!(request["ledger_index"], "validated");
+    }
+
+    #[tokio::test]
+    async fn test_get_order_book_filter_expired() {
+        let client = MockClient::new();
+        let mut expired = offer(1, json!("1000000"), usd("0.5"), "0.0000005");
+        expired["Expiration"] = json!(753_315_200);
+        let mut live = offer(2, json!("2000000"), usd("1.1"), "0.00000055");
+        live["Expiration"] = json!(753_315_201);
+        // Asks are requested first, then bids.
+        for offers in [vec![expired, live], vec![]] {
+            client.add_result(
+                RequestMethod::BookOffers,
+                XRPLResult::BookOffers(
+                    serde_json::from_value(json!({ "offers": offers })).unwrap(),
+                ),
+            );
+        }
+        let mut ledger = ledger(100);
+        ledger["ledger"]["close_time"] = json!(753_315_200);
+        ledger["ledger"]["parent_close_time"] = json!(753_315_190);
+        client.add_result(RequestMethod::Ledger, ledger_result(ledger));
+
+        let order_book = get_order_book(
+            XRP::new().into(),
+            IssuedCurrency::new("USD".into(), ISSUER.into()).into(),
+            &client,
+            None,
+            None,
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(sequences(&order_book.asks), [2]);
+        assert!(order_book.bids.is_empty());
+        let request = client.last_request(&RequestMethod::Ledger).unwrap();
+        assert_eq!(request["ledger_index"], "validated");
+    }
+
+    #[test]
+    fn test_book_offer_quality_without_server_quality() {
+        let mut offer: BookOffer =
+            serde_json::from_value(offer(1, json!("1000000"), usd("0.5"), "0")).unwrap();
+        offer.quality = None;
+
+        assert_eq!(
+            offer.get_quality().unwrap(),
+            "0.0000005".parse::<BigDecimal>().unwrap()
+        );
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_wait_for_ledger {
+    use core::time::Duration;
+
+    use super::{exceptions::XRPLLedgerHelperException, wait_for_ledger};
+    use crate::{
+        asynch::{
+            clients::MockClient,
+            exceptions::XRPLHelperException,
+            test_cases::{ledger, ledger_result},
+        },
+        models::requests::RequestMethod,
+    };
+
+    /// A client reporting the given validated ledger indexes in turn.
+    fn advancing_client(ledger_indexes: &[u32]) -> MockClient {
+        let client = MockClient::new();
+        for ledger_index in ledger_indexes {
+            client.add_result(RequestMethod::Ledger, ledger_result(ledger(*ledger_index)));
+        }
+
+        client
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_ledger() {
+        let client = advancing_client(&[10, 11, 12, 13, 14]);
+        let validated = wait_for_ledger(
+            &client,
+            13,
+            Duration::from_millis(1),
+            Duration::from_secs(1),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(validated, 13);
+        assert_eq!(client.request_count(&RequestMethod::Ledger), 4);
+        let request = client.last_request(&RequestMethod::Ledger).unwrap();
+        assert_eq!(request["ledger_index"], "validated");
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_ledger_timeout() {
+        // The validated ledger never reaches the target.
+        let client = advancing_client(&[10, 11, 12]);
+        let result = wait_for_ledger(
+            &client,
+            100,
+            Duration::from_millis(1),
+            Duration::from_millis(5),
+        )
+        .await;
+
+        match result {
+            Err(XRPLHelperException::XRPLLedgerHelperError(
+                XRPLLedgerHelperException::WaitForLedgerTimeout {
+                    target_ledger_index,
+                    validated_ledger_index,
+                },
+            )) => {
+                assert_eq!(target_ledger_index, 100);
+                assert!((10..=12).contains(&validated_ledger_index));
+            }
+            other => panic!("expected timeout, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_ledger_zero_poll_interval() {
+        let client = advancing_client(&[10]);
+        let result = wait_for_ledger(&client, 100, Duration::ZERO, Duration::from_secs(1)).await;
+
+        assert!(matches!(
+            result,
+            Err(XRPLHelperException::XRPLLedgerHelperError(
+                XRPLLedgerHelperException::ZeroPollInterval
+            ))
+        ));
+        assert!(client.requests().is_empty());
+    }
+}
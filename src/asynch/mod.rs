@@ -2,48 +2,54 @@ pub mod exceptions;
 
 #[cfg(feature = "helpers")]
 pub mod account;
-#[cfg(any(feature = "websocket", feature = "json-rpc"))]
+#[cfg(any(feature = "websocket", feature = "json-rpc", feature = "test-util"))]
 pub mod clients;
 #[cfg(feature = "helpers")]
 pub mod ledger;
+#[cfg(all(test, feature = "helpers"))]
+pub(crate) mod test_cases;
 #[cfg(feature = "helpers")]
 pub mod transaction;
 #[cfg(feature = "helpers")]
 pub mod wallet;
 
-#[allow(unused_imports)]
-#[allow(clippy::needless_return)]
 async fn wait_seconds(seconds: u64) {
     use core::time::Duration;
 
+    wait_duration(Duration::from_secs(seconds)).await
+}
+
+#[allow(unused_imports)]
+#[allow(clippy::needless_return)]
+async fn wait_duration(duration: core::time::Duration) {
     #[cfg(feature = "tokio-rt")]
     {
-        tokio::time::sleep(tokio::time::Duration::from_secs(seconds)).await;
+        tokio::time::sleep(duration).await;
         return;
     }
     #[cfg(feature = "embassy-rt")]
     {
-        embassy_time::Timer::after_secs(1).await;
+        embassy_time::Timer::after_micros(duration.as_micros() as u64).await;
         return;
     }
     #[cfg(feature = "actix-rt")]
     {
-        actix_rt::time::sleep(Duration::from_secs(seconds)).await;
+        actix_rt::time::sleep(duration).await;
         return;
     }
     #[cfg(feature = "async-std-rt")]
     {
-        async_std::task::sleep(Duration::from_secs(seconds)).await;
+        async_std::task::sleep(duration).await;
         return;
     }
     #[cfg(feature = "futures-rt")]
     {
-        futures_timer::Delay::new(Duration::from_secs(seconds)).await;
+        futures_timer::Delay::new(duration).await;
         return;
     }
     #[cfg(feature = "smol-rt")]
     {
-        smol::Timer::after(Duration::from_secs(seconds)).await;
+        smol::Timer::after(duration).await;
         return;
     }
 }
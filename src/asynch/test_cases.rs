@@ -0,0 +1,174 @@
+use alloc::string::ToString;
+use serde_json::{json, Value};
+
+use crate::{
+    asynch::clients::MockClient,
+    models::{requests::RequestMethod, results::XRPLResult, transactions::account_set::AccountSet},
+};
+
+pub const ACCOUNT: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+
+pub const NETWORK_ID: u32 = 21338;
+
+/// The validated ledger the mock network is at.
+pub const LEDGER_INDEX: u32 = 1000;
+
+/// An `AccountSet` from `account` with every optional field unset.
+pub fn account_set(account: &str) -> AccountSet<'_> {
+    AccountSet::new(
+        account.into(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+}
+
+/// An `AccountSet` from `account` that only lacks the fields taken
+/// from the network's common fields: its fee is 12 drops, its sequence
+/// 1 and its last ledger sequence 100.
+pub fn filled_account_set(account: &str) -> AccountSet<'_> {
+    let mut txn = account_set(account);
+    txn.common_fields.fee = Some("12".into());
+    txn.common_fields.sequence = Some(1);
+    txn.common_fields.last_ledger_sequence = Some(100);
+
+    txn
+}
+
+/// The `AccountRoot` of `account`, funded with 1000 XRP, owning nothing
+/// and at sequence 42.
+pub fn account_root(account: &str) -> Value {
+    json!({
+        "Account": account,
+        "Balance": "1000000000",
+        "Flags": 0,
+        "LedgerEntryType": "AccountRoot",
+        "OwnerCount": 0,
+        "PreviousTxnID": "4294BEBE5B569A18C0A2702387C9B1E7146DC3A5850C1E87204951C6FDAA4C42",
+        "PreviousTxnLgrSeq": 3,
+        "Sequence": 42,
+        "index": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8"
+    })
+}
+
+/// An `account_info` result in the validated ledger for `account_root`.
+pub fn account_info_result(account_root: Value) -> XRPLResult<'static> {
+    serde_json::from_value(json!({
+        "account_data": account_root,
+        "ledger_index": LEDGER_INDEX,
+        "validated": true
+    }))
+    .unwrap()
+}
+
+/// The validated ledger `ledger_index`.
+pub fn ledger(ledger_index: u32) -> Value {
+    json!({
+        "ledger": {
+            "account_hash": "",
+            "close_flags": 0,
+            "close_time": 0,
+            "close_time_resolution": 10,
+            "closed": true,
+            "ledger_hash": "",
+            "ledger_index": ledger_index.to_string(),
+            "parent_close_time": 0,
+            "parent_hash": "",
+            "total_coins": "100000000000000000",
+            "transaction_hash": ""
+        },
+        "ledger_hash": "",
+        "ledger_index": ledger_index,
+        "validated": true
+    })
+}
+
+/// A `ledger` result for `ledger`.
+pub fn ledger_result(ledger: Value) -> XRPLResult<'static> {
+    XRPLResult::Ledger(serde_json::from_value(ledger).unwrap())
+}
+
+/// A `server_state` result with mainnet's base fee and reserves.
+pub fn server_state_result() -> XRPLResult<'static> {
+    serde_json::from_value(json!({
+        "state": {
+            "build_version": "2.2.0",
+            "network_id": NETWORK_ID,
+            "validated_ledger": {
+                "base_fee": "10",
+                "close_time": 0,
+                "hash": "",
+                "reserve_base": "1000000",
+                "reserve_inc": "200000",
+                "seq": LEDGER_INDEX
+            }
+        }
+    }))
+    .unwrap()
+}
+
+/// A `fee` result for an open ledger charging 12 drops.
+pub fn fee_result() -> XRPLResult<'static> {
+    XRPLResult::Fee(
+        serde_json::from_value(json!({
+            "drops": {
+                "base_fee": "10",
+                "median_fee": "5000",
+                "minimum_fee": "10",
+                "open_ledger_fee": "12"
+            }
+        }))
+        .unwrap(),
+    )
+}
+
+/// A `submit` result for `tx_blob` with the given engine result.
+pub fn submit_result(
+    engine_result: &str,
+    engine_result_code: i32,
+    tx_blob: &str,
+) -> XRPLResult<'static> {
+    let applied = engine_result_code == 0;
+    serde_json::from_value(json!({
+        "accepted": true,
+        "applied": applied,
+        "broadcast": applied,
+        "engine_result": engine_result,
+        "engine_result_code": engine_result_code,
+        "engine_result_message": "",
+        "kept": true,
+        "queued": engine_result == "terQUEUED",
+        "tx_blob": tx_blob,
+        "tx_json": {}
+    }))
+    .unwrap()
+}
+
+/// A client answering the requests the helpers make to fill in and
+/// price transactions from [`ACCOUNT`], with the results above.
+pub fn client() -> MockClient {
+    let client = MockClient::new();
+    client.add_result(RequestMethod::ServerState, server_state_result());
+    client.add_result(
+        RequestMethod::AccountInfo,
+        account_info_result(account_root(ACCOUNT)),
+    );
+    client.add_result(RequestMethod::Fee, fee_result());
+    client.add_result(RequestMethod::Ledger, ledger_result(ledger(LEDGER_INDEX)));
+
+    client
+}
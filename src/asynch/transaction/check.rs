@@ -0,0 +1,170 @@
+use alloc::borrow::Cow;
+
+use crate::{
+    asynch::{clients::XRPLAsyncClient, exceptions::XRPLHelperResult},
+    models::{
+        results::submit::Submit,
+        transactions::{
+            check_cancel::CheckCancel, check_cash::CheckCash, check_create::CheckCreate,
+        },
+        Amount, Model,
+    },
+    wallet::Wallet,
+};
+
+use super::sign_and_submit;
+
+/// Creates a check from the wallet's account to `destination` that can
+/// be cashed for up to `send_max`. The transaction is autofilled,
+/// signed and submitted.
+pub async fn create_check<'a, C>(
+    destination: Cow<'a, str>,
+    send_max: Amount<'a>,
+    destination_tag: Option<u32>,
+    expiration: Option<u32>,
+    invoice_id: Option<Cow<'a, str>>,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    let mut check_create = CheckCreate::new(
+        wallet.classic_address.clone().into(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        destination,
+        send_max,
+        destination_tag,
+        expiration,
+        invoice_id,
+    );
+
+    sign_and_submit(&mut check_create, client, wallet, true, true, None).await
+}
+
+/// Cashes the check `check_id` to the wallet's account, either for
+/// exactly `amount` or for as much as possible but at least
+/// `deliver_min`. Exactly one of the two must be set. The transaction
+/// is autofilled, signed and submitted.
+pub async fn cash_check<'a, C>(
+    check_id: Cow<'a, str>,
+    amount: Option<Amount<'a>>,
+    deliver_min: Option<Amount<'a>>,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    let mut check_cash = CheckCash::new(
+        wallet.classic_address.clone().into(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        check_id,
+        amount,
+        deliver_min,
+    );
+    // Fail before autofilling rather than after a round trip.
+    check_cash.validate()?;
+
+    sign_and_submit(&mut check_cash, client, wallet, true, true, None).await
+}
+
+/// Cancels the check `check_id`, returning any XRP reserved for it.
+/// The transaction is autofilled, signed and submitted.
+pub async fn cancel_check<'a, C>(
+    check_id: Cow<'a, str>,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    let mut check_cancel = CheckCancel::new(
+        wallet.classic_address.clone().into(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        check_id,
+    );
+
+    sign_and_submit(&mut check_cancel, client, wallet, true, true, None).await
+}
+
+#[cfg(test)]
+mod test_cash_check {
+    use super::cash_check;
+    use crate::{
+        asynch::clients::MockClient,
+        models::{Amount, XRPAmount},
+        wallet::Wallet,
+    };
+
+    const CHECK_ID: &str = "838766BA2B995C00744175F69A1B11E32C3DBC40E64801A4056FCBD657F57334";
+
+    #[tokio::test]
+    async fn test_amount_and_deliver_min() {
+        let client = MockClient::new();
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let amount = || Some(Amount::XRPAmount(XRPAmount::from("1000000")));
+
+        let both = cash_check(CHECK_ID.into(), amount(), amount(), &wallet, &client).await;
+        assert!(both.is_err());
+        let neither = cash_check(CHECK_ID.into(), None, None, &wallet, &client).await;
+        assert!(neither.is_err());
+        // Both are rejected before anything is sent.
+        assert!(client.requests().is_empty());
+    }
+}
+
+#[cfg(all(feature = "json-rpc", feature = "std"))]
+#[cfg(test)]
+mod test_create_check {
+    use super::create_check;
+    use crate::{
+        asynch::{clients::AsyncJsonRpcClient, wallet::generate_faucet_wallet},
+        models::{Amount, XRPAmount},
+    };
+
+    #[tokio::test]
+    async fn test_create_check() {
+        let client = AsyncJsonRpcClient::connect("https://testnet.xrpl-labs.com/".parse().unwrap());
+        let sender = generate_faucet_wallet(&client, None, None, None, None)
+            .await
+            .unwrap();
+        let destination = generate_faucet_wallet(&client, None, None, None, None)
+            .await
+            .unwrap();
+        let result = create_check(
+            destination.classic_address.clone().into(),
+            Amount::XRPAmount(XRPAmount::from("10000000")),
+            None,
+            None,
+            None,
+            &sender,
+            &client,
+        )
+        .await
+        .unwrap();
+
+        assert!(result.is_success());
+    }
+}
@@ -0,0 +1,192 @@
+use alloc::borrow::Cow;
+
+use crate::{
+    asynch::{clients::XRPLAsyncClient, exceptions::XRPLHelperResult},
+    models::{
+        results::submit::Submit,
+        transactions::{
+            escrow_cancel::EscrowCancel, escrow_create::EscrowCreate, escrow_finish::EscrowFinish,
+        },
+        Model, XRPAmount,
+    },
+    utils::unix_to_ripple,
+    wallet::Wallet,
+};
+
+use super::sign_and_submit;
+
+/// Escrows `amount` from the wallet's account for `destination`. The
+/// escrow can be finished from `finish_after` and cancelled from
+/// `cancel_after`, both given as Unix timestamps and converted to the
+/// Ripple Epoch. The transaction is autofilled, signed and submitted.
+#[allow(clippy::too_many_arguments)]
+pub async fn create_escrow<'a, C>(
+    amount: XRPAmount<'a>,
+    destination: Cow<'a, str>,
+    finish_after: Option<i64>,
+    cancel_after: Option<i64>,
+    condition: Option<Cow<'a, str>>,
+    destination_tag: Option<u32>,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    let mut escrow_create = build_escrow_create(
+        wallet.classic_address.clone().into(),
+        amount,
+        destination,
+        finish_after,
+        cancel_after,
+        condition,
+        destination_tag,
+    )?;
+
+    sign_and_submit(&mut escrow_create, client, wallet, true, true, None).await
+}
+
+/// Delivers the escrow created by `owner` with `offer_sequence`. A
+/// conditional escrow also needs its `condition` and `fulfillment`.
+/// The transaction is autofilled, signed and submitted.
+pub async fn finish_escrow<'a, C>(
+    owner: Cow<'a, str>,
+    offer_sequence: u32,
+    condition: Option<Cow<'a, str>>,
+    fulfillment: Option<Cow<'a, str>>,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    let mut escrow_finish = EscrowFinish::new(
+        wallet.classic_address.clone().into(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        owner,
+        offer_sequence,
+        condition,
+        fulfillment,
+    );
+
+    sign_and_submit(&mut escrow_finish, client, wallet, true, true, None).await
+}
+
+/// Returns the escrow created by `owner` with `offer_sequence` to its
+/// owner. The transaction is autofilled, signed and submitted.
+pub async fn cancel_escrow<'a, C>(
+    owner: Cow<'a, str>,
+    offer_sequence: u32,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    let mut escrow_cancel = EscrowCancel::new(
+        wallet.classic_address.clone().into(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        owner,
+        offer_sequence,
+    );
+
+    sign_and_submit(&mut escrow_cancel, client, wallet, true, true, None).await
+}
+
+fn build_escrow_create<'a>(
+    account: Cow<'a, str>,
+    amount: XRPAmount<'a>,
+    destination: Cow<'a, str>,
+    finish_after: Option<i64>,
+    cancel_after: Option<i64>,
+    condition: Option<Cow<'a, str>>,
+    destination_tag: Option<u32>,
+) -> XRPLHelperResult<EscrowCreate<'a>> {
+    let escrow_create = EscrowCreate::new(
+        account,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        amount,
+        destination,
+        cancel_after.map(unix_to_ripple).transpose()?,
+        condition,
+        destination_tag,
+        finish_after.map(unix_to_ripple).transpose()?,
+    );
+    // Catches `cancel_after` not being after `finish_after`.
+    escrow_create.validate()?;
+
+    Ok(escrow_create)
+}
+
+#[cfg(test)]
+mod test_create_escrow {
+    use super::build_escrow_create;
+    use crate::{models::XRPAmount, utils::RIPPLE_EPOCH};
+
+    const ACCOUNT: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+    const DESTINATION: &str = "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW";
+
+    #[test]
+    fn test_build_escrow_create() {
+        let escrow_create = build_escrow_create(
+            ACCOUNT.into(),
+            XRPAmount::from("10000"),
+            DESTINATION.into(),
+            Some(RIPPLE_EPOCH),
+            Some(1_700_000_000),
+            None,
+            Some(23),
+        )
+        .unwrap();
+
+        assert_eq!(escrow_create.finish_after, Some(0));
+        assert_eq!(escrow_create.cancel_after, Some(753_315_200));
+        assert_eq!(escrow_create.destination, DESTINATION);
+        assert_eq!(escrow_create.destination_tag, Some(23));
+        assert_eq!(escrow_create.amount, XRPAmount::from("10000"));
+    }
+
+    #[test]
+    fn test_build_escrow_create_errors() {
+        let build = |finish_after, cancel_after| {
+            build_escrow_create(
+                ACCOUNT.into(),
+                XRPAmount::from("10000"),
+                DESTINATION.into(),
+                finish_after,
+                cancel_after,
+                None,
+                None,
+            )
+        };
+
+        // Cancelling must come strictly after finishing.
+        assert!(build(Some(1_700_000_000), Some(1_700_000_000)).is_err());
+        assert!(build(Some(1_700_000_001), Some(1_700_000_000)).is_err());
+        // Times before the Ripple Epoch cannot be represented.
+        assert!(build(Some(RIPPLE_EPOCH - 1), None).is_err());
+        assert!(build(None, Some(RIPPLE_EPOCH + u32::MAX as i64 + 1)).is_err());
+        assert!(build(None, Some(RIPPLE_EPOCH + u32::MAX as i64)).is_ok());
+    }
+}
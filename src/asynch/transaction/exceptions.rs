@@ -12,6 +12,10 @@ pub enum XRPLTransactionHelperException {
     ParseRippledVersionError(ParseIntError),
     #[error("Invalid rippled version: {0}")]
     InvalidRippledVersion(String),
+    #[error("The bridge door account {0:?} is not a valid classic address")]
+    InvalidBridgeDoor(String),
+    #[error("All transactions of a batch must be sent from {expected:?}, found {found:?}")]
+    MixedBatchAccounts { expected: String, found: String },
     #[error("XRPL Sign Transaction error: {0}")]
     XRPLSignTransactionError(#[from] XRPLSignTransactionException),
     #[error("XRPL Submit and Wait error: {0}")]
@@ -27,6 +31,10 @@ pub enum XRPLSignTransactionException {
     FeeTooHigh(String),
     #[error("Wallet is required to sign transaction")]
     WalletRequired,
+    #[error(
+        "The transaction signature does not match the transaction. Was it modified after signing?"
+    )]
+    SignatureInvalidAfterMutation,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
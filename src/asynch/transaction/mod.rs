@@ -1,44 +1,60 @@
+mod check;
+mod escrow;
 pub mod exceptions;
+mod payment_channel;
 mod submit_and_wait;
+mod xchain;
 
 use bigdecimal::{BigDecimal, RoundingMode};
+pub use check::*;
+pub use escrow::*;
+pub use payment_channel::*;
 pub use submit_and_wait::*;
+pub use xchain::*;
 
 use crate::{
     asynch::{
         account::get_next_valid_seq_number,
-        clients::{CommonFields, XRPLAsyncClient},
+        clients::XRPLAsyncClient,
         ledger::{get_fee, get_latest_validated_ledger_sequence},
         transaction::exceptions::XRPLSignTransactionException,
     },
     core::{
-        addresscodec::{is_valid_xaddress, xaddress_to_classic_address},
+        addresscodec::{decode_classic_address, is_valid_xaddress, xaddress_to_classic_address},
         binarycodec::{encode, encode_for_multisigning, encode_for_signing},
-        keypairs::sign as keypairs_sign,
+        keypairs::{is_valid_message, is_valid_public_key, sign as keypairs_sign},
     },
     models::{
-        requests::{server_state::ServerState, submit::Submit},
-        results::{server_state::ServerState as ServerStateResult, submit::Submit as SubmitResult},
+        ledger::objects::signer_list::SignerList,
+        requests::{server_state::ServerState, simulate::Simulate, submit::Submit},
+        results::{
+            server_state::ServerState as ServerStateResult, simulate::Simulate as SimulateResult,
+            submit::Submit as SubmitResult,
+        },
         transactions::{
-            exceptions::XRPLTransactionFieldException, Signer, Transaction, TransactionType,
+            exceptions::{XRPLTransactionException, XRPLTransactionFieldException},
+            ticket_create::TicketCreate,
+            Signer, Transaction, TransactionType,
         },
         Model, XRPAmount, XRPLModelException,
     },
+    transaction::exceptions::XRPLMultisignException,
     utils::transactions::{
         get_transaction_field_value, set_transaction_field_value, validate_transaction_has_field,
     },
-    wallet::Wallet,
+    wallet::{SigningKey, Wallet},
 };
 
 use alloc::string::String;
 use alloc::string::ToString;
 use alloc::vec::Vec;
 use alloc::{borrow::Cow, vec};
-use core::convert::TryInto;
+use core::convert::{TryFrom, TryInto};
 use core::fmt::Debug;
 use exceptions::XRPLTransactionHelperException;
 use serde::Serialize;
 use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
 use strum::IntoEnumIterator;
 
 use super::exceptions::XRPLHelperResult;
@@ -49,6 +65,122 @@ const REQUIRED_NETWORKID_VERSION: &str = "1.11.0";
 const LEDGER_OFFSET: u8 = 20;
 
 pub fn sign<'a, T, F>(transaction: &mut T, wallet: &Wallet, multisign: bool) -> XRPLHelperResult<()>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Model + Serialize + DeserializeOwned + Clone + Debug,
+{
+    // The wallet may not outlive the transaction, so the keys stored on
+    // it are copied.
+    _sign(transaction, wallet.into(), multisign, |value| {
+        Cow::Owned(value.into())
+    })
+}
+
+/// Signs the transaction with a borrowed [`SigningKey`]. Unlike [`sign`],
+/// the account and public key are borrowed into the transaction rather
+/// than copied.
+pub fn sign_with_key<'a, T, F>(
+    transaction: &mut T,
+    signing_key: SigningKey<'a>,
+    multisign: bool,
+) -> XRPLHelperResult<()>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Model + Serialize + DeserializeOwned + Clone + Debug,
+{
+    _sign(transaction, signing_key, multisign, Cow::Borrowed)
+}
+
+/// Multi-signs a transaction with every wallet that is a member of
+/// `signer_list`, adding their signatures to the `Signers` field and
+/// clearing `SigningPubKey` as multi-signed transactions require.
+///
+/// Wallets that are not in the list or already signed are skipped.
+/// Signatures already on the transaction are kept and count towards the
+/// list's quorum. If the weights of the signing members fall short of
+/// the quorum, the transaction is left unchanged and an error reports
+/// how much weight is missing.
+pub fn multisign_with<'a, T, F>(
+    transaction: &mut T,
+    wallets: &[&Wallet],
+    signer_list: &SignerList<'_>,
+) -> XRPLHelperResult<()>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Model + Serialize + DeserializeOwned + Clone + Debug,
+{
+    let signer_weight = |account: &str| {
+        signer_list
+            .signer_entries
+            .iter()
+            .find(|entry| entry.account == account)
+            .map(|entry| u32::from(entry.signer_weight))
+    };
+    let mut unsigned = transaction.clone();
+    unsigned.get_mut_common_fields().signing_pub_key = Some("".into());
+    let existing = unsigned
+        .get_mut_common_fields()
+        .signers
+        .take()
+        .unwrap_or_default();
+    let mut weight: u32 = existing
+        .iter()
+        .filter_map(|signer| signer_weight(&signer.account))
+        .sum();
+    let mut members: Vec<&Wallet> = Vec::new();
+    for wallet in wallets {
+        if existing
+            .iter()
+            .any(|signer| signer.account == wallet.classic_address)
+            || members
+                .iter()
+                .any(|member| member.classic_address == wallet.classic_address)
+        {
+            continue;
+        }
+        if let Some(entry_weight) = signer_weight(&wallet.classic_address) {
+            members.push(wallet);
+            weight += entry_weight;
+        }
+    }
+    if weight < signer_list.signer_quorum {
+        return Err(XRPLMultisignException::InsufficientSignerWeight {
+            missing: signer_list.signer_quorum - weight,
+        }
+        .into());
+    }
+    // Signers are ordered by their numeric account ID.
+    let mut signers = Vec::with_capacity(existing.len() + members.len());
+    for signer in existing {
+        signers.push((decode_classic_address(&signer.account)?, signer));
+    }
+    for wallet in members {
+        let account_id = decode_classic_address(&wallet.classic_address)?;
+        let mut signed = unsigned.clone();
+        sign(&mut signed, wallet, true)?;
+        if let Some(signer) = signed
+            .get_mut_common_fields()
+            .signers
+            .take()
+            .and_then(|signers| signers.into_iter().next())
+        {
+            signers.push((account_id, signer));
+        }
+    }
+    signers.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let common_fields = transaction.get_mut_common_fields();
+    common_fields.signing_pub_key = Some("".into());
+    common_fields.signers = Some(signers.into_iter().map(|(_, signer)| signer).collect());
+
+    Ok(())
+}
+
+fn _sign<'a, 'k, T, F>(
+    transaction: &mut T,
+    signing_key: SigningKey<'k>,
+    multisign: bool,
+    to_cow: impl Fn(&'k str) -> Cow<'a, str>,
+) -> XRPLHelperResult<()>
 where
     F: IntoEnumIterator + Serialize + Debug + PartialEq,
     T: Transaction<'a, F> + Model + Serialize + DeserializeOwned + Clone + Debug,
@@ -57,22 +189,22 @@ where
 
     if multisign {
         let serialized_for_signing =
-            encode_for_multisigning(transaction, wallet.classic_address.clone().into())?;
+            encode_for_multisigning(transaction, signing_key.classic_address.into())?;
         let serialized_bytes = hex::decode(serialized_for_signing)?;
-        let signature = keypairs_sign(&serialized_bytes, &wallet.private_key)?;
+        let signature = keypairs_sign(&serialized_bytes, signing_key.private_key)?;
         let signer = Signer::new(
-            wallet.classic_address.clone().into(),
+            to_cow(signing_key.classic_address),
             signature.into(),
-            wallet.public_key.clone().into(),
+            to_cow(signing_key.public_key),
         );
         transaction.get_mut_common_fields().signers = Some(vec![signer]);
 
         Ok(())
     } else {
-        prepare_transaction(transaction, wallet)?;
+        prepare_transaction(transaction, to_cow(signing_key.public_key))?;
         let serialized_for_signing = encode_for_signing(transaction)?;
         let serialized_bytes = hex::decode(serialized_for_signing)?;
-        let signature = keypairs_sign(&serialized_bytes, &wallet.private_key)?;
+        let signature = keypairs_sign(&serialized_bytes, signing_key.private_key)?;
         transaction.get_mut_common_fields().txn_signature = Some(signature.into());
 
         Ok(())
@@ -85,6 +217,7 @@ pub async fn sign_and_submit<'a, 'b, T, F, C>(
     wallet: &Wallet,
     autofill: bool,
     check_fee: bool,
+    fail_hard: Option<bool>,
 ) -> XRPLHelperResult<SubmitResult<'a>>
 where
     F: IntoEnumIterator + Serialize + Debug + PartialEq,
@@ -99,7 +232,7 @@ where
         }
         sign(transaction, wallet, false)?;
     }
-    submit(transaction, client).await
+    submit(transaction, client, false, fail_hard).await
 }
 
 pub async fn autofill<'a, 'b, F, T, C>(
@@ -115,16 +248,31 @@ where
     let txn = transaction.clone();
     let txn_common_fields = transaction.get_mut_common_fields();
     let common_fields = client.get_common_fields().await?;
-    if txn_common_fields.network_id.is_none() && txn_needs_network_id(common_fields.clone())? {
+    if txn_common_fields.network_id.is_none()
+        && should_set_network_id(
+            common_fields.network_id,
+            common_fields.build_version.as_deref(),
+        )
+    {
         txn_common_fields.network_id = common_fields.network_id;
     }
-    if txn_common_fields.sequence.is_none() {
+    if txn_common_fields.ticket_sequence.is_some() {
+        // Transactions that use a ticket must have a `Sequence` of 0.
+        txn_common_fields.sequence = Some(0);
+    } else if txn_common_fields.sequence.is_none() {
         txn_common_fields.sequence =
             Some(get_next_valid_seq_number(txn_common_fields.account.clone(), client, None).await?);
     }
     if txn_common_fields.fee.is_none() {
-        txn_common_fields.fee =
-            Some(calculate_fee_per_transaction_type(&txn, Some(client), signers_count).await?);
+        txn_common_fields.fee = Some(
+            calculate_fee_per_transaction_type(
+                &txn,
+                Some(client),
+                signers_count,
+                FeeOptions::default(),
+            )
+            .await?,
+        );
     }
     if txn_common_fields.last_ledger_sequence.is_none() {
         let ledger_sequence = get_latest_validated_ledger_sequence(client).await?;
@@ -134,6 +282,150 @@ where
     Ok(())
 }
 
+/// Autofills a transaction from values supplied by the caller, without
+/// any network calls, e.g. for signing on an air-gapped machine.
+///
+/// Like [`autofill`], only fields that are not set yet are filled.
+/// Transactions that use a ticket get a `Sequence` of 0 instead of
+/// `sequence`. `network_id` is only set for networks that require the
+/// `NetworkID` field, i.e. those with an ID above 1024.
+pub fn autofill_offline<'a, F, T>(
+    transaction: &mut T,
+    sequence: u32,
+    fee: XRPAmount<'a>,
+    last_ledger_sequence: u32,
+    network_id: Option<u32>,
+) -> XRPLHelperResult<()>
+where
+    T: Transaction<'a, F> + Model,
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+{
+    let fee_drops = fee
+        .0
+        .parse::<u64>()
+        .map_err(XRPLModelException::ParseIntError)?;
+    if fee_drops == 0 {
+        return Err(XRPLModelException::ValueZero("fee".to_string()).into());
+    }
+    if last_ledger_sequence == 0 {
+        return Err(XRPLModelException::ValueZero("last_ledger_sequence".to_string()).into());
+    }
+    let txn_common_fields = transaction.get_mut_common_fields();
+    if txn_common_fields.ticket_sequence.is_some() {
+        // Transactions that use a ticket must have a `Sequence` of 0.
+        txn_common_fields.sequence = Some(0);
+    } else if txn_common_fields.sequence.is_none() {
+        if sequence == 0 {
+            return Err(XRPLModelException::ValueZero("sequence".to_string()).into());
+        }
+        txn_common_fields.sequence = Some(sequence);
+    }
+    if txn_common_fields.fee.is_none() {
+        txn_common_fields.fee = Some(fee);
+    }
+    if txn_common_fields.last_ledger_sequence.is_none() {
+        txn_common_fields.last_ledger_sequence = Some(last_ledger_sequence);
+    }
+    if txn_common_fields.network_id.is_none()
+        && network_id.is_some_and(|network_id| network_id > RESTRICTED_NETWORKS as u32)
+    {
+        txn_common_fields.network_id = network_id;
+    }
+
+    Ok(())
+}
+
+/// Autofills a batch of transactions sent from the same account. Fails
+/// if the transactions' `Account` fields differ.
+///
+/// The network's common fields, the fee and the latest validated ledger
+/// are fetched once and shared by the whole batch. Transactions without a
+/// `Sequence` are assigned incrementing sequence numbers, starting at
+/// `starting_sequence` or, if it is `None`, at the next valid sequence
+/// number of the first transaction's account. Transactions that use a
+/// ticket get a `Sequence` of 0 instead.
+pub async fn autofill_batch<'a, 'b, F, T, C>(
+    transactions: &mut [T],
+    client: &'b C,
+    starting_sequence: Option<u32>,
+) -> XRPLHelperResult<()>
+where
+    T: Transaction<'a, F> + Model + Clone,
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    C: XRPLAsyncClient,
+{
+    let Some(first) = transactions.first() else {
+        return Ok(());
+    };
+    let account = &first.get_common_fields().account;
+    if let Some(other) = transactions
+        .iter()
+        .map(|txn| &txn.get_common_fields().account)
+        .find(|other| *other != account)
+    {
+        return Err(XRPLTransactionHelperException::MixedBatchAccounts {
+            expected: account.to_string(),
+            found: other.to_string(),
+        }
+        .into());
+    }
+    let needs_sequence = transactions.iter().any(|txn| {
+        let txn_common_fields = txn.get_common_fields();
+        txn_common_fields.sequence.is_none() && txn_common_fields.ticket_sequence.is_none()
+    });
+    let common_fields = client.get_common_fields().await?;
+    let set_network_id = should_set_network_id(
+        common_fields.network_id,
+        common_fields.build_version.as_deref(),
+    );
+    let mut sequence = match starting_sequence {
+        Some(sequence) => sequence,
+        None if !needs_sequence => 0,
+        None => {
+            get_next_valid_seq_number(first.get_common_fields().account.clone(), client, None)
+                .await?
+        }
+    };
+    let net_fee = get_fee(client, None, None).await?;
+    let last_ledger_sequence =
+        get_latest_validated_ledger_sequence(client).await? + LEDGER_OFFSET as u32;
+
+    for transaction in transactions.iter_mut() {
+        let fee = if transaction.get_common_fields().fee.is_none() {
+            Some(
+                calculate_fee_from_net_fee(
+                    &*transaction,
+                    net_fee.clone(),
+                    Some(client),
+                    None,
+                    FeeOptions::default(),
+                )
+                .await?,
+            )
+        } else {
+            None
+        };
+        let txn_common_fields = transaction.get_mut_common_fields();
+        if txn_common_fields.network_id.is_none() && set_network_id {
+            txn_common_fields.network_id = common_fields.network_id;
+        }
+        if txn_common_fields.ticket_sequence.is_some() {
+            txn_common_fields.sequence = Some(0);
+        } else if txn_common_fields.sequence.is_none() {
+            txn_common_fields.sequence = Some(sequence);
+            sequence += 1;
+        }
+        if fee.is_some() {
+            txn_common_fields.fee = fee;
+        }
+        if txn_common_fields.last_ledger_sequence.is_none() {
+            txn_common_fields.last_ledger_sequence = Some(last_ledger_sequence);
+        }
+    }
+
+    Ok(())
+}
+
 pub async fn autofill_and_sign<'a, 'b, T, F, C>(
     transaction: &mut T,
     client: &'b C,
@@ -154,58 +446,286 @@ where
     Ok(())
 }
 
-pub async fn submit<'a, T, F, C>(transaction: &T, client: &C) -> XRPLHelperResult<SubmitResult<'a>>
+/// Submits a signed transaction.
+///
+/// With `check_signature` set, a single-signed transaction whose
+/// `TxnSignature` doesn't match its current fields, e.g. because it was
+/// mutated after signing, is refused before it is sent. With `fail_hard`
+/// set to `Some(true)`, the server doesn't retry or relay the transaction
+/// if it fails locally, instead of the default `false`.
+pub async fn submit<'a, T, F, C>(
+    transaction: &T,
+    client: &C,
+    check_signature: bool,
+    fail_hard: Option<bool>,
+) -> XRPLHelperResult<SubmitResult<'a>>
 where
     F: IntoEnumIterator + Serialize + Debug + PartialEq,
     T: Transaction<'a, F> + Model + Serialize + DeserializeOwned + Clone + Debug,
     C: XRPLAsyncClient,
 {
     transaction.validate()?;
+    // The node would reject it anyway, but with a less helpful error.
+    if !transaction.get_common_fields().is_signed() {
+        return Err(XRPLModelException::from(XRPLTransactionException::TxMustBeSigned).into());
+    }
+    if check_signature {
+        check_txn_signature_integrity(transaction)?;
+    }
     let txn_blob = encode(transaction)?;
-    let req = Submit::new(None, txn_blob.into(), None);
+
+    submit_blob(&txn_blob, client, fail_hard).await
+}
+
+/// Submits an already signed transaction blob as is, e.g. one signed
+/// offline, without decoding or validating it first.
+pub async fn submit_blob<'a, C>(
+    blob: &str,
+    client: &C,
+    fail_hard: Option<bool>,
+) -> XRPLHelperResult<SubmitResult<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    let req = Submit::new(None, blob.to_string().into(), fail_hard);
     let res = client.request(req.into()).await?;
 
     Ok(res.try_into_result::<SubmitResult<'_>>()?)
 }
 
+/// Dry-runs an unsigned transaction and returns the result and metadata
+/// it would have, without submitting it. Fields such as `Fee` and
+/// `Sequence` are autofilled by the server if missing.
+pub async fn simulate<'a, 'b, T, F, C>(
+    transaction: &T,
+    client: &C,
+) -> XRPLHelperResult<SimulateResult<'b>>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Serialize,
+    C: XRPLAsyncClient,
+{
+    let req = Simulate::from_transaction(None, transaction, None)?;
+    let res = client.request(req.into()).await?;
+
+    Ok(res.try_into_result::<SimulateResult<'_>>()?)
+}
+
+/// Creates `count` tickets for the wallet's account and returns their
+/// ticket sequences, read from the `Ticket` objects created by the
+/// validated `TicketCreate`.
+pub async fn create_tickets<C>(
+    count: u32,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Vec<u32>>
+where
+    C: XRPLAsyncClient,
+{
+    let mut ticket_create = TicketCreate::new(
+        wallet.classic_address.clone().into(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        count,
+    );
+    let tx = submit_and_wait(&mut ticket_create, client, Some(wallet), None, None).await?;
+
+    get_created_ticket_sequences(&tx.meta)
+}
+
+/// Returns the ticket sequences of the `Ticket` objects created according
+/// to a transaction's metadata, in ascending order.
+fn get_created_ticket_sequences(meta: &Value) -> XRPLHelperResult<Vec<u32>> {
+    let affected_nodes = meta
+        .get("AffectedNodes")
+        .and_then(Value::as_array)
+        .ok_or_else(|| XRPLModelException::MissingField("AffectedNodes".to_string()))?;
+    let mut ticket_sequences = affected_nodes
+        .iter()
+        .filter_map(|node| node.get("CreatedNode"))
+        .filter(|node| node.get("LedgerEntryType").and_then(Value::as_str) == Some("Ticket"))
+        .map(|node| {
+            node.get("NewFields")
+                .and_then(|fields| fields.get("TicketSequence"))
+                .and_then(Value::as_u64)
+                .and_then(|sequence| u32::try_from(sequence).ok())
+                .ok_or_else(|| XRPLModelException::MissingField("TicketSequence".to_string()))
+        })
+        .collect::<Result<Vec<u32>, _>>()?;
+    ticket_sequences.sort_unstable();
+
+    Ok(ticket_sequences)
+}
+
+/// Make sure a single-signed transaction was not mutated after signing,
+/// which would otherwise only surface as a rejected blob.
+fn check_txn_signature_integrity<'a, T, F>(transaction: &T) -> XRPLHelperResult<()>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Serialize,
+{
+    let common_fields = transaction.get_common_fields();
+    let (Some(signature), Some(public_key)) =
+        (&common_fields.txn_signature, &common_fields.signing_pub_key)
+    else {
+        return Ok(());
+    };
+    if public_key.is_empty() || is_valid_single_signature(transaction, signature, public_key)? {
+        Ok(())
+    } else {
+        Err(XRPLSignTransactionException::SignatureInvalidAfterMutation.into())
+    }
+}
+
+/// Verifies the signatures of a signed transaction without submitting it.
+///
+/// Multi-signed transactions are valid if every `Signer` signed the
+/// transaction for its account. Otherwise `TxnSignature` is checked against
+/// `SigningPubKey`. Returns `false` for transactions that are not signed
+/// or carry a malformed public key, and fails if a `Signer`'s `Account`
+/// is not a valid address.
+pub fn verify_signature<'a, T, F>(transaction: &T) -> XRPLHelperResult<bool>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Serialize,
+{
+    let common_fields = transaction.get_common_fields();
+    if let Some(signers) = common_fields.signers.as_ref().filter(|s| !s.is_empty()) {
+        for signer in signers {
+            if !is_valid_public_key(&signer.signing_pub_key) {
+                return Ok(false);
+            }
+            let serialized_for_signing =
+                encode_for_multisigning(transaction, signer.account.clone())?;
+            let serialized_bytes = hex::decode(serialized_for_signing)?;
+            if !is_valid_message(
+                &serialized_bytes,
+                &signer.txn_signature,
+                &signer.signing_pub_key,
+            ) {
+                return Ok(false);
+            }
+        }
+
+        return Ok(true);
+    }
+    match (&common_fields.txn_signature, &common_fields.signing_pub_key) {
+        (Some(signature), Some(public_key)) if !public_key.is_empty() => {
+            is_valid_single_signature(transaction, signature, public_key)
+        }
+        _ => Ok(false),
+    }
+}
+
+fn is_valid_single_signature<'a, T, F>(
+    transaction: &T,
+    signature: &str,
+    public_key: &str,
+) -> XRPLHelperResult<bool>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Serialize,
+{
+    if !is_valid_public_key(public_key) {
+        return Ok(false);
+    }
+    let serialized_bytes = hex::decode(encode_for_signing(transaction)?)?;
+
+    Ok(is_valid_message(&serialized_bytes, signature, public_key))
+}
+
+/// How a fractional fee is rounded to whole drops.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FeeRounding {
+    /// Round towards zero.
+    #[default]
+    Down,
+    /// Round away from zero, so the fee is never below the computed cost.
+    /// This avoids `telINSUF_FEE_P` on networks that enforce it.
+    Up,
+}
+
+impl From<FeeRounding> for RoundingMode {
+    fn from(rounding: FeeRounding) -> Self {
+        match rounding {
+            FeeRounding::Down => RoundingMode::Down,
+            FeeRounding::Up => RoundingMode::Up,
+        }
+    }
+}
+
+/// Optional inputs of [`calculate_fee_per_transaction_type`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeeOptions<'a> {
+    /// The owner reserve increment an `AccountDelete` or `AMMCreate`
+    /// costs. Takes precedence over the value read from the network's
+    /// `server_state`. Offline and without one, 2 XRP is assumed, which
+    /// may be above the network's current reserve.
+    pub owner_reserve: Option<XRPAmount<'a>>,
+    /// How a fractional fee is rounded, down by default.
+    pub rounding: FeeRounding,
+}
+
+/// Calculates the fee of a transaction, fetching the network fee from
+/// `client` if one is given. See [`FeeOptions`] for the owner reserve
+/// and rounding.
 pub async fn calculate_fee_per_transaction_type<'a, 'b, 'c, T, F, C>(
     transaction: &T,
     client: Option<&'b C>,
     signers_count: Option<u8>,
+    options: FeeOptions<'_>,
 ) -> XRPLHelperResult<XRPAmount<'c>>
 where
     T: Transaction<'a, F>,
     F: IntoEnumIterator + Serialize + Debug + PartialEq,
     C: XRPLAsyncClient,
 {
-    let mut net_fee = XRPAmount::from("10");
-    let base_fee;
-    if let Some(client) = client {
-        net_fee = get_fee(client, None, None).await?;
-        base_fee = match transaction.get_transaction_type() {
-            TransactionType::EscrowFinish => calculate_base_fee_for_escrow_finish(
-                net_fee.clone(),
-                transaction
-                    .get_field_value("fulfillment")?
-                    .map(|fulfillment| fulfillment.into()),
-            )?,
-            // TODO: same for TransactionType::AMMCreate
-            TransactionType::AccountDelete => get_owner_reserve_from_response(client).await?,
-            _ => net_fee.clone(),
-        };
-    } else {
-        base_fee = match transaction.get_transaction_type() {
-            TransactionType::EscrowFinish => calculate_base_fee_for_escrow_finish(
-                net_fee.clone(),
-                transaction
-                    .get_field_value("fulfillment")?
-                    .map(|fulfillment| fulfillment.into()),
-            )?,
-            // TODO: same for TransactionType::AMMCreate
-            TransactionType::AccountDelete => XRPAmount::from(OWNER_RESERVE),
-            _ => net_fee.clone(),
-        };
-    }
+    let net_fee = match client {
+        Some(client) => get_fee(client, None, None).await?,
+        None => XRPAmount::from("10"),
+    };
+
+    calculate_fee_from_net_fee(transaction, net_fee, client, signers_count, options).await
+}
+
+/// Calculates the fee of a transaction from an already known network fee.
+async fn calculate_fee_from_net_fee<'a, 'b, 'c, T, F, C>(
+    transaction: &T,
+    net_fee: XRPAmount<'_>,
+    client: Option<&'b C>,
+    signers_count: Option<u8>,
+    options: FeeOptions<'_>,
+) -> XRPLHelperResult<XRPAmount<'c>>
+where
+    T: Transaction<'a, F>,
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    C: XRPLAsyncClient,
+{
+    let rounding = RoundingMode::from(options.rounding);
+    let base_fee = match transaction.get_transaction_type() {
+        TransactionType::EscrowFinish => calculate_base_fee_for_escrow_finish(
+            net_fee.clone(),
+            serde_json::to_value(transaction)?
+                .get("Fulfillment")
+                .and_then(Value::as_str)
+                .map(|fulfillment| Cow::Owned(fulfillment.to_string())),
+            rounding,
+        )?,
+        TransactionType::AccountDelete | TransactionType::AMMCreate => {
+            match (options.owner_reserve, client) {
+                (Some(owner_reserve), _) => XRPAmount::from(owner_reserve.0.into_owned()),
+                (None, Some(client)) => get_reserve_increment_from_response(client).await?,
+                (None, None) => XRPAmount::from(OWNER_RESERVE),
+            }
+        }
+        _ => net_fee.clone(),
+    };
     let mut base_fee_decimal: BigDecimal = base_fee.try_into()?;
     if let Some(signers_count) = signers_count {
         let net_fee_decimal: BigDecimal = net_fee.try_into()?;
@@ -213,21 +733,117 @@ where
         base_fee_decimal += &(net_fee_decimal * signer_count_fee_decimal);
     }
 
-    Ok(base_fee_decimal
-        .with_scale_round(0, RoundingMode::Down)
-        .into())
+    Ok(base_fee_decimal.with_scale_round(0, rounding).into())
+}
+
+/// The XRP that leaves an account when a transaction is applied: the
+/// transaction cost plus the owner reserve locked by any ledger objects
+/// the transaction creates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionCost<'a> {
+    /// The transaction cost that is destroyed when the transaction is applied.
+    pub fee: XRPAmount<'a>,
+    /// The additional owner reserve locked by the objects created by the transaction.
+    pub reserve_increment: XRPAmount<'a>,
+}
+
+/// Estimates the total cost of a transaction before submitting it.
+///
+/// The fee is taken from the transaction's `Fee` field if set, otherwise it
+/// is calculated like `autofill` would: the `open_ledger_fee` reported by
+/// the network's `fee` method, which already includes the load and queue
+/// escalation multiplier. `EscrowFinish` with a fulfillment costs
+/// `33 + fulfillment length / 16` times that fee, and `AccountDelete` and
+/// `AMMCreate` cost one owner reserve instead.
+///
+/// The reserve increment is the network's `reserve_inc` from `server_state`
+/// times the number of objects the transaction creates for the sending
+/// account, assuming it succeeds.
+///
+/// The estimate is unreliable when:
+/// - the open ledger fee changes before the transaction is submitted, as it
+///   does under load;
+/// - the transaction is multisigned, since each signer adds the fee again;
+/// - fewer objects are created than assumed, e.g. an `OfferCreate` that is
+///   fully filled or a `TrustSet` that modifies an existing trust line;
+/// - the transaction frees objects, which is not subtracted.
+pub async fn estimate_cost<'a, 'b, T, F, C>(
+    transaction: &T,
+    client: &'b C,
+) -> XRPLHelperResult<TransactionCost<'a>>
+where
+    T: Transaction<'a, F> + Serialize,
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    C: XRPLAsyncClient,
+{
+    let fee = match &transaction.get_common_fields().fee {
+        Some(fee) => XRPAmount::from(fee.0.to_string()),
+        None => {
+            calculate_fee_per_transaction_type(
+                transaction,
+                Some(client),
+                None,
+                FeeOptions::default(),
+            )
+            .await?
+        }
+    };
+    let owned_objects = get_owned_objects_created(transaction)?;
+    let reserve_increment = if owned_objects == 0 {
+        XRPAmount::default()
+    } else {
+        let reserve_inc: BigDecimal = get_reserve_increment_from_response(client)
+            .await?
+            .try_into()?;
+        let total: BigDecimal = reserve_inc * BigDecimal::from(owned_objects);
+        XRPAmount::from(total.with_scale_round(0, RoundingMode::Down))
+    };
+
+    Ok(TransactionCost {
+        fee,
+        reserve_increment,
+    })
+}
+
+/// Returns the number of objects owned by the sending account that the
+/// transaction creates when it succeeds.
+fn get_owned_objects_created<'a, T, F>(transaction: &T) -> XRPLHelperResult<u32>
+where
+    T: Transaction<'a, F> + Serialize,
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+{
+    match transaction.get_transaction_type() {
+        TransactionType::CheckCreate
+        | TransactionType::CredentialCreate
+        | TransactionType::DepositPreauth
+        | TransactionType::EscrowCreate
+        | TransactionType::NFTokenCreateOffer
+        | TransactionType::NFTokenMint
+        | TransactionType::OfferCreate
+        | TransactionType::PaymentChannelCreate
+        | TransactionType::SignerListSet
+        | TransactionType::TrustSet
+        | TransactionType::XChainCreateClaimID => Ok(1),
+        TransactionType::TicketCreate => match transaction.get_field_value("TicketCount")? {
+            Some(ticket_count) => Ok(ticket_count
+                .parse::<u32>()
+                .map_err(XRPLModelException::ParseIntError)?),
+            None => Err(XRPLModelException::MissingField("ticket_count".to_string()).into()),
+        },
+        _ => Ok(0),
+    }
 }
 
-async fn get_owner_reserve_from_response(
+async fn get_reserve_increment_from_response(
     client: &impl XRPLAsyncClient,
 ) -> XRPLHelperResult<XRPAmount<'_>> {
-    let owner_reserve_response = client.request(ServerState::new(None).into()).await?;
-    match owner_reserve_response
+    let server_state_response = client.request(ServerState::new(None).into()).await?;
+    match server_state_response
         .try_into_result::<ServerStateResult<'_>>()?
         .state
         .validated_ledger
     {
-        Some(validated_ledger) => Ok(validated_ledger.reserve_base),
+        Some(validated_ledger) => Ok(validated_ledger.reserve_inc),
         None => Err(XRPLModelException::MissingField("validated_ledger".to_string()).into()),
     }
 }
@@ -235,9 +851,10 @@ async fn get_owner_reserve_from_response(
 fn calculate_base_fee_for_escrow_finish<'a: 'b, 'b>(
     net_fee: XRPAmount<'a>,
     fulfillment: Option<Cow<str>>,
+    rounding: RoundingMode,
 ) -> XRPLHelperResult<XRPAmount<'b>> {
     if let Some(fulfillment) = fulfillment {
-        calculate_based_on_fulfillment(fulfillment, net_fee)
+        calculate_based_on_fulfillment(fulfillment, net_fee, rounding)
     } else {
         Ok(net_fee)
     }
@@ -246,6 +863,7 @@ fn calculate_base_fee_for_escrow_finish<'a: 'b, 'b>(
 fn calculate_based_on_fulfillment<'a>(
     fulfillment: Cow<str>,
     net_fee: XRPAmount<'_>,
+    rounding: RoundingMode,
 ) -> XRPLHelperResult<XRPAmount<'a>> {
     let fulfillment_bytes: Vec<u8> = fulfillment.chars().map(|c| c as u8).collect();
     let net_fee_f64: f64 = net_fee.try_into()?;
@@ -254,27 +872,27 @@ fn calculate_based_on_fulfillment<'a>(
     let base_fee: XRPAmount = base_fee_string.into();
     let base_fee_decimal: BigDecimal = base_fee.try_into()?;
 
-    Ok(base_fee_decimal
-        .with_scale_round(0, RoundingMode::Down)
-        .into())
+    Ok(base_fee_decimal.with_scale_round(0, rounding).into())
 }
 
-fn txn_needs_network_id(common_fields: CommonFields<'_>) -> XRPLHelperResult<bool> {
-    let is_higher_restricted_networks = if let Some(network_id) = common_fields.network_id {
-        network_id > RESTRICTED_NETWORKS as u32
-    } else {
-        false
+/// Returns whether a transaction for the given network must carry the
+/// `NetworkID` field.
+///
+/// Networks with an ID of 1024 or lower (including mainnet, testnet and
+/// devnet) must omit the field. Networks above 1024 require it, as long as
+/// the server runs rippled 1.11.0 or later. An unknown or unparsable
+/// `build_version` is treated as not supporting the field.
+pub fn should_set_network_id(network_id: Option<u32>, build_version: Option<&str>) -> bool {
+    let is_higher_restricted_networks = match network_id {
+        Some(network_id) => network_id > RESTRICTED_NETWORKS as u32,
+        None => false,
     };
-    if let Some(build_version) = common_fields.build_version {
-        match is_not_later_rippled_version(REQUIRED_NETWORKID_VERSION.into(), build_version.into())
-        {
-            Ok(is_not_later_rippled_version) => {
-                Ok(is_higher_restricted_networks && is_not_later_rippled_version)
-            }
-            Err(e) => Err(e.into()),
+    match build_version {
+        Some(build_version) if is_higher_restricted_networks => {
+            is_not_later_rippled_version(REQUIRED_NETWORKID_VERSION.into(), build_version.into())
+                .unwrap_or(false)
         }
-    } else {
-        Ok(false)
+        _ => false,
     }
 }
 
@@ -290,6 +908,12 @@ fn is_not_later_rippled_version<'a>(source: String, target: String) -> XRPLHelpe
             .split('.')
             .map(|i| i.to_string())
             .collect::<Vec<String>>();
+        if source_decomp.len() < 3 {
+            return Err(XRPLTransactionHelperException::InvalidRippledVersion(source).into());
+        }
+        if target_decomp.len() < 3 {
+            return Err(XRPLTransactionHelperException::InvalidRippledVersion(target).into());
+        }
         let (source_major, source_minor) = (
             source_decomp[0]
                 .parse::<u8>()
@@ -365,8 +989,10 @@ where
     C: XRPLAsyncClient,
 {
     // max of xrp_to_drops(0.1) and calculate_fee_per_transaction_type
-    let expected_fee = XRPAmount::from("100000")
-        .max(calculate_fee_per_transaction_type(transaction, Some(client), None).await?);
+    let expected_fee = XRPAmount::from("100000").max(
+        calculate_fee_per_transaction_type(transaction, Some(client), None, FeeOptions::default())
+            .await?,
+    );
     let transaction_fee = transaction
         .get_common_fields()
         .fee
@@ -379,13 +1005,16 @@ where
     }
 }
 
-fn prepare_transaction<'a, T, F>(transaction: &mut T, wallet: &Wallet) -> XRPLHelperResult<()>
+fn prepare_transaction<'a, T, F>(
+    transaction: &mut T,
+    signing_pub_key: Cow<'a, str>,
+) -> XRPLHelperResult<()>
 where
     F: IntoEnumIterator + Serialize + Debug + PartialEq,
     T: Transaction<'a, F> + Serialize + DeserializeOwned + Clone,
 {
     let commond_fields = transaction.get_mut_common_fields();
-    commond_fields.signing_pub_key = Some(wallet.public_key.clone().into());
+    commond_fields.signing_pub_key = Some(signing_pub_key);
 
     validate_account_xaddress(transaction, AccountFieldType::Account)?;
     if validate_transaction_has_field(transaction, "Destination").is_ok() {
@@ -457,6 +1086,25 @@ where
     }
 }
 
+/// Makes sure a tag given alongside an X-address matches the tag
+/// encoded in the X-address, so a mismatch can be caught before the
+/// transaction is signed.
+///
+/// Classic addresses carry no tag and always pass. An untagged X-address
+/// given together with a tag is a mismatch.
+pub fn validate_xaddress_tag_consistency(xaddress: &str, tag: Option<u32>) -> XRPLHelperResult<()> {
+    if !is_valid_xaddress(xaddress) {
+        return Ok(());
+    }
+    let (_, xaddress_tag, _) = xaddress_to_classic_address(xaddress)?;
+    match tag {
+        Some(tag) if xaddress_tag != Some(tag.into()) => {
+            Err(XRPLSignTransactionException::TagFieldMismatch("tag".to_string()).into())
+        }
+        _ => Ok(()),
+    }
+}
+
 fn convert_to_classic_address<'a, T, F>(
     transaction: &mut T,
     field_name: &str,
@@ -481,6 +1129,1101 @@ where
     }
 }
 
+#[cfg(test)]
+mod test_network_id {
+    use super::should_set_network_id;
+
+    #[test]
+    fn test_should_set_network_id() {
+        // mainnet, testnet and devnet never carry a NetworkID
+        assert!(!should_set_network_id(Some(0), Some("2.2.0")));
+        assert!(!should_set_network_id(Some(1), Some("2.2.0")));
+        assert!(!should_set_network_id(Some(1024), Some("2.2.0")));
+        // sidechains above 1024 on a recent enough rippled do
+        assert!(should_set_network_id(Some(1025), Some("1.11.0")));
+        assert!(should_set_network_id(Some(21338), Some("2.2.0-rc1")));
+        // old rippled versions do not support the field
+        assert!(!should_set_network_id(Some(1025), Some("1.10.1")));
+        // missing or malformed information
+        assert!(!should_set_network_id(None, Some("2.2.0")));
+        assert!(!should_set_network_id(Some(1025), None));
+        assert!(!should_set_network_id(Some(1025), Some("2.2")));
+        assert!(!should_set_network_id(Some(1025), Some("latest")));
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_estimate_cost {
+    use super::estimate_cost;
+    use crate::{
+        asynch::test_cases::{client, ACCOUNT},
+        models::{
+            requests::RequestMethod,
+            transactions::{
+                offer_create::OfferCreate, payment::Payment, ticket_create::TicketCreate,
+            },
+            IssuedCurrencyAmount, XRPAmount,
+        },
+    };
+
+    #[tokio::test]
+    async fn test_estimate_cost_offer_create() {
+        let client = client();
+        let txn = OfferCreate::new(
+            ACCOUNT.into(),
+            None,
+            Some("12".into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            XRPAmount::from("1000000").into(),
+            IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq".into(),
+                "0.3".into(),
+            )
+            .into(),
+            None,
+            None,
+        );
+        let cost = estimate_cost(&txn, &client).await.unwrap();
+
+        assert_eq!(cost.fee, XRPAmount::from("12"));
+        assert_eq!(cost.reserve_increment, XRPAmount::from("200000"));
+        assert_eq!(client.requests(), [RequestMethod::ServerState]);
+        assert_eq!(
+            client.last_request(&RequestMethod::ServerState).unwrap()["command"],
+            "server_state"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_estimate_cost_ticket_create() {
+        let client = client();
+        let txn = TicketCreate::new(
+            ACCOUNT.into(),
+            None,
+            Some("12".into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            3,
+        );
+        let cost = estimate_cost(&txn, &client).await.unwrap();
+
+        assert_eq!(cost.reserve_increment, XRPAmount::from("600000"));
+        assert_eq!(client.requests(), [RequestMethod::ServerState]);
+    }
+
+    #[tokio::test]
+    async fn test_estimate_cost_payment() {
+        let client = client();
+        let txn = Payment::new(
+            ACCOUNT.into(),
+            None,
+            Some("12".into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            XRPAmount::from("1000000").into(),
+            "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq".into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let cost = estimate_cost(&txn, &client).await.unwrap();
+
+        assert_eq!(cost.fee, XRPAmount::from("12"));
+        assert_eq!(cost.reserve_increment, XRPAmount::from("0"));
+        assert!(client.requests().is_empty());
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_submit_integrity {
+    use alloc::{string::ToString, vec};
+
+    use super::{check_txn_signature_integrity, sign, submit};
+    use crate::{
+        asynch::{
+            clients::MockClient,
+            exceptions::XRPLHelperException,
+            transaction::exceptions::{
+                XRPLSignTransactionException, XRPLTransactionHelperException,
+            },
+        },
+        models::{
+            requests::RequestMethod,
+            transactions::{payment::Payment, Memo},
+            XRPAmount,
+        },
+        wallet::Wallet,
+    };
+
+    /// A payment signed by `wallet`, then given a memo.
+    fn mutated_payment<'a>(wallet: &Wallet) -> Payment<'a> {
+        let mut txn = Payment::new(
+            wallet.classic_address.clone().into(),
+            None,
+            Some("12".into()),
+            None,
+            Some(7835923),
+            None,
+            Some(227234),
+            None,
+            None,
+            None,
+            XRPAmount::from("1000000").into(),
+            "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq".into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        sign(&mut txn, wallet, false).unwrap();
+        assert!(check_txn_signature_integrity(&txn).is_ok());
+
+        txn.common_fields.memos = Some(vec![Memo::new(
+            Some("6D7574617465640A".to_string()),
+            Some("746578742F706C61696E".to_string()),
+            Some("6E6F7465".to_string()),
+        )]);
+
+        txn
+    }
+
+    #[tokio::test]
+    async fn test_submit_refuses_mutated_transaction() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let txn = mutated_payment(&wallet);
+        let client = MockClient::new();
+        let error = submit(&txn, &client, true, None).await.unwrap_err();
+
+        assert!(matches!(
+            error,
+            XRPLHelperException::XRPLTransactionHelperError(
+                XRPLTransactionHelperException::XRPLSignTransactionError(
+                    XRPLSignTransactionException::SignatureInvalidAfterMutation
+                )
+            )
+        ));
+        // The transaction never reached the network.
+        assert!(client.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_submit_without_integrity_check() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let txn = mutated_payment(&wallet);
+        let client = MockClient::new();
+        // The mock has no response queued, so only the request is of interest.
+        let _ = submit(&txn, &client, false, None).await;
+
+        assert_eq!(client.request_count(&RequestMethod::Submit), 1);
+    }
+
+    #[tokio::test]
+    async fn test_submit_malformed_signing_pub_key() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let mut txn = mutated_payment(&wallet);
+        txn.common_fields.signing_pub_key = Some("ED00".into());
+        let client = MockClient::new();
+        let error = submit(&txn, &client, true, None).await.unwrap_err();
+
+        assert!(matches!(
+            error,
+            XRPLHelperException::XRPLTransactionHelperError(
+                XRPLTransactionHelperException::XRPLSignTransactionError(
+                    XRPLSignTransactionException::SignatureInvalidAfterMutation
+                )
+            )
+        ));
+        assert!(client.requests().is_empty());
+    }
+}
+
+#[cfg(test)]
+mod test_autofill_offline {
+    use super::{autofill_offline, sign};
+    use crate::{
+        asynch::test_cases::{account_set, ACCOUNT},
+        models::{transactions::Transaction, XRPAmount},
+        wallet::Wallet,
+    };
+
+    #[test]
+    fn test_autofill_offline_and_sign() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let mut txn = account_set(&wallet.classic_address);
+        autofill_offline(&mut txn, 7, XRPAmount::from("12"), 1020, Some(21338)).unwrap();
+
+        let common_fields = txn.get_common_fields();
+        assert_eq!(common_fields.sequence, Some(7));
+        assert_eq!(common_fields.fee, Some(XRPAmount::from("12")));
+        assert_eq!(common_fields.last_ledger_sequence, Some(1020));
+        assert_eq!(common_fields.network_id, Some(21338));
+
+        sign(&mut txn, &wallet, false).unwrap();
+        assert!(txn.is_signed());
+    }
+
+    #[test]
+    fn test_autofill_offline_restricted_network() {
+        let mut txn = account_set(ACCOUNT);
+        autofill_offline(&mut txn, 7, XRPAmount::from("12"), 1020, Some(1)).unwrap();
+
+        assert_eq!(txn.get_common_fields().network_id, None);
+    }
+
+    #[test]
+    fn test_autofill_offline_invalid_inputs() {
+        let mut txn = account_set(ACCOUNT);
+
+        assert!(autofill_offline(&mut txn, 0, XRPAmount::from("12"), 1020, None).is_err());
+        assert!(autofill_offline(&mut txn, 7, XRPAmount::from("0"), 1020, None).is_err());
+        assert!(autofill_offline(&mut txn, 7, XRPAmount::from("1.5"), 1020, None).is_err());
+        assert!(autofill_offline(&mut txn, 7, XRPAmount::from("12"), 0, None).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_multisign_with {
+    use alloc::{string::ToString, vec, vec::Vec};
+
+    use super::{multisign_with, sign, verify_signature};
+    use crate::{
+        asynch::{
+            exceptions::XRPLHelperException,
+            test_cases::{filled_account_set, ACCOUNT},
+        },
+        core::addresscodec::decode_classic_address,
+        models::{
+            ledger::objects::signer_list::{SignerEntry, SignerList},
+            transactions::Transaction,
+        },
+        transaction::exceptions::XRPLMultisignException,
+        wallet::Wallet,
+    };
+
+    /// A two-of-three signer list.
+    fn signer_list<'a>(members: &[Wallet]) -> SignerList<'a> {
+        SignerList::new(
+            vec![].into(),
+            None,
+            None,
+            "0000000000000000".into(),
+            "5904C0DC72C58A83AEFED2FFC5386356AA83FCA6A88C89D00646E51E687CDBE4".into(),
+            1,
+            members
+                .iter()
+                .map(|wallet| SignerEntry::new(wallet.classic_address.to_string(), 1, None))
+                .collect(),
+            0,
+            2,
+        )
+    }
+
+    /// Members of the signer list with fixed keys.
+    fn members() -> [Wallet; 3] {
+        [
+            Wallet::new("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r", 0).unwrap(),
+            Wallet::new("sp5fghtJtpUorTwvof1NpDXAzNwf5", 0).unwrap(),
+            Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_quorum_met() {
+        let members = members();
+        let outsider = Wallet::new("snoPBrXtMeMyMHUVTgbuqAfg1SUTb", 0).unwrap();
+        let mut txn = filled_account_set(ACCOUNT);
+        multisign_with(
+            &mut txn,
+            &[&members[0], &outsider, &members[2]],
+            &signer_list(&members),
+        )
+        .unwrap();
+
+        let signers = txn.get_common_fields().signers.as_ref().unwrap();
+        assert_eq!(signers.len(), 2);
+        assert!(signers
+            .iter()
+            .all(|signer| signer.account != outsider.classic_address));
+        assert_eq!(txn.get_common_fields().signing_pub_key.as_deref(), Some(""));
+        assert!(verify_signature(&txn).unwrap());
+    }
+
+    #[test]
+    fn test_quorum_not_met() {
+        let members = members();
+        let mut txn = filled_account_set(ACCOUNT);
+        let error = multisign_with(
+            &mut txn,
+            &[&members[1], &members[1]],
+            &signer_list(&members),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error,
+            XRPLHelperException::XRPLMultiSignError(
+                XRPLMultisignException::InsufficientSignerWeight { missing: 1 }
+            )
+        ));
+        assert!(txn.get_common_fields().signers.is_none());
+    }
+
+    #[test]
+    fn test_adds_to_existing_signers() {
+        let members = members();
+        let mut txn = filled_account_set(ACCOUNT);
+        txn.common_fields.signing_pub_key = Some("".into());
+        sign(&mut txn, &members[1], true).unwrap();
+        // The existing signature counts towards the quorum.
+        multisign_with(
+            &mut txn,
+            &[&members[1], &members[2]],
+            &signer_list(&members),
+        )
+        .unwrap();
+
+        let signers = txn.get_common_fields().signers.as_ref().unwrap();
+        assert_eq!(signers.len(), 2);
+        assert!(signers
+            .iter()
+            .any(|signer| signer.account == members[1].classic_address));
+        assert!(verify_signature(&txn).unwrap());
+    }
+
+    #[test]
+    fn test_signers_sorted_by_account_id() {
+        let members = members();
+        let mut txn = filled_account_set(ACCOUNT);
+        multisign_with(
+            &mut txn,
+            &[&members[0], &members[1], &members[2]],
+            &signer_list(&members),
+        )
+        .unwrap();
+
+        let account_ids: Vec<_> = txn
+            .get_common_fields()
+            .signers
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|signer| decode_classic_address(&signer.account).unwrap())
+            .collect();
+        assert!(account_ids.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(verify_signature(&txn).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_signer_address() {
+        let [first, mut second, _] = members();
+        second.classic_address = "rInvalid".to_string();
+        let members = [first, second];
+        let mut txn = filled_account_set(ACCOUNT);
+        let error = multisign_with(
+            &mut txn,
+            &[&members[0], &members[1]],
+            &signer_list(&members),
+        )
+        .unwrap_err();
+
+        assert!(matches!(error, XRPLHelperException::XRPLCoreError(_)));
+        assert!(txn.get_common_fields().signers.is_none());
+    }
+}
+
+#[cfg(test)]
+mod test_submit_unsigned {
+    use super::submit;
+    use crate::{
+        asynch::{
+            clients::MockClient,
+            exceptions::XRPLHelperException,
+            test_cases::{filled_account_set, ACCOUNT},
+        },
+        models::{transactions::exceptions::XRPLTransactionException, XRPLModelException},
+    };
+
+    #[tokio::test]
+    async fn test_submit_unsigned_transaction() {
+        let client = MockClient::new();
+        let txn = filled_account_set(ACCOUNT);
+        let error = submit(&txn, &client, false, None).await.unwrap_err();
+
+        assert!(matches!(
+            error,
+            XRPLHelperException::XRPLModelError(XRPLModelException::XRPLTransactionError(
+                XRPLTransactionException::TxMustBeSigned
+            ))
+        ));
+        assert!(client.requests().is_empty());
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_common_fields_cache {
+    use core::time::Duration;
+
+    use super::autofill;
+    use crate::{
+        asynch::test_cases::{client, filled_account_set, ACCOUNT, NETWORK_ID},
+        models::requests::RequestMethod,
+    };
+
+    #[tokio::test]
+    async fn test_autofill_reuses_cached_common_fields() {
+        let client = client().with_common_fields_ttl(Duration::from_secs(60));
+        let mut first = filled_account_set(ACCOUNT);
+        let mut second = filled_account_set(ACCOUNT);
+        autofill(&mut first, &client, None).await.unwrap();
+        autofill(&mut second, &client, None).await.unwrap();
+
+        assert_eq!(first.common_fields.network_id, Some(NETWORK_ID));
+        assert_eq!(second.common_fields.network_id, Some(NETWORK_ID));
+        assert_eq!(client.request_count(&RequestMethod::ServerState), 1);
+    }
+
+    #[tokio::test]
+    async fn test_autofill_refetches_expired_common_fields() {
+        let client = client().with_common_fields_ttl(Duration::ZERO);
+        autofill(&mut filled_account_set(ACCOUNT), &client, None)
+            .await
+            .unwrap();
+        autofill(&mut filled_account_set(ACCOUNT), &client, None)
+            .await
+            .unwrap();
+
+        assert_eq!(client.request_count(&RequestMethod::ServerState), 2);
+    }
+}
+
+#[cfg(test)]
+mod test_simulate {
+    use serde_json::json;
+
+    use super::simulate;
+    use crate::{
+        asynch::{
+            clients::MockClient,
+            test_cases::{account_set, ACCOUNT},
+        },
+        models::requests::RequestMethod,
+    };
+
+    #[tokio::test]
+    async fn test_simulate() {
+        let client = MockClient::new();
+        client.add_result(
+            RequestMethod::Simulate,
+            serde_json::from_value(json!({
+                "applied": false,
+                "engine_result": "tesSUCCESS",
+                "engine_result_code": 0,
+                "engine_result_message": "The simulated transaction would have been applied.",
+                "ledger_index": 3,
+                "meta": {
+                    "AffectedNodes": [],
+                    "TransactionIndex": 0,
+                    "TransactionResult": "tesSUCCESS"
+                },
+                "tx_json": {
+                    "Account": ACCOUNT,
+                    "Fee": "10",
+                    "Sequence": 1,
+                    "SigningPubKey": "",
+                    "TransactionType": "AccountSet",
+                    "TxnSignature": ""
+                }
+            }))
+            .unwrap(),
+        );
+        let tx = account_set(ACCOUNT);
+        let result = simulate(&tx, &client).await.unwrap();
+
+        assert!(result.is_success());
+        assert!(!result.applied);
+        assert_eq!(result.meta.unwrap()["TransactionResult"], "tesSUCCESS");
+        assert_eq!(result.tx_json.unwrap()["Fee"], "10");
+        let request = client.last_request(&RequestMethod::Simulate).unwrap();
+        assert_eq!(request["command"], "simulate");
+        assert_eq!(request["tx_json"]["Account"], ACCOUNT);
+    }
+}
+
+#[cfg(test)]
+mod test_owner_reserve {
+    use super::{calculate_fee_per_transaction_type, FeeOptions};
+    use crate::{
+        asynch::{
+            clients::MockClient,
+            test_cases::{client, ACCOUNT},
+        },
+        models::{
+            requests::RequestMethod,
+            transactions::{account_delete::AccountDelete, amm_create::AMMCreate},
+            IssuedCurrencyAmount, XRPAmount,
+        },
+    };
+
+    fn account_delete<'a>() -> AccountDelete<'a> {
+        AccountDelete::new(
+            ACCOUNT.into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe".into(),
+            None,
+        )
+    }
+
+    fn amm_create<'a>() -> AMMCreate<'a> {
+        AMMCreate::new(
+            ACCOUNT.into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            XRPAmount::from("250000000").into(),
+            IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe".into(),
+                "25".into(),
+            )
+            .into(),
+            500,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_offline_supplied_owner_reserve() {
+        let fee = calculate_fee_per_transaction_type(
+            &account_delete(),
+            None::<&MockClient>,
+            None,
+            FeeOptions {
+                owner_reserve: Some(XRPAmount::from("200000")),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(fee, XRPAmount::from("200000"));
+    }
+
+    #[tokio::test]
+    async fn test_offline_default_owner_reserve() {
+        let fee = calculate_fee_per_transaction_type(
+            &account_delete(),
+            None::<&MockClient>,
+            None,
+            FeeOptions::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(fee, XRPAmount::from("2000000"));
+    }
+
+    #[tokio::test]
+    async fn test_owner_reserve_from_server_state() {
+        let client = client();
+        let fee = calculate_fee_per_transaction_type(
+            &account_delete(),
+            Some(&client),
+            None,
+            FeeOptions::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(fee, XRPAmount::from("200000"));
+        assert_eq!(client.request_count(&RequestMethod::ServerState), 1);
+    }
+
+    #[tokio::test]
+    async fn test_amm_create_fee() {
+        let fee = calculate_fee_per_transaction_type(
+            &amm_create(),
+            None::<&MockClient>,
+            None,
+            FeeOptions {
+                owner_reserve: Some(XRPAmount::from("200000")),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(fee, XRPAmount::from("200000"));
+
+        let client = client();
+        let fee = calculate_fee_per_transaction_type(
+            &amm_create(),
+            Some(&client),
+            None,
+            FeeOptions::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(fee, XRPAmount::from("200000"));
+        assert_eq!(client.request_count(&RequestMethod::ServerState), 1);
+    }
+}
+
+#[cfg(test)]
+mod test_fee_rounding {
+    use bigdecimal::RoundingMode;
+
+    use super::{
+        calculate_based_on_fulfillment, calculate_fee_per_transaction_type, FeeOptions, FeeRounding,
+    };
+    use crate::{
+        asynch::clients::MockClient,
+        models::{transactions::escrow_finish::EscrowFinish, XRPAmount},
+    };
+
+    /// A one byte fulfillment costs 10 * (33 + 1/16) = 330.625 drops.
+    const FULFILLMENT: &str = "A";
+
+    fn escrow_finish<'a>() -> EscrowFinish<'a> {
+        EscrowFinish::new(
+            "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+            7,
+            Some(
+                "A0258020E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855810100"
+                    .into(),
+            ),
+            Some(FULFILLMENT.into()),
+        )
+    }
+
+    async fn escrow_finish_fee(rounding: FeeRounding) -> XRPAmount<'static> {
+        calculate_fee_per_transaction_type(
+            &escrow_finish(),
+            None::<&MockClient>,
+            None,
+            FeeOptions {
+                rounding,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_fee_rounding() {
+        assert_eq!(
+            escrow_finish_fee(FeeRounding::default()).await,
+            XRPAmount::from("330")
+        );
+        assert_eq!(
+            escrow_finish_fee(FeeRounding::Down).await,
+            XRPAmount::from("330")
+        );
+        assert_eq!(
+            escrow_finish_fee(FeeRounding::Up).await,
+            XRPAmount::from("331")
+        );
+    }
+
+    #[test]
+    fn test_round_down() {
+        let fee =
+            calculate_based_on_fulfillment(FULFILLMENT.into(), "10".into(), RoundingMode::Down)
+                .unwrap();
+
+        assert_eq!(fee, XRPAmount::from("330"));
+    }
+
+    #[test]
+    fn test_round_up() {
+        let fee = calculate_based_on_fulfillment(FULFILLMENT.into(), "10".into(), RoundingMode::Up)
+            .unwrap();
+
+        assert_eq!(fee, XRPAmount::from("331"));
+    }
+}
+
+#[cfg(test)]
+mod test_submit_blob {
+    use super::{sign, submit, submit_blob};
+    use crate::{
+        asynch::{
+            clients::MockClient,
+            test_cases::{filled_account_set, submit_result},
+        },
+        models::requests::RequestMethod,
+        wallet::Wallet,
+    };
+
+    const TX_BLOB: &str = "1200002280000000240000000361D4838D7EA4C6800000000000000000000000000055534400000000004B4E9C06F24296074F7BC48F92A97916C6DC5EA968400000000000000A732103AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB74473045022100D184EB4AE5956FF600E7536EE459345C7BBCF097A84CC61A93B9AF7197EDB98702201CEA8009B7BEEBAA2AACC0359B41C427C1C5B550A4CA4B80CF2174AF2D6D5DCE81144B4E9C06F24296074F7BC48F92A97916C6DC5EA983143E9D4A2B8AA0780F682D136F7A56D6724EF53754";
+
+    fn client() -> MockClient {
+        let client = MockClient::new();
+        client.add_result(
+            RequestMethod::Submit,
+            submit_result("tesSUCCESS", 0, TX_BLOB),
+        );
+
+        client
+    }
+
+    #[tokio::test]
+    async fn test_submit_blob() {
+        let client = client();
+        let result = submit_blob(TX_BLOB, &client, None).await.unwrap();
+
+        assert!(result.is_success());
+        assert_eq!(result.tx_blob, TX_BLOB);
+        assert_eq!(client.requests(), [RequestMethod::Submit]);
+        let request = client.last_request(&RequestMethod::Submit).unwrap();
+        assert_eq!(request["tx_blob"], TX_BLOB);
+        assert!(request.get("fail_hard").is_none());
+
+        submit_blob(TX_BLOB, &client, Some(true)).await.unwrap();
+        let request = client.last_request(&RequestMethod::Submit).unwrap();
+        assert_eq!(request["fail_hard"], true);
+    }
+
+    #[tokio::test]
+    async fn test_submit_fail_hard() {
+        let client = client();
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let mut txn = filled_account_set(&wallet.classic_address);
+        sign(&mut txn, &wallet, false).unwrap();
+        submit(&txn, &client, false, Some(true)).await.unwrap();
+
+        let request = client.last_request(&RequestMethod::Submit).unwrap();
+        assert_eq!(request["fail_hard"], true);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_autofill_ticket {
+    use super::autofill;
+    use crate::{
+        asynch::test_cases::{client, filled_account_set, ACCOUNT},
+        models::requests::RequestMethod,
+    };
+
+    #[tokio::test]
+    async fn test_autofill_with_ticket() {
+        let client = client();
+        let mut txn = filled_account_set(ACCOUNT);
+        txn.common_fields.sequence = None;
+        txn.common_fields.ticket_sequence = Some(8);
+        autofill(&mut txn, &client, None).await.unwrap();
+
+        assert_eq!(txn.common_fields.sequence, Some(0));
+        assert_eq!(txn.common_fields.ticket_sequence, Some(8));
+        assert_eq!(client.request_count(&RequestMethod::AccountInfo), 0);
+    }
+}
+
+#[cfg(test)]
+mod test_xaddress_tag_consistency {
+    use super::validate_xaddress_tag_consistency;
+
+    const TAGGED_XADDRESS: &str = "X7AcgcsBL6XDcUb289X4mJ8djcdyKaGZMhc9YTE92ehJ2Fu";
+    const UNTAGGED_XADDRESS: &str = "X7AcgcsBL6XDcUb289X4mJ8djcdyKaB5hJDWMArnXr61cqZ";
+
+    #[test]
+    fn test_validate_xaddress_tag_consistency() {
+        assert!(validate_xaddress_tag_consistency(TAGGED_XADDRESS, Some(1)).is_ok());
+        assert!(validate_xaddress_tag_consistency(TAGGED_XADDRESS, None).is_ok());
+        assert!(validate_xaddress_tag_consistency(TAGGED_XADDRESS, Some(2)).is_err());
+        assert!(validate_xaddress_tag_consistency(UNTAGGED_XADDRESS, None).is_ok());
+        assert!(validate_xaddress_tag_consistency(UNTAGGED_XADDRESS, Some(1)).is_err());
+        assert!(
+            validate_xaddress_tag_consistency("r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59", Some(1))
+                .is_ok()
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_verify_signature {
+    use super::{sign, sign_with_key, verify_signature};
+    use crate::{
+        models::{transactions::payment::Payment, XRPAmount},
+        wallet::Wallet,
+    };
+
+    fn payment<'a>(wallet: &Wallet) -> Payment<'a> {
+        Payment::new(
+            wallet.classic_address.clone().into(),
+            None,
+            Some("12".into()),
+            None,
+            Some(7835923),
+            None,
+            Some(227234),
+            None,
+            None,
+            None,
+            XRPAmount::from("1000000").into(),
+            "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq".into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_verify_single_signature() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let mut txn = payment(&wallet);
+        assert!(!verify_signature(&txn).unwrap());
+
+        sign(&mut txn, &wallet, false).unwrap();
+        assert!(verify_signature(&txn).unwrap());
+
+        txn.common_fields.sequence = Some(227235);
+        assert!(!verify_signature(&txn).unwrap());
+    }
+
+    #[test]
+    fn test_verify_multisignature() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let mut txn = payment(&wallet);
+        txn.common_fields.signing_pub_key = Some("".into());
+        sign(&mut txn, &wallet, true).unwrap();
+        assert!(verify_signature(&txn).unwrap());
+
+        txn.destination = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into();
+        assert!(!verify_signature(&txn).unwrap());
+    }
+
+    #[test]
+    fn test_sign_with_key() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        for multisign in [false, true] {
+            let mut signed = payment(&wallet);
+            let mut signed_with_key = payment(&wallet);
+            if multisign {
+                signed.common_fields.signing_pub_key = Some("".into());
+                signed_with_key.common_fields.signing_pub_key = Some("".into());
+            }
+            sign(&mut signed, &wallet, multisign).unwrap();
+            sign_with_key(&mut signed_with_key, wallet.signing_key(), multisign).unwrap();
+
+            assert_eq!(signed, signed_with_key);
+            assert!(verify_signature(&signed_with_key).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_verify_malformed_public_key() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let mut signed = payment(&wallet);
+        sign(&mut signed, &wallet, false).unwrap();
+        let mut multisigned = payment(&wallet);
+        multisigned.common_fields.signing_pub_key = Some("".into());
+        sign(&mut multisigned, &wallet, true).unwrap();
+
+        for public_key in ["", "E", "ED", "ED00", "zz"] {
+            let mut txn = signed.clone();
+            txn.common_fields.signing_pub_key = Some(public_key.into());
+            assert!(!verify_signature(&txn).unwrap());
+
+            let mut txn = multisigned.clone();
+            txn.common_fields.signers.as_mut().unwrap()[0].signing_pub_key = public_key.into();
+            assert!(!verify_signature(&txn).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_verify_invalid_signer_account() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let mut txn = payment(&wallet);
+        txn.common_fields.signing_pub_key = Some("".into());
+        sign(&mut txn, &wallet, true).unwrap();
+        txn.common_fields.signers.as_mut().unwrap()[0].account = "rInvalid".into();
+
+        assert!(verify_signature(&txn).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_create_tickets {
+    use serde_json::json;
+
+    use super::get_created_ticket_sequences;
+
+    #[test]
+    fn test_get_created_ticket_sequences() {
+        let meta = json!({
+            "AffectedNodes": [
+                {
+                    "ModifiedNode": {
+                        "FinalFields": {
+                            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                            "OwnerCount": 2,
+                            "Sequence": 13,
+                            "TicketCount": 2
+                        },
+                        "LedgerEntryType": "AccountRoot",
+                        "LedgerIndex": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8",
+                        "PreviousFields": { "OwnerCount": 0, "Sequence": 10 }
+                    }
+                },
+                {
+                    "CreatedNode": {
+                        "LedgerEntryType": "Ticket",
+                        "LedgerIndex": "7B3A3DCD9D1B2F1E2C8C3BB7A6B9F0E5D2CC3D1A4B5E6F708192A3B4C5D6E7F8",
+                        "NewFields": {
+                            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                            "TicketSequence": 12
+                        }
+                    }
+                },
+                {
+                    "CreatedNode": {
+                        "LedgerEntryType": "DirectoryNode",
+                        "LedgerIndex": "1BCBB5C6C5FB8AF0EE0B5F46B0C74F0E0A9A1F9B1D7A4E7A0F3E2C1B0A9F8E7D",
+                        "NewFields": { "Owner": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn" }
+                    }
+                },
+                {
+                    "CreatedNode": {
+                        "LedgerEntryType": "Ticket",
+                        "LedgerIndex": "9C2E4A6B8D0F1E3C5A7B9D1F3E5C7A9B1D3F5E7C9A1B3D5F7E9C1A3B5D7F9E1C",
+                        "NewFields": {
+                            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                            "TicketSequence": 11
+                        }
+                    }
+                }
+            ],
+            "TransactionIndex": 0,
+            "TransactionResult": "tesSUCCESS"
+        });
+
+        assert_eq!(get_created_ticket_sequences(&meta).unwrap(), [11, 12]);
+        assert!(get_created_ticket_sequences(&json!({})).is_err());
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_autofill_batch {
+    use alloc::vec::Vec;
+
+    use super::autofill_batch;
+    use crate::{
+        asynch::{
+            exceptions::XRPLHelperException,
+            test_cases::{account_set, client, ACCOUNT, LEDGER_INDEX, NETWORK_ID},
+            transaction::exceptions::XRPLTransactionHelperException,
+        },
+        models::{requests::RequestMethod, XRPAmount},
+    };
+    const OTHER_ACCOUNT: &str = "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX";
+
+    #[tokio::test]
+    async fn test_autofill_batch() {
+        let client = client();
+        let mut transactions = [
+            account_set(ACCOUNT),
+            account_set(ACCOUNT),
+            account_set(ACCOUNT),
+        ];
+        autofill_batch(&mut transactions, &client, None)
+            .await
+            .unwrap();
+
+        let sequences: Vec<_> = transactions
+            .iter()
+            .map(|txn| txn.common_fields.sequence)
+            .collect();
+        assert_eq!(sequences, [Some(42), Some(43), Some(44)]);
+        for txn in &transactions {
+            assert_eq!(txn.common_fields.fee, Some(XRPAmount::from("12")));
+            assert_eq!(
+                txn.common_fields.last_ledger_sequence,
+                Some(LEDGER_INDEX + 20)
+            );
+            assert_eq!(txn.common_fields.network_id, Some(NETWORK_ID));
+        }
+        for method in [
+            RequestMethod::ServerState,
+            RequestMethod::AccountInfo,
+            RequestMethod::Fee,
+            RequestMethod::Ledger,
+        ] {
+            assert_eq!(client.request_count(&method), 1);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_autofill_batch_starting_sequence() {
+        let client = client();
+        let mut transactions = [account_set(ACCOUNT), account_set(ACCOUNT)];
+        transactions[0].common_fields.sequence = Some(7);
+        autofill_batch(&mut transactions, &client, Some(100))
+            .await
+            .unwrap();
+
+        assert_eq!(transactions[0].common_fields.sequence, Some(7));
+        assert_eq!(transactions[1].common_fields.sequence, Some(100));
+        assert_eq!(client.request_count(&RequestMethod::AccountInfo), 0);
+    }
+
+    #[tokio::test]
+    async fn test_autofill_batch_mixed_accounts() {
+        let client = client();
+        let mut transactions = [account_set(ACCOUNT), account_set(ACCOUNT)];
+        transactions[1].common_fields.account = OTHER_ACCOUNT.into();
+
+        assert!(matches!(
+            autofill_batch(&mut transactions, &client, None).await,
+            Err(XRPLHelperException::XRPLTransactionHelperError(
+                XRPLTransactionHelperException::MixedBatchAccounts { expected, found }
+            )) if expected == ACCOUNT && found == OTHER_ACCOUNT
+        ));
+        assert!(client.requests().is_empty());
+        assert_eq!(transactions[0].common_fields.sequence, None);
+    }
+}
+
 #[cfg(all(feature = "websocket", feature = "std"))]
 #[cfg(test)]
 mod test_autofill {
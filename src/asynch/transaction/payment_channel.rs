@@ -0,0 +1,149 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+use crate::{
+    asynch::{clients::XRPLAsyncClient, exceptions::XRPLHelperResult},
+    models::{
+        results::submit::Submit,
+        transactions::payment_channel_claim::{PaymentChannelClaim, PaymentChannelClaimFlag},
+    },
+    wallet::Wallet,
+};
+
+use super::sign_and_submit;
+
+/// Claims XRP from the payment channel `channel_id`. `balance` is the
+/// total delivered by the channel after the claim and `amount` the
+/// total authorized by `signature`, both in drops. `public_key` must
+/// match the channel's. `close` requests closing the channel and
+/// `renew` clears its expiration. The transaction is autofilled,
+/// signed and submitted.
+#[allow(clippy::too_many_arguments)]
+pub async fn claim_payment_channel<'a, C>(
+    channel_id: Cow<'a, str>,
+    balance: Option<Cow<'a, str>>,
+    amount: Option<Cow<'a, str>>,
+    signature: Option<Cow<'a, str>>,
+    public_key: Option<Cow<'a, str>>,
+    close: bool,
+    renew: bool,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    let mut payment_channel_claim = build_payment_channel_claim(
+        wallet.classic_address.clone().into(),
+        channel_id,
+        balance,
+        amount,
+        signature,
+        public_key,
+        close,
+        renew,
+    );
+
+    sign_and_submit(&mut payment_channel_claim, client, wallet, true, true, None).await
+}
+
+#[allow(clippy::too_many_arguments)]
+fn build_payment_channel_claim<'a>(
+    account: Cow<'a, str>,
+    channel_id: Cow<'a, str>,
+    balance: Option<Cow<'a, str>>,
+    amount: Option<Cow<'a, str>>,
+    signature: Option<Cow<'a, str>>,
+    public_key: Option<Cow<'a, str>>,
+    close: bool,
+    renew: bool,
+) -> PaymentChannelClaim<'a> {
+    let mut flags = Vec::new();
+    if close {
+        flags.push(PaymentChannelClaimFlag::TfClose);
+    }
+    if renew {
+        flags.push(PaymentChannelClaimFlag::TfRenew);
+    }
+
+    PaymentChannelClaim::new(
+        account,
+        None,
+        None,
+        Some(flags.into()),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        channel_id,
+        amount,
+        balance,
+        public_key,
+        signature,
+    )
+}
+
+#[cfg(test)]
+mod test_claim_payment_channel {
+    use super::build_payment_channel_claim;
+    use crate::{
+        core::keypairs::sign_payment_channel_claim,
+        models::transactions::{
+            payment_channel_claim::PaymentChannelClaimFlag, Transaction, TransactionType,
+        },
+        wallet::Wallet,
+    };
+
+    const CHANNEL_ID: &str = "C1AE6DDDEEC05CF2978C0BAD6FE302948E9533691DC749DCDD3B9E5992CA6198";
+
+    #[test]
+    fn test_build_payment_channel_claim() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let signature =
+            sign_payment_channel_claim(CHANNEL_ID, "1000000", &wallet.private_key).unwrap();
+        let claim = build_payment_channel_claim(
+            "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+            CHANNEL_ID.into(),
+            Some("500000".into()),
+            Some("1000000".into()),
+            Some(signature.clone().into()),
+            Some(wallet.public_key.clone().into()),
+            false,
+            false,
+        );
+
+        assert_eq!(
+            claim.get_transaction_type(),
+            TransactionType::PaymentChannelClaim
+        );
+        assert_eq!(claim.channel, CHANNEL_ID);
+        assert_eq!(claim.balance.as_deref(), Some("500000"));
+        assert_eq!(claim.amount.as_deref(), Some("1000000"));
+        assert_eq!(claim.signature.as_deref(), Some(signature.as_str()));
+        assert_eq!(
+            claim.public_key.as_deref(),
+            Some(wallet.public_key.as_str())
+        );
+        assert!(!claim.has_flag(&PaymentChannelClaimFlag::TfClose));
+        assert!(!claim.has_flag(&PaymentChannelClaimFlag::TfRenew));
+    }
+
+    #[test]
+    fn test_build_payment_channel_claim_flags() {
+        let claim = build_payment_channel_claim(
+            "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+            CHANNEL_ID.into(),
+            None,
+            None,
+            None,
+            None,
+            true,
+            true,
+        );
+
+        assert!(claim.has_flag(&PaymentChannelClaimFlag::TfClose));
+        assert!(claim.has_flag(&PaymentChannelClaimFlag::TfRenew));
+        assert_eq!(claim.signature, None);
+    }
+}
@@ -21,6 +21,13 @@ use crate::{
     wallet::Wallet,
 };
 
+/// Sign (if needed), submit and wait for the transaction to be included
+/// in a validated ledger.
+///
+/// The preliminary result of the submission is not final: a transaction
+/// that was queued or failed provisionally may still succeed. The result
+/// returned is the validated `Tx` fetched once the transaction is in a
+/// validated ledger, including its metadata.
 pub async fn submit_and_wait<'a: 'b, 'b, T, F, C>(
     transaction: &'b mut T,
     client: &C,
@@ -47,9 +54,9 @@ where
     C: XRPLAsyncClient,
 {
     let tx_hash = transaction.get_hash()?;
-    let submit_response = submit(transaction, client).await?;
+    let submit_response = submit(transaction, client, false, None).await?;
     let prelim_result = submit_response.engine_result;
-    if &prelim_result[0..3] == "tem" {
+    if prelim_result.starts_with("tem") {
         let message = format!(
             "{}: {}",
             prelim_result, submit_response.engine_result_message
@@ -59,6 +66,7 @@ where
         wait_for_final_transaction_result(
             tx_hash,
             client,
+            prelim_result,
             transaction
                 .get_common_fields()
                 .last_ledger_sequence
@@ -71,18 +79,14 @@ where
 async fn wait_for_final_transaction_result<'a: 'b, 'b, C>(
     tx_hash: Cow<'a, str>,
     client: &C,
+    prelim_result: Cow<'_, str>,
     last_ledger_sequence: u32,
 ) -> XRPLHelperResult<Tx<'b>>
 where
     C: XRPLAsyncClient,
 {
     let mut validated_ledger_sequence = 0;
-    let mut c = 0;
     while validated_ledger_sequence < last_ledger_sequence {
-        c += 1;
-        if c > 20 {
-            panic!()
-        }
         validated_ledger_sequence = get_latest_validated_ledger_sequence(client).await?;
         // sleep for 1 second
         wait_seconds(1).await;
@@ -126,10 +130,12 @@ where
             }
         }
     }
-    Err(
-        XRPLSubmitAndWaitException::SubmissionFailed("Transaction not included in ledger".into())
-            .into(),
-    )
+    Err(XRPLSubmitAndWaitException::SubmissionTimeout {
+        last_ledger_sequence,
+        validated_ledger_sequence,
+        prelim_result: prelim_result.into(),
+    }
+    .into())
 }
 
 async fn get_signed_transaction<'a, T, F, C>(
@@ -214,3 +220,50 @@ mod test_submit_and_wait {
             .unwrap();
     }
 }
+
+#[cfg(test)]
+mod test_submit_and_wait_mock {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        asynch::{
+            clients::MockClient,
+            test_cases::{filled_account_set, ledger, ledger_result, submit_result},
+        },
+        models::{requests::RequestMethod, results::XRPLResult},
+    };
+
+    fn tx_result(validated: bool, transaction_result: &str) -> XRPLResult<'static> {
+        XRPLResult::Tx(
+            serde_json::from_value(json!({
+                "ctid": "C000006400000000",
+                "date": 0,
+                "hash": "",
+                "ledger_index": 100,
+                "meta": { "TransactionResult": transaction_result },
+                "validated": validated
+            }))
+            .unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_queued_transaction_validates() {
+        let client = MockClient::new();
+        client.add_result(RequestMethod::Submit, submit_result("terQUEUED", -89, ""));
+        client.add_result(RequestMethod::Ledger, ledger_result(ledger(90)));
+        client.add_result(RequestMethod::Tx, tx_result(false, "terQUEUED"));
+        client.add_result(RequestMethod::Tx, tx_result(true, "tesSUCCESS"));
+
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let mut tx = filled_account_set(&wallet.classic_address);
+        let result = submit_and_wait(&mut tx, &client, Some(&wallet), None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(result.validated, Some(true));
+        assert_eq!(result.meta["TransactionResult"], "tesSUCCESS");
+        assert_eq!(client.request_count(&RequestMethod::Tx), 2);
+    }
+}
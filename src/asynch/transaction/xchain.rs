@@ -0,0 +1,336 @@
+use alloc::{borrow::Cow, string::ToString};
+
+use crate::{
+    asynch::{clients::XRPLAsyncClient, exceptions::XRPLHelperResult},
+    core::addresscodec::is_valid_classic_address,
+    models::{
+        results::submit::Submit,
+        transactions::{
+            exceptions::XRPLXChainCreateBridgeException, xchain_claim::XChainClaim,
+            xchain_commit::XChainCommit, xchain_create_claim_id::XChainCreateClaimID,
+        },
+        Amount, Model, XChainBridge, XRPLModelException, XRP,
+    },
+    wallet::Wallet,
+};
+
+use super::{exceptions::XRPLTransactionHelperException, sign_and_submit};
+
+/// Reserves a claim ID on the destination chain of `xchain_bridge` for
+/// a transfer sent by `other_chain_source`. The `signature_reward`, in
+/// drops, pays the witnesses attesting the transfer. The transaction
+/// is autofilled, signed and submitted.
+pub async fn xchain_create_claim_id<'a, C>(
+    xchain_bridge: XChainBridge<'a>,
+    signature_reward: Cow<'a, str>,
+    other_chain_source: Cow<'a, str>,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    let mut xchain_create_claim_id = build_xchain_create_claim_id(
+        wallet.classic_address.clone().into(),
+        xchain_bridge,
+        signature_reward,
+        other_chain_source,
+    )?;
+
+    sign_and_submit(
+        &mut xchain_create_claim_id,
+        client,
+        wallet,
+        true,
+        true,
+        None,
+    )
+    .await
+}
+
+/// Locks or burns `amount` on the source chain of `xchain_bridge` for
+/// the claim ID reserved on the destination chain. With an
+/// `other_chain_destination` the witnesses deliver the funds without
+/// a separate claim. The transaction is autofilled, signed and
+/// submitted.
+pub async fn xchain_commit<'a, C>(
+    xchain_bridge: XChainBridge<'a>,
+    xchain_claim_id: Cow<'a, str>,
+    amount: Amount<'a>,
+    other_chain_destination: Option<Cow<'a, str>>,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    let mut xchain_commit = build_xchain_commit(
+        wallet.classic_address.clone().into(),
+        xchain_bridge,
+        xchain_claim_id,
+        amount,
+        other_chain_destination,
+    )?;
+
+    sign_and_submit(&mut xchain_commit, client, wallet, true, true, None).await
+}
+
+/// Claims `amount` for `destination` on the destination chain of
+/// `xchain_bridge` once the commit for `xchain_claim_id` has been
+/// attested. The transaction is autofilled, signed and submitted.
+pub async fn xchain_claim<'a, C>(
+    xchain_bridge: XChainBridge<'a>,
+    xchain_claim_id: Cow<'a, str>,
+    amount: Amount<'a>,
+    destination: Cow<'a, str>,
+    destination_tag: Option<u32>,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    let mut xchain_claim = build_xchain_claim(
+        wallet.classic_address.clone().into(),
+        xchain_bridge,
+        xchain_claim_id,
+        amount,
+        destination,
+        destination_tag,
+    )?;
+
+    sign_and_submit(&mut xchain_claim, client, wallet, true, true, None).await
+}
+
+/// Checks that the door accounts are distinct classic addresses and
+/// that the bridge is either XRP-XRP or IOU-IOU.
+fn validate_bridge(xchain_bridge: &XChainBridge<'_>) -> XRPLHelperResult<()> {
+    for door in [
+        &xchain_bridge.locking_chain_door,
+        &xchain_bridge.issuing_chain_door,
+    ] {
+        if !is_valid_classic_address(door) {
+            return Err(XRPLTransactionHelperException::InvalidBridgeDoor(door.to_string()).into());
+        }
+    }
+    if xchain_bridge.locking_chain_door == xchain_bridge.issuing_chain_door {
+        return Err(
+            XRPLModelException::from(XRPLXChainCreateBridgeException::SameDoorAccounts).into(),
+        );
+    }
+    if (xchain_bridge.locking_chain_issue == XRP::new().into())
+        != (xchain_bridge.issuing_chain_issue == XRP::new().into())
+    {
+        return Err(XRPLModelException::from(
+            XRPLXChainCreateBridgeException::CrossCurrencyBridgeNotAllowed,
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+fn build_xchain_create_claim_id<'a>(
+    account: Cow<'a, str>,
+    xchain_bridge: XChainBridge<'a>,
+    signature_reward: Cow<'a, str>,
+    other_chain_source: Cow<'a, str>,
+) -> XRPLHelperResult<XChainCreateClaimID<'a>> {
+    validate_bridge(&xchain_bridge)?;
+    let xchain_create_claim_id = XChainCreateClaimID::new(
+        account,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        other_chain_source,
+        signature_reward,
+        xchain_bridge,
+    );
+    xchain_create_claim_id.validate()?;
+
+    Ok(xchain_create_claim_id)
+}
+
+fn build_xchain_commit<'a>(
+    account: Cow<'a, str>,
+    xchain_bridge: XChainBridge<'a>,
+    xchain_claim_id: Cow<'a, str>,
+    amount: Amount<'a>,
+    other_chain_destination: Option<Cow<'a, str>>,
+) -> XRPLHelperResult<XChainCommit<'a>> {
+    validate_bridge(&xchain_bridge)?;
+    let xchain_commit = XChainCommit::new(
+        account,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        amount,
+        xchain_bridge,
+        xchain_claim_id,
+        other_chain_destination,
+    );
+    xchain_commit.validate()?;
+
+    Ok(xchain_commit)
+}
+
+fn build_xchain_claim<'a>(
+    account: Cow<'a, str>,
+    xchain_bridge: XChainBridge<'a>,
+    xchain_claim_id: Cow<'a, str>,
+    amount: Amount<'a>,
+    destination: Cow<'a, str>,
+    destination_tag: Option<u32>,
+) -> XRPLHelperResult<XChainClaim<'a>> {
+    validate_bridge(&xchain_bridge)?;
+    let xchain_claim = XChainClaim::new(
+        account,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        amount,
+        destination,
+        xchain_bridge,
+        xchain_claim_id,
+        destination_tag,
+    );
+    // Catches an amount in neither of the bridge's issues.
+    xchain_claim.validate()?;
+
+    Ok(xchain_claim)
+}
+
+#[cfg(test)]
+mod test_xchain {
+    use super::{build_xchain_claim, build_xchain_commit, build_xchain_create_claim_id};
+    use crate::models::{
+        transactions::TransactionType, IssuedCurrency, IssuedCurrencyAmount, XChainBridge, XRP,
+    };
+
+    const ACCOUNT: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+    const LOCKING_CHAIN_DOOR: &str = "r9LqNeG6qHxjeUocjvVki2XR35weJ9mZgQ";
+    const ISSUING_CHAIN_DOOR: &str = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+
+    fn xrp_bridge() -> XChainBridge<'static> {
+        XChainBridge::new(
+            ISSUING_CHAIN_DOOR.into(),
+            XRP::new().into(),
+            LOCKING_CHAIN_DOOR.into(),
+            XRP::new().into(),
+        )
+    }
+
+    #[test]
+    fn test_build_xchain_create_claim_id() {
+        let xchain_create_claim_id = build_xchain_create_claim_id(
+            ACCOUNT.into(),
+            xrp_bridge(),
+            "100".into(),
+            ACCOUNT.into(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            xchain_create_claim_id.common_fields.transaction_type,
+            TransactionType::XChainCreateClaimID
+        );
+        assert_eq!(xchain_create_claim_id.common_fields.account, ACCOUNT);
+        assert_eq!(xchain_create_claim_id.signature_reward, "100");
+        assert_eq!(xchain_create_claim_id.other_chain_source, ACCOUNT);
+        assert_eq!(xchain_create_claim_id.xchain_bridge, xrp_bridge());
+    }
+
+    #[test]
+    fn test_build_xchain_commit() {
+        let xchain_commit = build_xchain_commit(
+            ACCOUNT.into(),
+            xrp_bridge(),
+            "13f".into(),
+            "10000".into(),
+            Some(ACCOUNT.into()),
+        )
+        .unwrap();
+
+        assert_eq!(xchain_commit.xchain_claim_id, "13f");
+        assert_eq!(xchain_commit.amount, "10000".into());
+        assert_eq!(
+            xchain_commit.other_chain_destination.as_deref(),
+            Some(ACCOUNT)
+        );
+        assert_eq!(xchain_commit.xchain_bridge, xrp_bridge());
+    }
+
+    #[test]
+    fn test_build_xchain_claim() {
+        let xchain_claim = build_xchain_claim(
+            ACCOUNT.into(),
+            xrp_bridge(),
+            "13f".into(),
+            "10000".into(),
+            ACCOUNT.into(),
+            Some(7),
+        )
+        .unwrap();
+
+        assert_eq!(xchain_claim.destination, ACCOUNT);
+        assert_eq!(xchain_claim.destination_tag, Some(7));
+        assert_eq!(xchain_claim.xchain_claim_id, "13f");
+
+        // The amount must be in one of the bridge's issues.
+        let usd = IssuedCurrencyAmount::new("USD".into(), LOCKING_CHAIN_DOOR.into(), "10".into());
+        assert!(build_xchain_claim(
+            ACCOUNT.into(),
+            xrp_bridge(),
+            "13f".into(),
+            usd.into(),
+            ACCOUNT.into(),
+            None,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_invalid_bridges() {
+        let build = |bridge| {
+            build_xchain_create_claim_id(ACCOUNT.into(), bridge, "100".into(), ACCOUNT.into())
+        };
+        let usd = IssuedCurrency::new("USD".into(), LOCKING_CHAIN_DOOR.into());
+
+        assert!(build(XChainBridge::new(
+            "not an address".into(),
+            XRP::new().into(),
+            LOCKING_CHAIN_DOOR.into(),
+            XRP::new().into(),
+        ))
+        .is_err());
+        assert!(build(XChainBridge::new(
+            LOCKING_CHAIN_DOOR.into(),
+            XRP::new().into(),
+            LOCKING_CHAIN_DOOR.into(),
+            XRP::new().into(),
+        ))
+        .is_err());
+        assert!(build(XChainBridge::new(
+            ISSUING_CHAIN_DOOR.into(),
+            XRP::new().into(),
+            LOCKING_CHAIN_DOOR.into(),
+            usd.into(),
+        ))
+        .is_err());
+    }
+}
@@ -153,9 +153,52 @@ pub mod json_rpc {
 pub trait XRPLSyncWebsocketIO {
     fn xrpl_send(&mut self, message: XRPLRequest<'_>) -> XRPLClientResult<()>;
 
-    fn xrpl_receive(&mut self) -> XRPLClientResult<Option<XRPLResponse<'_>>>;
+    fn xrpl_receive(&mut self) -> XRPLClientResult<Option<XRPLResponse<'static>>>;
+
+    /// Returns a blocking iterator over the incoming messages, such as
+    /// those of a subscribed stream. It ends when the socket is closed
+    /// or after yielding an error.
+    fn messages(&mut self) -> WebSocketMessages<'_, Self>
+    where
+        Self: Sized,
+    {
+        WebSocketMessages {
+            client: self,
+            done: false,
+        }
+    }
+}
+
+/// A blocking iterator over the messages received by a websocket
+/// client. See [`XRPLSyncWebsocketIO::messages`].
+pub struct WebSocketMessages<'a, T: XRPLSyncWebsocketIO> {
+    client: &'a mut T,
+    done: bool,
+}
+
+impl<T: XRPLSyncWebsocketIO> Iterator for WebSocketMessages<'_, T> {
+    type Item = XRPLClientResult<XRPLResponse<'static>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.client.xrpl_receive() {
+            Ok(Some(message)) => Some(Ok(message)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(error) => {
+                self.done = true;
+                Some(Err(error))
+            }
+        }
+    }
 }
 
+impl<T: XRPLSyncWebsocketIO> core::iter::FusedIterator for WebSocketMessages<'_, T> {}
+
 #[cfg(all(feature = "websocket", feature = "std"))]
 pub mod websocket {
     use embassy_sync::blocking_mutex::raw::RawMutex;
@@ -235,7 +278,7 @@ pub mod websocket {
             self.rt.block_on(self.inner.xrpl_send(message))
         }
 
-        fn xrpl_receive(&mut self) -> XRPLClientResult<Option<XRPLResponse<'_>>> {
+        fn xrpl_receive(&mut self) -> XRPLClientResult<Option<XRPLResponse<'static>>> {
             self.rt.block_on(self.inner.xrpl_receive())
         }
     }
@@ -300,8 +343,81 @@ pub mod websocket {
 
         fn xrpl_receive(
             &mut self,
-        ) -> XRPLClientResult<Option<crate::models::results::XRPLResponse<'_>>> {
+        ) -> XRPLClientResult<Option<crate::models::results::XRPLResponse<'static>>> {
             block_on(self.0.xrpl_receive())
         }
     }
 }
+
+#[cfg(test)]
+mod test_messages {
+    use alloc::collections::VecDeque;
+
+    use super::XRPLSyncWebsocketIO;
+    use crate::{
+        asynch::clients::exceptions::{XRPLClientException, XRPLClientResult},
+        models::{requests::XRPLRequest, results::XRPLResponse},
+    };
+
+    /// A socket that hands out its queued messages and then closes.
+    struct FakeSocket(VecDeque<XRPLClientResult<XRPLResponse<'static>>>);
+
+    impl XRPLSyncWebsocketIO for FakeSocket {
+        fn xrpl_send(&mut self, _message: XRPLRequest<'_>) -> XRPLClientResult<()> {
+            Ok(())
+        }
+
+        fn xrpl_receive(&mut self) -> XRPLClientResult<Option<XRPLResponse<'static>>> {
+            self.0.pop_front().transpose()
+        }
+    }
+
+    fn ledger_closed(ledger_index: u32) -> XRPLResponse<'static> {
+        serde_json::from_value(serde_json::json!({
+            "type": "ledgerClosed",
+            "fee_base": 10,
+            "fee_ref": 10,
+            "ledger_hash": "687F604EF6B2F67319E8DCC8C66EF49D84D18A1E18F948421FC24D2C7C3DB464",
+            "ledger_index": ledger_index,
+            "ledger_time": 638329271,
+            "reserve_base": 20000000,
+            "reserve_inc": 5000000,
+            "txn_count": 5,
+            "validated_ledgers": "32570-62964740"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_messages() {
+        let mut socket = FakeSocket(VecDeque::from([
+            Ok(ledger_closed(62964739)),
+            Ok(ledger_closed(62964740)),
+        ]));
+        let messages: alloc::vec::Vec<_> = socket
+            .messages()
+            .map(|message| serde_json::to_value(message.unwrap()).unwrap())
+            .collect();
+
+        assert_eq!(
+            messages,
+            [
+                serde_json::to_value(ledger_closed(62964739)).unwrap(),
+                serde_json::to_value(ledger_closed(62964740)).unwrap(),
+            ]
+        );
+        assert!(socket.messages().next().is_none());
+    }
+
+    #[test]
+    fn test_messages_end_after_error() {
+        let mut socket = FakeSocket(VecDeque::from([
+            Err(XRPLClientException::MockResponseMissing("subscribe".into())),
+            Ok(ledger_closed(62964739)),
+        ]));
+        let mut messages = socket.messages();
+
+        assert!(messages.next().unwrap().is_err());
+        assert!(messages.next().is_none());
+    }
+}
@@ -25,6 +25,9 @@ pub const MAX_URI_LENGTH: usize = 512;
 
 pub const MAX_DOMAIN_LENGTH: usize = 256;
 
+/// Maximum length of a hex-encoded `CredentialType` (64 bytes).
+pub const MAX_CREDENTIAL_TYPE_LENGTH: usize = 128;
+
 /// Represents the supported cryptography algorithms.
 #[derive(Debug, PartialEq, Eq, Clone, EnumIter, Display, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
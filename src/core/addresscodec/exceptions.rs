@@ -17,8 +17,12 @@ pub enum XRPLAddressCodecException {
     InvalidCAddressTag,
     #[error("Invalid seed prefix encoding type")]
     InvalidSeedPrefixEncodingType,
-    #[error("Invalid encoding prefix length")]
-    InvalidEncodingPrefixLength,
+    #[error("Invalid encoding prefix")]
+    InvalidPrefix,
+    #[error("Invalid base58 checksum")]
+    InvalidChecksum,
+    #[error("Invalid decoded payload length (expected: {expected}, found: {found})")]
+    InvalidLength { expected: usize, found: usize },
     #[error("Invalid classic address value")]
     InvalidClassicAddressValue,
     #[error("Unsupported XAddress")]
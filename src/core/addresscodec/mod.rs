@@ -359,7 +359,7 @@ pub fn encode_classic_address(bytestring: &[u8]) -> XRPLCoreResult<String> {
 /// let decoding: Option<Vec<u8>> = match decode_classic_address(key) {
 ///     Ok(bytes) => Some(bytes),
 ///     Err(e) => match e {
-///         XRPLCoreException::XRPLAddressCodecError(XRPLAddressCodecException::InvalidEncodingPrefixLength) => None,
+///         XRPLCoreException::XRPLAddressCodecError(XRPLAddressCodecException::InvalidPrefix) => None,
 ///         _ => None,
 ///     }
 /// };
@@ -367,7 +367,11 @@ pub fn encode_classic_address(bytestring: &[u8]) -> XRPLCoreResult<String> {
 /// assert_eq!(Some(bytes), decoding);
 /// ```
 pub fn decode_classic_address(classic_address: &str) -> XRPLCoreResult<Vec<u8>> {
-    Ok(decode_base58(classic_address, &CLASSIC_ADDRESS_PREFIX)?)
+    Ok(decode_base58_with_length(
+        classic_address,
+        &CLASSIC_ADDRESS_PREFIX,
+        CLASSIC_ADDRESS_ID_LENGTH,
+    )?)
 }
 
 /// Returns the node public key encoding of these bytes
@@ -433,7 +437,7 @@ pub fn encode_node_public_key(bytestring: &[u8]) -> XRPLCoreResult<String> {
 /// let decoding: Option<Vec<u8>> = match decode_node_public_key(key) {
 ///     Ok(bytes) => Some(bytes),
 ///     Err(e) => match e {
-///         XRPLCoreException::XRPLAddressCodecError(XRPLAddressCodecException::InvalidEncodingPrefixLength) => None,
+///         XRPLCoreException::XRPLAddressCodecError(XRPLAddressCodecException::InvalidPrefix) => None,
 ///         _ => None,
 ///     }
 /// };
@@ -441,7 +445,11 @@ pub fn encode_node_public_key(bytestring: &[u8]) -> XRPLCoreResult<String> {
 /// assert_eq!(Some(bytes), decoding);
 /// ```
 pub fn decode_node_public_key(node_public_key: &str) -> XRPLCoreResult<Vec<u8>> {
-    Ok(decode_base58(node_public_key, &NODE_PUBLIC_KEY_PREFIX)?)
+    Ok(decode_base58_with_length(
+        node_public_key,
+        &NODE_PUBLIC_KEY_PREFIX,
+        NODE_PUBLIC_KEY_LENGTH.into(),
+    )?)
 }
 
 /// Returns the account public key encoding of these
@@ -507,7 +515,7 @@ pub fn encode_account_public_key(bytestring: &[u8]) -> XRPLCoreResult<String> {
 /// let decoding: Option<Vec<u8>> = match decode_account_public_key(key) {
 ///     Ok(bytes) => Some(bytes),
 ///     Err(e) => match e {
-///         XRPLCoreException::XRPLAddressCodecError(XRPLAddressCodecException::InvalidEncodingPrefixLength) => None,
+///         XRPLCoreException::XRPLAddressCodecError(XRPLAddressCodecException::InvalidPrefix) => None,
 ///         _ => None,
 ///     }
 /// };
@@ -515,12 +523,47 @@ pub fn encode_account_public_key(bytestring: &[u8]) -> XRPLCoreResult<String> {
 /// assert_eq!(Some(bytes), decoding);
 /// ```
 pub fn decode_account_public_key(account_public_key: &str) -> XRPLCoreResult<Vec<u8>> {
-    Ok(decode_base58(
+    Ok(decode_base58_with_length(
         account_public_key,
         &ACCOUNT_PUBLIC_KEY_PREFIX,
+        ACCOUNT_PUBLIC_KEY_LENGTH.into(),
     )?)
 }
 
+/// Returns the classic address and tag of an address given
+/// in either classic or X-address form, along with whether
+/// it is a test network X-address. Classic addresses are
+/// passed through with no tag and `false`.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::addresscodec::normalize_address;
+///
+/// let classic_address: &str = "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59";
+/// let xaddress: &str = "T719a5UwUCnEs54UsxG9CJYYDhwmFCvbJNZbi37gBGkRkbE";
+///
+/// assert_eq!(
+///     Ok((classic_address.into(), None, false)),
+///     normalize_address(classic_address),
+/// );
+/// assert_eq!(
+///     Ok((classic_address.into(), Some(1), true)),
+///     normalize_address(xaddress),
+/// );
+/// ```
+pub fn normalize_address(input: &str) -> XRPLCoreResult<(String, Option<u64>, bool)> {
+    if is_valid_xaddress(input) {
+        xaddress_to_classic_address(input)
+    } else {
+        decode_classic_address(input)?;
+
+        Ok((input.into(), None, false))
+    }
+}
+
 /// Returns whether `classic_address` is a valid classic address.
 ///
 /// # Examples
@@ -535,7 +578,7 @@ pub fn decode_account_public_key(account_public_key: &str) -> XRPLCoreResult<Vec
 /// assert!(is_valid_classic_address(address));
 /// ```
 pub fn is_valid_classic_address(classic_address: &str) -> bool {
-    decode_base58(classic_address, &CLASSIC_ADDRESS_PREFIX).is_ok()
+    decode_classic_address(classic_address).is_ok()
 }
 
 /// Returns whether ``xaddress`` is a valid X-Address.
@@ -560,6 +603,7 @@ mod test {
     use super::*;
     use crate::alloc::string::ToString;
     use crate::core::addresscodec::test_cases::*;
+    use crate::core::exceptions::XRPLCoreException;
 
     #[test]
     fn test_algorithm_to_prefix() {
@@ -683,6 +727,49 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_decode_classic_address_errors() {
+        let address = "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59";
+        let mutated = "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk58";
+        let truncated = &address[..address.len() - 2];
+
+        assert!(decode_classic_address(address).is_ok());
+        assert_eq!(
+            decode_classic_address(mutated),
+            Err(XRPLAddressCodecException::InvalidChecksum.into())
+        );
+        assert!(matches!(
+            decode_classic_address(truncated),
+            Err(XRPLCoreException::XRPLAddressCodecError(
+                XRPLAddressCodecException::InvalidLength { expected: 20, .. }
+            ))
+        ));
+        assert_eq!(
+            decode_base58(address, &NODE_PUBLIC_KEY_PREFIX),
+            Err(XRPLAddressCodecException::InvalidPrefix)
+        );
+        assert!(!is_valid_classic_address(mutated));
+    }
+
+    #[test]
+    fn test_normalize_address() {
+        for case in ADDRESS_TEST_CASES {
+            assert_eq!(
+                normalize_address(case.address),
+                Ok((case.address.to_string(), None, false))
+            );
+            assert_eq!(
+                normalize_address(case.main_xaddress),
+                Ok((case.address.to_string(), case.tag, false))
+            );
+            assert_eq!(
+                normalize_address(case.test_xaddress),
+                Ok((case.address.to_string(), case.tag, true))
+            );
+        }
+        assert!(normalize_address("r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk58").is_err());
+    }
+
     #[test]
     fn test_is_valid_xaddress() {
         for case in ADDRESS_TEST_CASES {
@@ -39,6 +39,9 @@ pub(crate) const ACCOUNT_PUBLIC_KEY_LENGTH: u8 = 33;
 /// [`bs58`]: bs58::Alphabet
 pub const XRPL_ALPHABET: Alphabet = *bs58::Alphabet::RIPPLE;
 
+/// Length of the checksum appended to base58 encodings.
+pub(crate) const CHECKSUM_LENGTH: usize = 4;
+
 /// Lenght of a seed value.
 pub const SEED_LENGTH: usize = 16;
 
@@ -71,7 +74,7 @@ pub const SEED_LENGTH: usize = 16;
 /// ) {
 ///     Ok(val) => Some(val),
 ///     Err(e) => match e {
-///         XRPLAddressCodecException::InvalidEncodingPrefixLength => None,
+///         XRPLAddressCodecException::InvalidPrefix => None,
 ///         _ => None,
 ///     }
 /// };
@@ -82,16 +85,46 @@ pub fn decode_base58(
     b58_string: &str,
     prefix: &[u8],
 ) -> Result<Vec<u8>, XRPLAddressCodecException> {
-    let prefix_len = prefix.len();
     let decoded = bs58::decode(b58_string)
         .with_alphabet(&XRPL_ALPHABET)
         .with_check(None)
-        .into_vec()?;
+        .into_vec()
+        .map_err(|error| match error {
+            bs58::decode::Error::InvalidChecksum { .. } => {
+                XRPLAddressCodecException::InvalidChecksum
+            }
+            error => error.into(),
+        })?;
+
+    match decoded.strip_prefix(prefix) {
+        Some(payload) => Ok(payload.to_vec()),
+        None => Err(XRPLAddressCodecException::InvalidPrefix),
+    }
+}
 
-    if &decoded[..prefix_len] != prefix {
-        Err(XRPLAddressCodecException::InvalidEncodingPrefixLength)
+/// Returns the byte decoding of the base58-encoded string,
+/// making sure the payload after the prefix is
+/// `payload_length` bytes long before verifying the
+/// checksum, so that truncated or padded strings are
+/// reported as such.
+pub(crate) fn decode_base58_with_length(
+    b58_string: &str,
+    prefix: &[u8],
+    payload_length: usize,
+) -> Result<Vec<u8>, XRPLAddressCodecException> {
+    let raw_length = bs58::decode(b58_string)
+        .with_alphabet(&XRPL_ALPHABET)
+        .into_vec()?
+        .len();
+    let found = raw_length.saturating_sub(prefix.len() + CHECKSUM_LENGTH);
+
+    if raw_length < prefix.len() + CHECKSUM_LENGTH || found != payload_length {
+        Err(XRPLAddressCodecException::InvalidLength {
+            expected: payload_length,
+            found,
+        })
     } else {
-        Ok(decoded[prefix_len..].to_vec())
+        decode_base58(b58_string, prefix)
     }
 }
 
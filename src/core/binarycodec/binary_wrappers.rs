@@ -488,7 +488,12 @@ impl Parser for BinaryParser {
     }
 
     fn read(&mut self, n: usize) -> XRPLCoreResult<Vec<u8>> {
-        let first_n_bytes = self.0[..n].to_owned();
+        let first_n_bytes = self.0.get(..n).map(ToOwned::to_owned).ok_or(
+            XRPLBinaryCodecException::UnexpectedParserSkipOverflow {
+                max: self.0.len(),
+                found: n,
+            },
+        )?;
 
         self.skip_bytes(n)?;
         Ok(first_n_bytes)
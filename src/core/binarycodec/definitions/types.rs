@@ -4,7 +4,11 @@
 use super::FieldHeader;
 use super::FieldInfo;
 use super::FieldInstance;
+use super::{CODE_MAX_VALUE, CODE_MIN_VALUE};
+use crate::core::binarycodec::exceptions::XRPLBinaryCodecException;
+use crate::core::exceptions::XRPLCoreResult;
 use alloc::borrow::ToOwned;
+use alloc::format;
 use alloc::string::String;
 use alloc::string::ToString;
 use alloc::vec::Vec;
@@ -84,6 +88,7 @@ pub struct LedgerEntryTypes {
     pub xchain_owned_claim_id: i16,
     #[serde(rename = "XChainOwnedCreateAccountClaimID")]
     pub xchain_owned_create_account_claim_id: i16,
+    pub credential: i16,
 }
 
 /// =(
@@ -115,6 +120,14 @@ pub struct TransactionResults {
     pub tel_can_not_queue_fee: i16,
     #[serde(rename = "telCAN_NOT_QUEUE_FULL")]
     pub tel_can_not_queue_null: i16,
+    #[serde(rename = "telWRONG_NETWORK")]
+    pub tel_wrong_network: i16,
+    #[serde(rename = "telREQUIRES_NETWORK_ID")]
+    pub tel_requires_network_id: i16,
+    #[serde(rename = "telNETWORK_ID_MAKES_TX_NON_CANONICAL")]
+    pub tel_network_id_makes_tx_non_canonical: i16,
+    #[serde(rename = "telENV_RPC_FAILED")]
+    pub tel_env_rpc_failed: i16,
 
     #[serde(rename = "temMALFORMED")]
     pub tem_malformed: i16,
@@ -182,10 +195,36 @@ pub struct TransactionResults {
     pub tem_invalid_account_id: i16,
     #[serde(rename = "temCANNOT_PREAUTH_SELF")]
     pub tem_cannot_preauth_self: i16,
+    #[serde(rename = "temINVALID_COUNT")]
+    pub tem_invalid_count: i16,
     #[serde(rename = "temUNCERTAIN")]
     pub tem_uncertain: i16,
     #[serde(rename = "temUNKNOWN")]
     pub tem_unknown: i16,
+    #[serde(rename = "temSEQ_AND_TICKET")]
+    pub tem_seq_and_ticket: i16,
+    #[serde(rename = "temBAD_NFTOKEN_TRANSFER_FEE")]
+    pub tem_bad_nftoken_transfer_fee: i16,
+    #[serde(rename = "temBAD_AMM_TOKENS")]
+    pub tem_bad_amm_tokens: i16,
+    #[serde(rename = "temXCHAIN_EQUAL_DOOR_ACCOUNTS")]
+    pub tem_xchain_equal_door_accounts: i16,
+    #[serde(rename = "temXCHAIN_BAD_PROOF")]
+    pub tem_xchain_bad_proof: i16,
+    #[serde(rename = "temXCHAIN_BRIDGE_BAD_ISSUES")]
+    pub tem_xchain_bridge_bad_issues: i16,
+    #[serde(rename = "temXCHAIN_BRIDGE_NONDOOR_OWNER")]
+    pub tem_xchain_bridge_nondoor_owner: i16,
+    #[serde(rename = "temXCHAIN_BRIDGE_BAD_MIN_ACCOUNT_CREATE_AMOUNT")]
+    pub tem_xchain_bridge_bad_min_account_create_amount: i16,
+    #[serde(rename = "temXCHAIN_BRIDGE_BAD_REWARD_AMOUNT")]
+    pub tem_xchain_bridge_bad_reward_amount: i16,
+    #[serde(rename = "temEMPTY_DID")]
+    pub tem_empty_did: i16,
+    #[serde(rename = "temARRAY_EMPTY")]
+    pub tem_array_empty: i16,
+    #[serde(rename = "temARRAY_TOO_LARGE")]
+    pub tem_array_too_large: i16,
 
     #[serde(rename = "tefFAILURE")]
     pub tef_failure: i16,
@@ -225,6 +264,10 @@ pub struct TransactionResults {
     pub tef_invariant_failed: i16,
     #[serde(rename = "tefTOO_BIG")]
     pub tef_too_big: i16,
+    #[serde(rename = "tefNO_TICKET")]
+    pub tef_no_ticket: i16,
+    #[serde(rename = "tefNFTOKEN_IS_NOT_TRANSFERABLE")]
+    pub tef_nftoken_is_not_transferable: i16,
 
     #[serde(rename = "terRETRY")]
     pub ter_retry: i16,
@@ -248,6 +291,10 @@ pub struct TransactionResults {
     pub ter_no_ripple: i16,
     #[serde(rename = "terQUEUED")]
     pub ter_queued: i16,
+    #[serde(rename = "terPRE_TICKET")]
+    pub ter_pre_ticket: i16,
+    #[serde(rename = "terNO_AMM")]
+    pub ter_no_amm: i16,
 
     #[serde(rename = "tesSUCCESS")]
     pub tes_success: i16,
@@ -328,6 +375,84 @@ pub struct TransactionResults {
     pub tec_has_obligations: i16,
     #[serde(rename = "tecTOO_SOON")]
     pub tec_too_soon: i16,
+    #[serde(rename = "tecHOOK_REJECTED")]
+    pub tec_hook_rejected: i16,
+    #[serde(rename = "tecMAX_SEQUENCE_REACHED")]
+    pub tec_max_sequence_reached: i16,
+    #[serde(rename = "tecNO_SUITABLE_NFTOKEN_PAGE")]
+    pub tec_no_suitable_nftoken_page: i16,
+    #[serde(rename = "tecNFTOKEN_BUY_SELL_MISMATCH")]
+    pub tec_nftoken_buy_sell_mismatch: i16,
+    #[serde(rename = "tecNFTOKEN_OFFER_TYPE_MISMATCH")]
+    pub tec_nftoken_offer_type_mismatch: i16,
+    #[serde(rename = "tecCANT_ACCEPT_OWN_NFTOKEN_OFFER")]
+    pub tec_cant_accept_own_nftoken_offer: i16,
+    #[serde(rename = "tecINSUFFICIENT_FUNDS")]
+    pub tec_insufficient_funds: i16,
+    #[serde(rename = "tecOBJECT_NOT_FOUND")]
+    pub tec_object_not_found: i16,
+    #[serde(rename = "tecINSUFFICIENT_PAYMENT")]
+    pub tec_insufficient_payment: i16,
+    #[serde(rename = "tecUNFUNDED_AMM")]
+    pub tec_unfunded_amm: i16,
+    #[serde(rename = "tecAMM_BALANCE")]
+    pub tec_amm_balance: i16,
+    #[serde(rename = "tecAMM_FAILED")]
+    pub tec_amm_failed: i16,
+    #[serde(rename = "tecAMM_INVALID_TOKENS")]
+    pub tec_amm_invalid_tokens: i16,
+    #[serde(rename = "tecAMM_EMPTY")]
+    pub tec_amm_empty: i16,
+    #[serde(rename = "tecAMM_NOT_EMPTY")]
+    pub tec_amm_not_empty: i16,
+    #[serde(rename = "tecAMM_ACCOUNT")]
+    pub tec_amm_account: i16,
+    #[serde(rename = "tecINCOMPLETE")]
+    pub tec_incomplete: i16,
+    #[serde(rename = "tecXCHAIN_BAD_TRANSFER_ISSUE")]
+    pub tec_xchain_bad_transfer_issue: i16,
+    #[serde(rename = "tecXCHAIN_NO_CLAIM_ID")]
+    pub tec_xchain_no_claim_id: i16,
+    #[serde(rename = "tecXCHAIN_BAD_CLAIM_ID")]
+    pub tec_xchain_bad_claim_id: i16,
+    #[serde(rename = "tecXCHAIN_CLAIM_NO_QUORUM")]
+    pub tec_xchain_claim_no_quorum: i16,
+    #[serde(rename = "tecXCHAIN_PROOF_UNKNOWN_KEY")]
+    pub tec_xchain_proof_unknown_key: i16,
+    #[serde(rename = "tecXCHAIN_CREATE_ACCOUNT_NONXRP_ISSUE")]
+    pub tec_xchain_create_account_nonxrp_issue: i16,
+    #[serde(rename = "tecXCHAIN_WRONG_CHAIN")]
+    pub tec_xchain_wrong_chain: i16,
+    #[serde(rename = "tecXCHAIN_REWARD_MISMATCH")]
+    pub tec_xchain_reward_mismatch: i16,
+    #[serde(rename = "tecXCHAIN_NO_SIGNERS_LIST")]
+    pub tec_xchain_no_signers_list: i16,
+    #[serde(rename = "tecXCHAIN_SENDING_ACCOUNT_MISMATCH")]
+    pub tec_xchain_sending_account_mismatch: i16,
+    #[serde(rename = "tecXCHAIN_INSUFF_CREATE_AMOUNT")]
+    pub tec_xchain_insuff_create_amount: i16,
+    #[serde(rename = "tecXCHAIN_ACCOUNT_CREATE_PAST")]
+    pub tec_xchain_account_create_past: i16,
+    #[serde(rename = "tecXCHAIN_ACCOUNT_CREATE_TOO_MANY")]
+    pub tec_xchain_account_create_too_many: i16,
+    #[serde(rename = "tecXCHAIN_PAYMENT_FAILED")]
+    pub tec_xchain_payment_failed: i16,
+    #[serde(rename = "tecXCHAIN_SELF_COMMIT")]
+    pub tec_xchain_self_commit: i16,
+    #[serde(rename = "tecXCHAIN_BAD_PUBLIC_KEY_ACCOUNT_PAIR")]
+    pub tec_xchain_bad_public_key_account_pair: i16,
+    #[serde(rename = "tecXCHAIN_CREATE_ACCOUNT_DISABLED")]
+    pub tec_xchain_create_account_disabled: i16,
+    #[serde(rename = "tecEMPTY_DID")]
+    pub tec_empty_did: i16,
+    #[serde(rename = "tecINVALID_UPDATE_TIME")]
+    pub tec_invalid_update_time: i16,
+    #[serde(rename = "tecTOKEN_PAIR_NOT_FOUND")]
+    pub tec_token_pair_not_found: i16,
+    #[serde(rename = "tecARRAY_EMPTY")]
+    pub tec_array_empty: i16,
+    #[serde(rename = "tecARRAY_TOO_LARGE")]
+    pub tec_array_too_large: i16,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -357,6 +482,28 @@ pub struct TransactionTypes {
     pub deposit_preauth: i16,
     pub trust_set: i16,
     pub account_delete: i16,
+    #[serde(rename = "NFTokenMint")]
+    pub nftoken_mint: i16,
+    #[serde(rename = "NFTokenBurn")]
+    pub nftoken_burn: i16,
+    #[serde(rename = "NFTokenCreateOffer")]
+    pub nftoken_create_offer: i16,
+    #[serde(rename = "NFTokenCancelOffer")]
+    pub nftoken_cancel_offer: i16,
+    #[serde(rename = "NFTokenAcceptOffer")]
+    pub nftoken_accept_offer: i16,
+    #[serde(rename = "AMMCreate")]
+    pub amm_create: i16,
+    #[serde(rename = "AMMDeposit")]
+    pub amm_deposit: i16,
+    #[serde(rename = "AMMWithdraw")]
+    pub amm_withdraw: i16,
+    #[serde(rename = "AMMVote")]
+    pub amm_vote: i16,
+    #[serde(rename = "AMMBid")]
+    pub amm_bid: i16,
+    #[serde(rename = "AMMDelete")]
+    pub amm_delete: i16,
     #[serde(rename = "XChainAccountCreateCommit")]
     pub xchain_account_create_commit: i16,
     #[serde(rename = "XChainAddAccountCreateAttestation")]
@@ -373,6 +520,9 @@ pub struct TransactionTypes {
     pub xchain_create_claim_id: i16,
     #[serde(rename = "XChainModifyBridge")]
     pub xchain_modify_bridge: i16,
+    pub credential_create: i16,
+    pub credential_accept: i16,
+    pub credential_delete: i16,
 
     pub enable_amendment: i16,
     pub set_fee: i16,
@@ -788,10 +938,122 @@ pub fn get_ledger_entry_type_name(ledger_entry_type: &i16) -> Option<&String> {
     definition_map.get_ledger_entry_type_name(ledger_entry_type)
 }
 
+/// Validates the internal consistency of the definitions table the
+/// binary codec relies on, returning every inconsistency found. An
+/// inconsistent table silently produces wrong blobs, so embedders may
+/// want to assert this at startup.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::binarycodec::definitions::self_check;
+///
+/// assert!(self_check().is_ok());
+/// ```
+pub fn self_check() -> XRPLCoreResult<()> {
+    let (definitions, _) = _load_definitions().as_ref().expect("self_check");
+    let issues = check_definitions(definitions);
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(XRPLBinaryCodecException::InconsistentDefinitions(issues).into())
+    }
+}
+
+/// Checks that every field has a known type, that serialized fields
+/// have valid and unique headers, and that the codes of transaction
+/// types, transaction results and ledger entry types are unique.
+fn check_definitions(definitions: &Definitions) -> Vec<String> {
+    let mut issues = Vec::new();
+    let (types, _) = DefinitionMap::_make_type_maps(&definitions.types);
+    let mut headers: IndexMap<(i16, i16), &str> = IndexMap::new();
+    for Field(name, info) in &definitions.fields {
+        let Some(type_code) = types.get(&info.r#type) else {
+            issues.push(format!("field {} has unknown type {}", name, info.r#type));
+            continue;
+        };
+        if !info.is_serialized {
+            continue;
+        }
+        if !(CODE_MIN_VALUE..=CODE_MAX_VALUE).contains(type_code) {
+            issues.push(format!(
+                "field {} has type code {} out of range",
+                name, type_code
+            ));
+        }
+        if !(CODE_MIN_VALUE..=CODE_MAX_VALUE).contains(&info.nth) {
+            issues.push(format!(
+                "field {} has field code {} out of range",
+                name, info.nth
+            ));
+        }
+        if let Some(other) = headers.insert((*type_code, info.nth), name) {
+            issues.push(format!(
+                "fields {} and {} share the ordinal {} of type {}",
+                other, name, info.nth, info.r#type
+            ));
+        }
+    }
+    let (transaction_types, _) =
+        DefinitionMap::_make_transaction_type_maps(&definitions.transaction_types);
+    let (transaction_results, _) =
+        DefinitionMap::_make_transaction_result_maps(&definitions.transaction_results);
+    let (ledger_entry_types, _) =
+        DefinitionMap::_make_ledger_entry_type_maps(&definitions.ledger_entry_types);
+    for (kind, codes) in [
+        ("type", &types),
+        ("transaction type", &transaction_types),
+        ("transaction result", &transaction_results),
+        ("ledger entry type", &ledger_entry_types),
+    ] {
+        let mut names: IndexMap<i16, &str> = IndexMap::new();
+        for (name, code) in codes {
+            if let Some(other) = names.insert(*code, name) {
+                issues.push(format!(
+                    "{}s {} and {} share the code {}",
+                    kind, other, name, code
+                ));
+            }
+        }
+    }
+
+    issues
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn test_self_check() {
+        assert!(self_check().is_ok());
+    }
+
+    #[test]
+    fn test_check_definitions_duplicates() {
+        let (definitions, _) = _load_definitions().as_ref().unwrap();
+        let mut definitions = definitions.clone();
+        let mut duplicate = definitions
+            .fields
+            .iter()
+            .find(|field| field.0 == "Fee")
+            .unwrap()
+            .clone();
+        duplicate.0 = "Fee2".to_string();
+        definitions.fields.push(duplicate);
+        definitions.transaction_types.payment = definitions.transaction_types.escrow_create;
+        let mut unknown = definitions.fields[0].clone();
+        unknown.0 = "Unknown".to_string();
+        unknown.1.r#type = "UInt1024".to_string();
+        definitions.fields.push(unknown);
+
+        let issues = check_definitions(&definitions);
+
+        assert_eq!(issues.len(), 3, "{:?}", issues);
+    }
+
     #[test]
     fn test_load_definitions() {
         assert!(!_load_definitions().is_none());
@@ -36,6 +36,8 @@ pub enum XRPLBinaryCodecException {
     XAddressTagMismatch,
     #[error("Field is not account or destination")]
     FieldIsNotAccountOrDestination,
+    #[error("Inconsistent definitions: {0:?}")]
+    InconsistentDefinitions(alloc::vec::Vec<alloc::string::String>),
     #[error("Try from int error: {0}")]
     TryFromIntError(#[from] core::num::TryFromIntError),
     #[error("From utf8 error: {0}")]
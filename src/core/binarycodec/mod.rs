@@ -4,12 +4,20 @@
 pub mod definitions;
 pub mod types;
 
-use types::{AccountId, STObject};
+use types::{
+    xchain_bridge::TYPE_ORDER, AccountId, Amount, Currency, Hash256, Issue, PathSet, STObject,
+    TryFromParser, XChainBridge,
+};
 
-use alloc::{borrow::Cow, string::String, vec::Vec};
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
 use core::convert::TryFrom;
 use hex::ToHex;
 use serde::Serialize;
+use serde_json::{Map, Value};
 
 pub mod binary_wrappers;
 pub mod exceptions;
@@ -18,12 +26,20 @@ pub mod utils;
 
 pub use binary_wrappers::*;
 
+use crate::core::addresscodec::encode_classic_address;
 use crate::XRPLSerdeJsonError;
+use definitions::{
+    get_ledger_entry_type_name, get_transaction_result_name, get_transaction_type_name,
+    FieldInstance,
+};
+use types::exceptions::XRPLTypeException;
 
 use super::exceptions::XRPLCoreResult;
 
 const TRANSACTION_SIGNATURE_PREFIX: i32 = 0x53545800;
 const TRANSACTION_MULTISIG_PREFIX: i32 = 0x534D5400;
+const PAYMENT_CHANNEL_CLAIM_PREFIX: i32 = 0x434C4D00;
+const MANIFEST_PREFIX: i32 = 0x4D414E00;
 
 pub fn encode<T>(signed_transaction: &T) -> XRPLCoreResult<String>
 where
@@ -51,7 +67,7 @@ pub fn encode_for_multisigning<T>(
 where
     T: Serialize,
 {
-    let signing_account_id = AccountId::try_from(signing_account.as_ref()).unwrap();
+    let signing_account_id = AccountId::try_from(signing_account.as_ref())?;
 
     serialize_json(
         prepared_transaction,
@@ -61,6 +77,53 @@ where
     )
 }
 
+/// Encodes a payment channel claim for signing: the claim prefix,
+/// the 256-bit channel ID and the amount of XRP, in drops, that the
+/// claim authorizes.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::binarycodec::encode_for_signing_claim;
+///
+/// let channel_id = "43904CBFCDCEC530B4037871F86EE90BF799DF8D2E0EA564BC8A3F332E4F5FB1";
+///
+/// assert_eq!(
+///     encode_for_signing_claim(channel_id, "1000").unwrap(),
+///     "434C4D00\
+///      43904CBFCDCEC530B4037871F86EE90BF799DF8D2E0EA564BC8A3F332E4F5FB1\
+///      00000000000003E8",
+/// );
+/// ```
+pub fn encode_for_signing_claim(channel_id: &str, amount: &str) -> XRPLCoreResult<String> {
+    let channel_id = Hash256::try_from(channel_id)?;
+    let drops = amount
+        .parse::<u64>()
+        .map_err(exceptions::XRPLBinaryCodecException::from)?;
+
+    let mut buffer = Vec::from(PAYMENT_CHANNEL_CLAIM_PREFIX.to_be_bytes());
+    buffer.extend(channel_id.as_ref());
+    buffer.extend(drops.to_be_bytes());
+
+    Ok(buffer.encode_hex_upper::<String>())
+}
+
+/// Encodes a validator manifest for signing: the manifest prefix
+/// followed by its fields, without `Signature` and `MasterSignature`.
+pub fn encode_for_signing_manifest<T>(manifest: &T) -> XRPLCoreResult<String>
+where
+    T: Serialize,
+{
+    serialize_json(
+        manifest,
+        Some(MANIFEST_PREFIX.to_be_bytes().as_ref()),
+        None,
+        true,
+    )
+}
+
 fn serialize_json<T>(
     prepared_transaction: &T,
     prefix: Option<&[u8]>,
@@ -87,3 +150,441 @@ where
 
     Ok(hex_string)
 }
+
+/// Decodes a serialized object, such as a transaction blob or its
+/// metadata, into its JSON representation.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::binarycodec::decode;
+///
+/// let decoded = decode("1200072200000000").unwrap();
+///
+/// assert_eq!(decoded["TransactionType"], "OfferCreate");
+/// assert_eq!(decoded["Flags"], 0);
+/// ```
+pub fn decode(blob: &str) -> XRPLCoreResult<Value> {
+    let bytes = hex::decode(blob)?;
+    let mut parser = BinaryParser::from(bytes.as_slice());
+
+    decode_object(&mut parser, None)
+}
+
+/// Decode a hex-encoded transaction metadata blob, as returned in the
+/// `meta` field of binary responses, into a [`TransactionMetadata`].
+///
+/// [`TransactionMetadata`]: crate::models::transactions::metadata::TransactionMetadata
+#[cfg(feature = "models")]
+pub fn decode_metadata(
+    blob: &str,
+) -> XRPLCoreResult<crate::models::transactions::metadata::TransactionMetadata<'static>> {
+    let value = decode(blob)?;
+
+    Ok(serde_json::from_value(value).map_err(XRPLSerdeJsonError::from)?)
+}
+
+/// Decodes fields until `end_marker`, or until the end of the parser
+/// for a top-level object.
+fn decode_object(parser: &mut BinaryParser, end_marker: Option<&str>) -> XRPLCoreResult<Value> {
+    let mut object = Map::new();
+    while !parser.is_end(None) {
+        let field = parser.read_field()?;
+        if Some(field.name.as_str()) == end_marker {
+            return Ok(Value::Object(object));
+        }
+        let value = decode_field_value(parser, &field)?;
+        object.insert(field.name, value);
+    }
+    match end_marker {
+        None => Ok(Value::Object(object)),
+        Some(end_marker) => Err(XRPLTypeException::MissingField(end_marker.into()).into()),
+    }
+}
+
+fn decode_field_value(parser: &mut BinaryParser, field: &FieldInstance) -> XRPLCoreResult<Value> {
+    if field.is_vl_encoded {
+        let length = parser.read_length_prefix()?;
+        let bytes = parser.read(length)?;
+        return match field.associated_type.as_str() {
+            "AccountID" => Ok(Value::String(encode_classic_address(&bytes)?)),
+            "Vector256" => Ok(Value::Array(
+                bytes
+                    .chunks(32)
+                    .map(|hash| Value::String(hex::encode_upper(hash)))
+                    .collect(),
+            )),
+            _ => Ok(Value::String(hex::encode_upper(bytes))),
+        };
+    }
+    let value = match field.associated_type.as_str() {
+        "UInt8" => {
+            let value = parser.read_uint8()?;
+            match field.name.as_str() {
+                "TransactionResult" => enum_name(get_transaction_result_name(&(value as i16)))
+                    .unwrap_or_else(|| value.into()),
+                _ => value.into(),
+            }
+        }
+        "UInt16" => {
+            let value = parser.read_uint16()?;
+            let name = match field.name.as_str() {
+                "TransactionType" => enum_name(get_transaction_type_name(&(value as i16))),
+                "LedgerEntryType" => enum_name(get_ledger_entry_type_name(&(value as i16))),
+                _ => None,
+            };
+            name.unwrap_or_else(|| value.into())
+        }
+        "UInt32" => parser.read_uint32()?.into(),
+        // rippled represents UInt64 fields as hex strings in JSON.
+        "UInt64" => Value::String(hex::encode_upper(parser.read(8)?)),
+        "Amount" => serde_json::to_value(Amount::from_parser(parser, None)?)
+            .map_err(XRPLSerdeJsonError::from)?,
+        "PathSet" => serde_json::to_value(PathSet::from_parser(parser, None)?)
+            .map_err(XRPLSerdeJsonError::from)?,
+        "Currency" => Value::String(Currency::from_parser(parser, None)?.to_string()),
+        "Issue" => decode_issue(parser)?,
+        "XChainBridge" => {
+            let mut bridge = Map::new();
+            for [name, object_type] in TYPE_ORDER {
+                let value = if object_type == "AccountID" {
+                    let length = parser.read_length_prefix()?;
+                    Value::String(encode_classic_address(&parser.read(length)?)?)
+                } else {
+                    decode_issue(parser)?
+                };
+                bridge.insert(name.into(), value);
+            }
+            Value::Object(bridge)
+        }
+        "STObject" => decode_object(parser, Some("ObjectEndMarker"))?,
+        "STArray" => {
+            let mut array = Vec::new();
+            loop {
+                let element = parser.read_field()?;
+                if element.name == "ArrayEndMarker" {
+                    break;
+                }
+                let mut wrapper = Map::new();
+                let value = decode_field_value(parser, &element)?;
+                wrapper.insert(element.name, value);
+                array.push(Value::Object(wrapper));
+            }
+            Value::Array(array)
+        }
+        field_type => {
+            let length = match field_type {
+                "UInt96" => 12,
+                "Hash128" => 16,
+                "Hash160" => 20,
+                "UInt192" => 24,
+                "Hash256" => 32,
+                "UInt384" => 48,
+                "UInt512" => 64,
+                _ => return Err(XRPLTypeException::UnknownXRPLType.into()),
+            };
+            Value::String(hex::encode_upper(parser.read(length)?))
+        }
+    };
+
+    Ok(value)
+}
+
+fn decode_issue(parser: &mut BinaryParser) -> XRPLCoreResult<Value> {
+    let currency = Currency::from_parser(parser, None)?.to_string();
+    let mut issue = Map::new();
+    if currency != "XRP" {
+        let issuer = encode_classic_address(&parser.read(20)?)?;
+        issue.insert("issuer".into(), Value::String(issuer));
+    }
+    issue.insert("currency".into(), Value::String(currency));
+
+    Ok(Value::Object(issue))
+}
+
+fn enum_name(name: Option<&String>) -> Option<Value> {
+    name.map(|name| Value::String(name.clone()))
+}
+
+/// A single field of a serialized object, as located by [`annotate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnnotatedField {
+    /// The name of the field, e.g. `TakerPays`.
+    pub name: String,
+    /// The name of the field's serialization type, e.g. `Amount`.
+    pub field_type: String,
+    /// The sort key of the field, combining its type code and field code.
+    pub ordinal: i32,
+    /// The position of the field's first byte in the blob.
+    pub offset: usize,
+    /// The field's bytes as uppercase hex, including its field ID and
+    /// any length prefix. Nested objects and arrays are kept whole.
+    pub hex: String,
+}
+
+/// Splits a serialized object into its top-level fields, in the order
+/// they appear in `blob`. Useful to find out which field of a
+/// transaction is malformed.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::binarycodec::annotate;
+///
+/// let fields = annotate("1200072200000000").unwrap();
+///
+/// assert_eq!(fields[0].name, "TransactionType");
+/// assert_eq!(fields[1].name, "Flags");
+/// assert_eq!(fields[1].hex, "2200000000");
+/// ```
+pub fn annotate(blob: &str) -> XRPLCoreResult<Vec<AnnotatedField>> {
+    let bytes = hex::decode(blob)?;
+    let mut parser = BinaryParser::from(bytes.as_slice());
+    let mut fields = Vec::new();
+    while !parser.is_end(None) {
+        let offset = bytes.len() - parser.len();
+        let field = parser.read_field()?;
+        skip_field_value(&mut parser, &field)?;
+        fields.push(AnnotatedField {
+            hex: hex::encode_upper(&bytes[offset..bytes.len() - parser.len()]),
+            name: field.name,
+            field_type: field.associated_type,
+            ordinal: field.ordinal,
+            offset,
+        });
+    }
+
+    Ok(fields)
+}
+
+fn skip_field_value(parser: &mut BinaryParser, field: &FieldInstance) -> XRPLCoreResult<()> {
+    if field.is_vl_encoded {
+        let length = parser.read_length_prefix()?;
+        parser.skip_bytes(length)?;
+        return Ok(());
+    }
+    match field.associated_type.as_str() {
+        "Amount" => drop(Amount::from_parser(parser, None)?),
+        "Issue" => drop(Issue::from_parser(parser, None)?),
+        "PathSet" => drop(PathSet::from_parser(parser, None)?),
+        "XChainBridge" => drop(XChainBridge::from_parser(parser, None)?),
+        "STObject" => skip_nested(parser, "ObjectEndMarker")?,
+        "STArray" => skip_nested(parser, "ArrayEndMarker")?,
+        field_type => {
+            let length = match field_type {
+                "UInt8" => 1,
+                "UInt16" => 2,
+                "UInt32" => 4,
+                "UInt64" => 8,
+                "UInt96" => 12,
+                "Hash128" => 16,
+                "Hash160" | "Currency" => 20,
+                "UInt192" => 24,
+                "Hash256" => 32,
+                "UInt384" => 48,
+                "UInt512" => 64,
+                _ => return Err(XRPLTypeException::UnknownXRPLType.into()),
+            };
+            parser.skip_bytes(length)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn skip_nested(parser: &mut BinaryParser, end_marker: &str) -> XRPLCoreResult<()> {
+    loop {
+        let field = parser.read_field()?;
+        if field.name == end_marker {
+            return Ok(());
+        }
+        skip_field_value(parser, &field)?;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::String;
+
+    use super::{annotate, decode, encode};
+
+    /// The `OfferCreate` buffer from the `STObject::try_from_value` docs.
+    const OFFER_CREATE: &str = "120007220000000024000195F964400000170A53AC2065D5460561E\
+        C9DE000000000000000000000000000494C53000000000092D70596\
+        8936C419CE614BF264B5EEB1CEA47FF468400000000000000A73210\
+        28472865AF4CB32AA285834B57576B7290AA8C31B459047DB27E16F\
+        418D6A71667447304502202ABE08D5E78D1E74A4C18F2714F64E87B\
+        8BD57444AFA5733109EB3C077077520022100DB335EE97386E4C059\
+        1CAC024D50E9230D8F171EEB901B5E5E4BD6D1E0AEF98C811439408\
+        A69F0895E62149CFCC006FB89FA7D1E6E5D";
+
+    /// The metadata of the validated `EscrowFinish` transaction
+    /// 1A76D4BA47A53A66B539D4BD4C30826A5E51C78D0B7344758EACAC77A6753C3C.
+    #[cfg(feature = "models")]
+    const ESCROW_FINISH_METADATA: &str = "201C0000000BF8E511006125020B814F55F72706F8C7B9C07D83332B\
+        E330D77B5CE6A246FE4FA04DD47EBC88719A35B5F95644A462A2806A\
+        513D7480CA71059D3D33637B65458017A8828A8F95AF17272501E662\
+        4000000010DC67D0E1E7220000000024000000052D00000000624000\
+        000013C074F08114DC0BCC71D87BB4E684B35721DC12F2C4E1ABABA4\
+        E1E1E4110075569766AE124E5053BCFDE253F6E3DDBAC13858CC0700\
+        DDECDCD57FF2FA777BEF7DE7220000000025020B814F202521A0C1B8\
+        34000000000000000039000000000000000055F72706F8C7B9C07D83\
+        332BE330D77B5CE6A246FE4FA04DD47EBC88719A35B5F96140000000\
+        02E40D208114E151CA3207BAB5B91D2F0E4D35ECDFD4551C69A18314\
+        DC0BCC71D87BB4E684B35721DC12F2C4E1ABABA4E1E1E511006456BA\
+        4B47767C25E21CCDA3553BD45BE3699B34B508B459FE0472C70C5166\
+        0058E0E7220000000058BA4B47767C25E21CCDA3553BD45BE3699B34\
+        B508B459FE0472C70C51660058E08214E151CA3207BAB5B91D2F0E4D\
+        35ECDFD4551C69A1E1E1E511006125020B814F55F72706F8C7B9C07D\
+        83332BE330D77B5CE6A246FE4FA04DD47EBC88719A35B5F956E4DA2A\
+        510F0C7FFD0474FAA4C7308A83828E0B3DD09EAA9CFDFFC067E3719D\
+        2EE624000000032D00000001624000000002FAF06CE1E72200000000\
+        24000000042D00000000624000000002FAF0628114E151CA3207BAB5\
+        B91D2F0E4D35ECDFD4551C69A1E1E1E511006456ECE79D27042E87B0\
+        2DF3A263DB6BB6FCD96E69E20E0955F84D47D164C37546A1E7220000\
+        000058ECE79D27042E87B02DF3A263DB6BB6FCD96E69E20E0955F84D\
+        47D164C37546A18214DC0BCC71D87BB4E684B35721DC12F2C4E1ABAB\
+        A4E1E1F1031000";
+
+    #[test]
+    fn test_annotate_offer_create() {
+        let fields = annotate(OFFER_CREATE).unwrap();
+        let layout: alloc::vec::Vec<(&str, &str, i32)> = fields
+            .iter()
+            .map(|field| {
+                (
+                    field.name.as_str(),
+                    field.field_type.as_str(),
+                    field.ordinal,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            layout,
+            [
+                ("TransactionType", "UInt16", 0x1_0002),
+                ("Flags", "UInt32", 0x2_0002),
+                ("Sequence", "UInt32", 0x2_0004),
+                ("TakerPays", "Amount", 0x6_0004),
+                ("TakerGets", "Amount", 0x6_0005),
+                ("Fee", "Amount", 0x6_0008),
+                ("SigningPubKey", "Blob", 0x7_0003),
+                ("TxnSignature", "Blob", 0x7_0004),
+                ("Account", "AccountID", 0x8_0001),
+            ]
+        );
+        assert_eq!(fields[3].offset, 13);
+        assert_eq!(fields[3].hex, "64400000170A53AC20");
+        assert_eq!(
+            fields
+                .iter()
+                .map(|field| field.hex.as_str())
+                .collect::<String>(),
+            OFFER_CREATE
+        );
+    }
+
+    #[test]
+    fn test_annotate_nested_object() {
+        // A `Memos` array holding one `Memo` with a `MemoData` blob,
+        // followed by `Flags`.
+        let fields = annotate("F9EA7D024849E1F12200000000").unwrap();
+
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].name, "Memos");
+        assert_eq!(fields[0].hex, "F9EA7D024849E1F1");
+        assert_eq!(fields[1].name, "Flags");
+        assert_eq!(fields[1].offset, 8);
+    }
+
+    #[test]
+    fn test_annotate_truncated() {
+        assert!(annotate("1200072200").is_err());
+    }
+
+    #[test]
+    fn test_decode_offer_create() {
+        let decoded = decode(OFFER_CREATE).unwrap();
+
+        assert_eq!(decoded["TransactionType"], "OfferCreate");
+        assert_eq!(decoded["Account"], "raD5qJMAShLeHZXf9wjUmo6vRK4arj9cF3");
+        assert_eq!(decoded["Sequence"], 103929);
+        assert_eq!(decoded["TakerPays"], "98957503520");
+        assert_eq!(decoded["TakerGets"]["currency"], "ILS");
+        assert_eq!(encode(&decoded).unwrap(), OFFER_CREATE);
+    }
+
+    #[test]
+    fn test_decode_metadata() {
+        let metadata = serde_json::json!({
+            "AffectedNodes": [{
+                "ModifiedNode": {
+                    "FinalFields": {
+                        "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                        "Balance": "999999988",
+                        "Flags": 0,
+                        "OwnerCount": 0,
+                        "Sequence": 2
+                    },
+                    "LedgerEntryType": "AccountRoot",
+                    "LedgerIndex": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8",
+                    "PreviousFields": { "Balance": "1000000000", "Sequence": 1 }
+                }
+            }],
+            "TransactionIndex": 3,
+            "TransactionResult": "tesSUCCESS"
+        });
+        let decoded = decode(&encode(&metadata).unwrap()).unwrap();
+
+        assert_eq!(decoded, metadata);
+    }
+
+    #[cfg(feature = "models")]
+    #[test]
+    fn test_decode_metadata_model() {
+        use crate::models::transactions::metadata::AffectedNode;
+
+        let metadata = super::decode_metadata(ESCROW_FINISH_METADATA).unwrap();
+
+        assert_eq!(metadata.transaction_result, "tesSUCCESS");
+        assert_eq!(metadata.transaction_index, 11);
+        assert_eq!(metadata.affected_nodes.len(), 5);
+        assert_eq!(
+            metadata
+                .affected_nodes
+                .iter()
+                .map(|node| node.ledger_entry_type())
+                .collect::<alloc::vec::Vec<_>>(),
+            [
+                "AccountRoot",
+                "Escrow",
+                "DirectoryNode",
+                "AccountRoot",
+                "DirectoryNode"
+            ]
+        );
+        assert!(matches!(
+            metadata.affected_nodes[1],
+            AffectedNode::DeletedNode(_)
+        ));
+        assert_eq!(metadata.delivered_amount, None);
+
+        let decoded = decode(ESCROW_FINISH_METADATA).unwrap();
+        assert_eq!(
+            decoded["AffectedNodes"][0]["ModifiedNode"]["PreviousFields"]["Balance"],
+            "282879952"
+        );
+        assert_eq!(encode(&decoded).unwrap(), ESCROW_FINISH_METADATA);
+    }
+
+    #[test]
+    fn test_decode_truncated() {
+        assert!(decode("E1").is_err());
+        assert!(decode(&OFFER_CREATE[..20]).is_err());
+    }
+}
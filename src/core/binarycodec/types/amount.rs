@@ -197,7 +197,7 @@ impl Amount {
     /// Returns true if 2nd bit in 1st byte is set to 1
     /// (positive amount).
     pub fn is_positive(&self) -> bool {
-        self.0[1] & 0x40 > 0
+        self.0[0] & 0x40 > 0
     }
 }
 
@@ -262,8 +262,8 @@ impl TryFromParser for Amount {
     ) -> XRPLCoreResult<Amount, Self::Error> {
         let parser_first_byte = parser.peek();
         let num_bytes = match parser_first_byte {
-            None => _CURRENCY_AMOUNT_BYTE_LENGTH,
-            Some(_) => _NATIVE_AMOUNT_BYTE_LENGTH,
+            Some([byte]) if byte & _NOT_XRP_BIT_MASK != 0 => _CURRENCY_AMOUNT_BYTE_LENGTH,
+            _ => _NATIVE_AMOUNT_BYTE_LENGTH,
         };
 
         Ok(Amount(parser.read(num_bytes as usize)?))
@@ -9,6 +9,8 @@ pub mod hash;
 pub mod issue;
 pub mod paths;
 pub(crate) mod test_cases;
+#[cfg(all(test, feature = "std"))]
+mod test_round_trip;
 pub mod utils;
 pub mod vector256;
 pub mod xchain_bridge;
@@ -36,6 +38,7 @@ pub use self::xchain_bridge::XChainBridge;
 
 use crate::core::binarycodec::binary_wrappers::Serialization;
 use crate::core::binarycodec::definitions::get_field_instance;
+use crate::core::binarycodec::definitions::get_ledger_entry_type_code;
 use crate::core::binarycodec::definitions::get_transaction_result_code;
 use crate::core::binarycodec::definitions::get_transaction_type_code;
 use crate::core::binarycodec::definitions::FieldInstance;
@@ -121,10 +124,10 @@ impl XRPLTypes {
                         .parse::<u32>()
                         .map_err(XRPLTypeException::ParseIntError)?,
                 )),
+                // rippled represents UInt64 fields (e.g. `IndexNext`, `LowNode`)
+                // as hex strings in JSON.
                 "UInt64" => Ok(XRPLTypes::UInt64(
-                    value
-                        .parse::<u64>()
-                        .map_err(XRPLTypeException::ParseIntError)?,
+                    u64::from_str_radix(value, 16).map_err(XRPLTypeException::ParseIntError)?,
                 )),
                 _ => Err(exceptions::XRPLTypeException::UnknownXRPLType.into()),
             }
@@ -395,7 +398,7 @@ impl STObject {
                         Value::Number(transaction_result_code.to_owned().into()),
                     );
                 } else if field == "LedgerEntryType" {
-                    let ledger_entry_type_code = match get_transaction_type_code(value) {
+                    let ledger_entry_type_code = match get_ledger_entry_type_code(value) {
                         Some(code) => code,
                         None => {
                             return Err(
@@ -616,3 +619,35 @@ where
         SerializedType(instance.as_ref().to_vec())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_uint64_from_hex_string() {
+        match XRPLTypes::from_value("UInt64", Value::String("00000000000000FF".into())).unwrap() {
+            XRPLTypes::UInt64(value) => assert_eq!(value, 255),
+            other => panic!("expected UInt64, found {:?}", other),
+        }
+        match XRPLTypes::from_value("UInt64", Value::Number(255.into())).unwrap() {
+            XRPLTypes::UInt64(value) => assert_eq!(value, 255),
+            other => panic!("expected UInt64, found {:?}", other),
+        }
+        assert!(XRPLTypes::from_value("UInt64", Value::String("not hex".into())).is_err());
+    }
+
+    #[test]
+    fn test_uint64_field_from_hex_string() {
+        let object = STObject::try_from_value(
+            serde_json::json!({ "IndexNext": "000000000000000A", "LowNode": "0000000000000001" }),
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(
+            hex::encode_upper(object.as_ref()),
+            "31000000000000000A370000000000000001"
+        );
+    }
+}
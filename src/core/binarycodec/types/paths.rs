@@ -122,6 +122,82 @@ impl PathStepData {
     }
 }
 
+impl PathStep {
+    /// Construct a PathStep that ripples through the given account.
+    pub fn account(account: &str) -> XRPLCoreResult<Self> {
+        Self::from_parts(Some(account), None, None)
+    }
+
+    /// Construct a PathStep that converts to the given currency, held
+    /// with `issuer` for issued currencies.
+    pub fn currency(currency: &str, issuer: Option<&str>) -> XRPLCoreResult<Self> {
+        Self::from_parts(None, Some(currency), issuer)
+    }
+
+    /// Construct a PathStep from any combination of its fields.
+    pub fn from_parts(
+        account: Option<&str>,
+        currency: Option<&str>,
+        issuer: Option<&str>,
+    ) -> XRPLCoreResult<Self> {
+        let mut value_bytes: Vec<u8> = vec![];
+        let mut data_type = 0x00;
+        let mut buffer = vec![];
+
+        if let Some(account) = account {
+            let data = AccountId::try_from(account)?;
+            data_type |= _TYPE_ACCOUNT;
+
+            value_bytes.extend_from_slice(data.as_ref());
+        };
+
+        if let Some(currency) = currency {
+            let data = Currency::try_from(currency)?;
+            data_type |= _TYPE_CURRENCY;
+
+            value_bytes.extend_from_slice(data.as_ref());
+        };
+
+        if let Some(issuer) = issuer {
+            let data = AccountId::try_from(issuer)?;
+            data_type |= _TYPE_ISSUER;
+
+            value_bytes.extend_from_slice(data.as_ref());
+        };
+
+        buffer.extend_from_slice(&[data_type]);
+        buffer.extend_from_slice(&value_bytes);
+
+        Self::new(Some(&buffer))
+    }
+}
+
+impl Path {
+    /// Construct a Path from its steps.
+    pub fn from_steps(steps: &[PathStep]) -> Self {
+        Path(steps.iter().flat_map(|step| step.0.clone()).collect())
+    }
+}
+
+impl PathSet {
+    /// Construct a PathSet from a list of paths, each a list of steps.
+    pub fn from_paths(paths: Vec<Vec<PathStep>>) -> Self {
+        let mut buffer: Vec<u8> = vec![];
+
+        for steps in paths {
+            buffer.extend_from_slice(Path::from_steps(&steps).as_ref());
+            buffer.push(_PATH_SEPARATOR_BYTE);
+        }
+
+        match buffer.last_mut() {
+            Some(last) => *last = _PATHSET_END_BYTE,
+            None => buffer.push(_PATHSET_END_BYTE),
+        }
+
+        PathSet(buffer)
+    }
+}
+
 impl TryFromParser for PathStep {
     type Error = XRPLCoreException;
 
@@ -323,35 +399,11 @@ impl TryFrom<IndexMap<String, String>> for PathStep {
 
     /// Construct a PathStep object from a dictionary.
     fn try_from(value: IndexMap<String, String>) -> XRPLCoreResult<Self, Self::Error> {
-        let mut value_bytes: Vec<u8> = vec![];
-        let mut data_type = 0x00;
-        let mut buffer = vec![];
-
-        if value.contains_key(_ACC_KEY) {
-            let data = AccountId::try_from(value[_ACC_KEY].as_ref())?;
-            data_type |= _TYPE_ACCOUNT;
-
-            value_bytes.extend_from_slice(data.as_ref());
-        };
-
-        if value.contains_key(_CUR_KEY) {
-            let data = Currency::try_from(value[_CUR_KEY].as_ref())?;
-            data_type |= _TYPE_CURRENCY;
-
-            value_bytes.extend_from_slice(data.as_ref());
-        };
-
-        if value.contains_key(_ISS_KEY) {
-            let data = AccountId::try_from(value[_ISS_KEY].as_ref())?;
-            data_type |= _TYPE_ISSUER;
-
-            value_bytes.extend_from_slice(data.as_ref());
-        };
-
-        buffer.extend_from_slice(&[data_type]);
-        buffer.extend_from_slice(&value_bytes);
-
-        Ok(Self::new(Some(&buffer))?)
+        Self::from_parts(
+            value.get(_ACC_KEY).map(String::as_str),
+            value.get(_CUR_KEY).map(String::as_str),
+            value.get(_ISS_KEY).map(String::as_str),
+        )
     }
 }
 
@@ -624,4 +676,57 @@ mod test {
             serde_json::to_string(&compact).expect(""),
         );
     }
+
+    #[test]
+    fn test_pathset_from_paths() {
+        let json: Vec<Vec<IndexMap<String, String>>> =
+            serde_json::from_str(PATH_SET_TEST).expect("");
+        let paths = json
+            .iter()
+            .map(|path| {
+                path.iter()
+                    .map(|step| {
+                        PathStep::from_parts(
+                            step.get(_ACC_KEY).map(String::as_str),
+                            step.get(_CUR_KEY).map(String::as_str),
+                            step.get(_ISS_KEY).map(String::as_str),
+                        )
+                        .unwrap()
+                    })
+                    .collect()
+            })
+            .collect();
+        let pathset = PathSet::from_paths(paths);
+
+        assert_eq!(hex::encode_upper(pathset.as_ref()), TEST_PATH_SET_BUFFER);
+    }
+
+    #[test]
+    fn test_pathset_from_paths_two_hops() {
+        let pathset = PathSet::from_paths(vec![vec![
+            PathStep::account("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B").unwrap(),
+            PathStep::currency("USD", Some("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B")).unwrap(),
+        ]]);
+        let expected = PathSet::try_from(
+            r#"[[
+                {"account": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"},
+                {"currency": "USD", "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"}
+            ]]"#,
+        )
+        .unwrap();
+
+        assert_eq!(pathset.as_ref(), expected.as_ref());
+        assert_eq!(
+            serde_json::to_value(&pathset).unwrap(),
+            serde_json::json!([[
+                {"account": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"},
+                {"currency": "USD", "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"}
+            ]])
+        );
+        // Account step, currency and issuer step, end of path set.
+        assert_eq!(pathset.as_ref()[0], _TYPE_ACCOUNT);
+        assert_eq!(pathset.as_ref()[21], _TYPE_CURRENCY | _TYPE_ISSUER);
+        assert_eq!(pathset.as_ref().len(), 1 + 20 + 1 + 20 + 20 + 1);
+        assert_eq!(pathset.as_ref().last(), Some(&_PATHSET_END_BYTE));
+    }
 }
@@ -0,0 +1,315 @@
+//! Randomized round-trip tests for the binary codec types.
+//!
+//! Every case builds a value from its JSON representation, serializes it
+//! through `XRPLTypes`/`SerializedType`, re-parses the bytes with a
+//! `BinaryParser` and asserts that the parsed bytes match, that the parser
+//! consumed exactly the encoded value and, where the type can be turned back
+//! into JSON, that re-encoding that JSON yields the same bytes.
+//!
+//! Values are produced by a small handwritten generator driven by a seeded
+//! RNG so failures are reproducible.
+//!
+//! Intentionally excluded `XRPLTypes` variants:
+//! - `STObject` and `STArray`: there is no `TryFromParser` decoder for them
+//!   yet; their field encoding is covered by the transaction fixtures.
+//! - `Path` and `PathStep`: always encoded as part of a `PathSet`, which is
+//!   covered here.
+//! - `Unknown`: has no encoding.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt::Debug;
+
+use indexmap::IndexMap;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde_json::{json, Value};
+
+use super::{
+    AccountId, Amount, Blob, Currency, Hash128, Hash160, Hash256, Issue, PathSet, SerializedType,
+    TryFromParser, Vector256, XChainBridge, XRPLType, XRPLTypes,
+};
+use crate::core::addresscodec::encode_classic_address;
+use crate::core::{BinaryParser, Parser};
+
+const SEED: u64 = 0x5852_504C;
+const CASES: usize = 200;
+
+fn rng() -> StdRng {
+    StdRng::seed_from_u64(SEED)
+}
+
+fn random_bytes(rng: &mut StdRng, len: usize) -> Vec<u8> {
+    (0..len).map(|_| rng.gen()).collect()
+}
+
+fn random_hex(rng: &mut StdRng, len: usize) -> String {
+    hex::encode_upper(random_bytes(rng, len))
+}
+
+fn random_address(rng: &mut StdRng) -> String {
+    encode_classic_address(&random_bytes(rng, 20)).unwrap()
+}
+
+fn random_currency(rng: &mut StdRng) -> String {
+    if rng.gen_bool(0.5) {
+        loop {
+            let code: String = (0..3)
+                .map(|_| char::from(rng.gen_range(b'A'..=b'Z')))
+                .collect();
+            if code != "XRP" {
+                return code;
+            }
+        }
+    } else {
+        // Nonstandard currency codes must not start with a zero byte.
+        let mut bytes = random_bytes(rng, 20);
+        bytes[0] = rng.gen_range(0x01..=0xFF);
+        hex::encode_upper(bytes)
+    }
+}
+
+fn random_issue(rng: &mut StdRng) -> Value {
+    if rng.gen_bool(0.25) {
+        json!({ "currency": "XRP" })
+    } else {
+        json!({ "currency": random_currency(rng), "issuer": random_address(rng) })
+    }
+}
+
+fn random_amount(rng: &mut StdRng) -> Value {
+    if rng.gen_bool(0.5) {
+        Value::String(rng.gen_range(0u64..=100_000_000_000_000_000).to_string())
+    } else {
+        let sign = if rng.gen_bool(0.25) { "-" } else { "" };
+        let value = format!(
+            "{}{}.{}",
+            sign,
+            rng.gen_range(0u64..1_000_000),
+            rng.gen_range(0u64..1_000_000_000)
+        );
+        json!({
+            "currency": random_currency(rng),
+            "issuer": random_address(rng),
+            "value": value,
+        })
+    }
+}
+
+fn random_path_set(rng: &mut StdRng) -> Vec<Vec<IndexMap<String, String>>> {
+    (0..rng.gen_range(1..=3))
+        .map(|_| {
+            (0..rng.gen_range(1..=4))
+                .map(|_| {
+                    let mut step = IndexMap::new();
+                    loop {
+                        if rng.gen_bool(0.5) {
+                            step.insert("account".to_string(), random_address(rng));
+                        }
+                        if rng.gen_bool(0.5) {
+                            step.insert("currency".to_string(), random_currency(rng));
+                        }
+                        if rng.gen_bool(0.5) {
+                            step.insert("issuer".to_string(), random_address(rng));
+                        }
+                        if !step.is_empty() {
+                            return step;
+                        }
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Parses `bytes` back into `T` and asserts the parsed value is
+/// byte-for-byte identical and that nothing was left unread.
+fn assert_parses_back<T>(bytes: &[u8], length: Option<usize>) -> T
+where
+    T: TryFromParser + AsRef<[u8]>,
+    T::Error: Debug,
+{
+    let mut parser = BinaryParser::from(bytes);
+    let parsed = T::from_parser(&mut parser, length).unwrap();
+
+    assert_eq!(hex::encode_upper(bytes), hex::encode_upper(parsed.as_ref()));
+    assert!(parser.is_end(None), "trailing bytes after {:?}", bytes);
+
+    parsed
+}
+
+fn serialize(value: XRPLTypes) -> Vec<u8> {
+    SerializedType::from(value).as_ref().to_vec()
+}
+
+#[test]
+fn test_account_id_round_trip() {
+    let mut rng = rng();
+    for _ in 0..CASES {
+        let address = random_address(&mut rng);
+        let bytes = serialize(XRPLTypes::from_value("AccountID", json!(address)).unwrap());
+        let parsed: AccountId = assert_parses_back(&bytes, None);
+
+        assert_eq!(address, parsed.to_string());
+    }
+}
+
+#[test]
+fn test_amount_round_trip() {
+    let mut rng = rng();
+    for _ in 0..CASES {
+        let amount = random_amount(&mut rng);
+        let bytes = serialize(XRPLTypes::from_value("Amount", amount.clone()).unwrap());
+        let parsed: Amount = assert_parses_back(&bytes, None);
+        let reencoded = serialize(
+            XRPLTypes::from_value("Amount", serde_json::to_value(&parsed).unwrap()).unwrap(),
+        );
+
+        assert_eq!(bytes, reencoded, "{}", amount);
+        if let Some(value) = amount.get("value").and_then(Value::as_str) {
+            assert!(!parsed.is_native());
+            if !value
+                .trim_start_matches('-')
+                .trim_matches(['0', '.'])
+                .is_empty()
+            {
+                assert_eq!(!value.starts_with('-'), parsed.is_positive(), "{}", value);
+            }
+        } else {
+            assert!(parsed.is_native());
+        }
+    }
+}
+
+#[test]
+fn test_blob_round_trip() {
+    let mut rng = rng();
+    for _ in 0..CASES {
+        let len = rng.gen_range(0..=1024);
+        let blob = random_hex(&mut rng, len);
+        let bytes = serialize(XRPLTypes::from_value("Blob", json!(blob)).unwrap());
+        // Blobs are length-prefixed in a field and read as raw bytes.
+        let mut parser = BinaryParser::from(bytes.as_slice());
+        let parsed = Blob::new(Some(&parser.read(len).unwrap())).unwrap();
+
+        assert!(parser.is_end(None));
+        assert_eq!(blob, parsed.to_string());
+    }
+}
+
+#[test]
+fn test_currency_round_trip() {
+    let mut rng = rng();
+    for _ in 0..CASES {
+        let currency = random_currency(&mut rng);
+        let bytes = serialize(XRPLTypes::from_value("Currency", json!(currency)).unwrap());
+        let parsed: Currency = assert_parses_back(&bytes, None);
+
+        assert_eq!(currency, parsed.to_string());
+    }
+}
+
+#[test]
+fn test_hash_round_trip() {
+    let mut rng = rng();
+    for _ in 0..CASES {
+        let hash = random_hex(&mut rng, 16);
+        let bytes = serialize(XRPLTypes::from_value("Hash128", json!(hash)).unwrap());
+        let parsed: Hash128 = assert_parses_back(&bytes, None);
+        assert_eq!(hash, parsed.to_string());
+
+        let hash = random_hex(&mut rng, 20);
+        let bytes = serialize(XRPLTypes::from_value("Hash160", json!(hash)).unwrap());
+        let parsed: Hash160 = assert_parses_back(&bytes, None);
+        assert_eq!(hash, parsed.to_string());
+
+        let hash = random_hex(&mut rng, 32);
+        let bytes = serialize(XRPLTypes::from_value("Hash256", json!(hash)).unwrap());
+        let parsed: Hash256 = assert_parses_back(&bytes, None);
+        assert_eq!(hash, parsed.to_string());
+    }
+}
+
+#[test]
+fn test_issue_round_trip() {
+    let mut rng = rng();
+    for _ in 0..CASES {
+        let issue = Issue::try_from(random_issue(&mut rng)).unwrap();
+        let bytes = serialize(XRPLTypes::Issue(issue));
+
+        assert_parses_back::<Issue>(&bytes, None);
+    }
+}
+
+#[test]
+fn test_path_set_round_trip() {
+    let mut rng = rng();
+    for _ in 0..CASES {
+        let path_set = random_path_set(&mut rng);
+        let bytes = serialize(XRPLTypes::PathSet(
+            PathSet::try_from(path_set.clone()).unwrap(),
+        ));
+        let parsed: PathSet = assert_parses_back(&bytes, None);
+
+        assert_eq!(json!(path_set), serde_json::to_value(&parsed).unwrap());
+    }
+}
+
+#[test]
+fn test_uint_round_trip() {
+    let mut rng = rng();
+    for _ in 0..CASES {
+        let value: u8 = rng.gen();
+        let bytes = serialize(XRPLTypes::from_value("UInt8", json!(value)).unwrap());
+        assert_eq!(value, BinaryParser::from(bytes).read_uint8().unwrap());
+
+        let value: u16 = rng.gen();
+        let bytes = serialize(XRPLTypes::from_value("UInt16", json!(value)).unwrap());
+        assert_eq!(value, BinaryParser::from(bytes).read_uint16().unwrap());
+
+        let value: u32 = rng.gen();
+        let bytes = serialize(XRPLTypes::from_value("UInt32", json!(value)).unwrap());
+        assert_eq!(value, BinaryParser::from(bytes).read_uint32().unwrap());
+
+        let value: u64 = rng.gen();
+        let bytes = serialize(XRPLTypes::from_value("UInt64", json!(value)).unwrap());
+        let mut parser = BinaryParser::from(bytes);
+        let read: [u8; 8] = parser.read(8).unwrap().try_into().unwrap();
+        assert_eq!(value, u64::from_be_bytes(read));
+        assert!(parser.is_end(None));
+    }
+}
+
+#[test]
+fn test_vector256_round_trip() {
+    let mut rng = rng();
+    for _ in 0..CASES {
+        let hashes: Vec<String> = (0..rng.gen_range(0..=8))
+            .map(|_| random_hex(&mut rng, 32))
+            .collect();
+        let vector =
+            Vector256::try_from(hashes.iter().map(String::as_str).collect::<Vec<_>>()).unwrap();
+        let bytes = serialize(XRPLTypes::Vector256(vector));
+        let parsed: Vector256 = assert_parses_back(&bytes, Some(bytes.len()));
+
+        assert_eq!(json!(hashes), serde_json::to_value(&parsed).unwrap());
+    }
+}
+
+#[test]
+fn test_xchain_bridge_round_trip() {
+    let mut rng = rng();
+    for _ in 0..CASES {
+        let bridge = json!({
+            "LockingChainDoor": random_address(&mut rng),
+            "LockingChainIssue": random_issue(&mut rng),
+            "IssuingChainDoor": random_address(&mut rng),
+            "IssuingChainIssue": random_issue(&mut rng),
+        });
+        let bytes = serialize(XRPLTypes::from_value("XChainBridge", bridge).unwrap());
+
+        assert_parses_back::<XChainBridge>(&bytes, None);
+    }
+}
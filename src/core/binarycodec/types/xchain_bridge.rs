@@ -12,7 +12,7 @@ use super::{
     XRPLType,
 };
 
-const TYPE_ORDER: [[&str; 2]; 4] = [
+pub(crate) const TYPE_ORDER: [[&str; 2]; 4] = [
     ["LockingChainDoor", "AccountID"],
     ["LockingChainIssue", "Issue"],
     ["IssuingChainDoor", "AccountID"],
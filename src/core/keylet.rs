@@ -0,0 +1,174 @@
+//! Functions for computing the index (keylet) of ledger objects.
+//!
+//! Every object in the ledger's state tree is identified by a 256-bit
+//! ID which is the SHA-512Half of a 16-bit namespace followed by the
+//! fields that uniquely identify the object.
+//!
+//! See Ledger Object IDs:
+//! `<https://xrpl.org/ledger-object-ids.html>`
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+use crate::core::addresscodec::decode_classic_address;
+use crate::core::binarycodec::types::Currency;
+use crate::core::exceptions::XRPLCoreResult;
+use crate::core::keypairs::utils::sha512_first_half;
+
+/// Namespace of `AccountRoot` objects.
+const ACCOUNT_ROOT_SPACE: u16 = 0x0061;
+/// Namespace of `Check` objects.
+const CHECK_SPACE: u16 = 0x0043;
+/// Namespace of `Escrow` objects.
+const ESCROW_SPACE: u16 = 0x0075;
+/// Namespace of `Offer` objects.
+const OFFER_SPACE: u16 = 0x006F;
+/// Namespace of `RippleState` objects.
+const RIPPLE_STATE_SPACE: u16 = 0x0072;
+/// Namespace of `Ticket` objects.
+const TICKET_SPACE: u16 = 0x0054;
+
+fn _hash_index(space: u16, parts: &[&[u8]]) -> String {
+    let mut preimage: Vec<u8> = Vec::from(space.to_be_bytes());
+    for part in parts {
+        preimage.extend_from_slice(part);
+    }
+
+    hex::encode_upper(sha512_first_half(&preimage))
+}
+
+fn _account_sequence_index(space: u16, address: &str, sequence: u32) -> XRPLCoreResult<String> {
+    let account_id = decode_classic_address(address)?;
+
+    Ok(_hash_index(space, &[&account_id, &sequence.to_be_bytes()]))
+}
+
+/// Returns the index of an account's `AccountRoot` object.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::keylet::account_root;
+///
+/// let index = account_root("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").unwrap();
+///
+/// assert_eq!(
+///     "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8",
+///     index
+/// );
+/// ```
+pub fn account_root(address: &str) -> XRPLCoreResult<String> {
+    let account_id = decode_classic_address(address)?;
+
+    Ok(_hash_index(ACCOUNT_ROOT_SPACE, &[&account_id]))
+}
+
+/// Returns the index of the `Offer` created by `address`
+/// with the given `sequence`.
+pub fn offer(address: &str, sequence: u32) -> XRPLCoreResult<String> {
+    _account_sequence_index(OFFER_SPACE, address, sequence)
+}
+
+/// Returns the index of the `RippleState` (trust line) between
+/// two accounts for the given currency. The order of the
+/// accounts does not matter.
+pub fn ripple_state(address1: &str, address2: &str, currency: &str) -> XRPLCoreResult<String> {
+    let account_id1 = decode_classic_address(address1)?;
+    let account_id2 = decode_classic_address(address2)?;
+    let currency = Currency::try_from(currency)?;
+    let (low, high) = if account_id1 < account_id2 {
+        (account_id1, account_id2)
+    } else {
+        (account_id2, account_id1)
+    };
+
+    Ok(_hash_index(
+        RIPPLE_STATE_SPACE,
+        &[&low, &high, currency.as_ref()],
+    ))
+}
+
+/// Returns the index of the `Escrow` created by `address`
+/// with the given `sequence`.
+pub fn escrow(address: &str, sequence: u32) -> XRPLCoreResult<String> {
+    _account_sequence_index(ESCROW_SPACE, address, sequence)
+}
+
+/// Returns the index of the `Check` created by `address`
+/// with the given `sequence`.
+pub fn check(address: &str, sequence: u32) -> XRPLCoreResult<String> {
+    _account_sequence_index(CHECK_SPACE, address, sequence)
+}
+
+/// Returns the index of the `Ticket` owned by `address`
+/// with the given `ticket_sequence`.
+pub fn ticket(address: &str, ticket_sequence: u32) -> XRPLCoreResult<String> {
+    _account_sequence_index(TICKET_SPACE, address, ticket_sequence)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ACCOUNT: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+    const PEER: &str = "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW";
+
+    #[test]
+    fn test_account_root() {
+        assert_eq!(
+            "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8",
+            account_root(ACCOUNT).unwrap()
+        );
+        assert!(account_root("rInvalid").is_err());
+    }
+
+    #[test]
+    fn test_offer() {
+        assert_eq!(
+            "96F76F27D8A327FC48753167EC04A46AA0E382E6F57F32FD12274144D00F1797",
+            offer("rBqb89MRQJnMPq8wTwEbtz4kvxrEDfcYvt", 866).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_ripple_state() {
+        let expected = "9CA88CDEDFF9252B3DE183CE35B038F57282BC9503CDFA1923EF9A95DF0D6F7B";
+
+        assert_eq!(expected, ripple_state(ACCOUNT, PEER, "USD").unwrap());
+        assert_eq!(expected, ripple_state(PEER, ACCOUNT, "USD").unwrap());
+        assert_eq!(
+            expected,
+            ripple_state(ACCOUNT, PEER, "0000000000000000000000005553440000000000").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_escrow() {
+        assert_eq!(
+            "DC5F3851D8A1AB622F957761E5963BC5BD439D5C24AC6AD7AC4523F0640244AC",
+            escrow(ACCOUNT, 366).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(
+            "49647F0D748DC3FE26BDACBC57F251AADEFFF391403EC9BF87C97F67E9977FB0",
+            check("rUn84CUYbNjRoTQ6mSW7BVJPSVJNLb1QLo", 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_ticket() {
+        // The same account and sequence produce different indices per namespace.
+        let ticket = ticket(ACCOUNT, 366).unwrap();
+
+        assert_eq!(ticket.len(), 64);
+        assert_ne!(ticket, escrow(ACCOUNT, 366).unwrap());
+        assert_ne!(ticket, check(ACCOUNT, 366).unwrap());
+        assert_ne!(ticket, offer(ACCOUNT, 366).unwrap());
+    }
+}
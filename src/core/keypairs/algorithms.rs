@@ -75,10 +75,11 @@ impl Secp256k1 {
 
     /// Concat candidate key.
     fn _candidate_merger(input: &[u8], candidate: &[u8], phase: &Secp256k1Phase) -> Vec<u8> {
-        if phase == &Secp256k1Phase::Root {
-            [input, candidate].concat()
-        } else {
-            [input, &SECP256K1_INTERMEDIATE_KEYPAIR_PADDING, candidate].concat()
+        match phase {
+            Secp256k1Phase::Root => [input, candidate].concat(),
+            Secp256k1Phase::Mid(account_index) => {
+                [input, &account_index.to_be_bytes(), candidate].concat()
+            }
         }
     }
 
@@ -86,9 +87,8 @@ impl Secp256k1 {
     /// for a given phase of this algorithm. The difference
     /// between generating the root and intermediate keypairs
     /// is just what bytes are input by the caller and that
-    /// the intermediate keypair needs to inject
-    /// SECP256K1_INTERMEDIATE_KEYPAIR_PADDING into the value
-    /// to hash to get the raw private key.
+    /// the intermediate keypair needs to inject the account
+    /// index into the value to hash to get the raw private key.
     fn _derive_part(
         bytes: &[u8],
         phase: Secp256k1Phase,
@@ -142,6 +142,23 @@ impl Secp256k1 {
 
         Err(XRPLKeypairsException::InvalidSecret.into())
     }
+
+    /// Derives the keypair of the account at `account_index`
+    /// from a seed value. Index 0 is the account returned by
+    /// `derive_keypair`.
+    pub fn derive_keypair_at_index(
+        &self,
+        decoded_seed: &[u8],
+        account_index: u32,
+    ) -> XRPLCoreResult<(String, String)> {
+        let (root_public, root_secret) = Self::_derive_part(decoded_seed, Secp256k1Phase::Root)?;
+        let (mid_public, mid_secret) =
+            Self::_derive_part(&root_public.serialize(), Secp256k1Phase::Mid(account_index))?;
+        let (final_public, final_secret) =
+            Self::_derive_final(root_public, root_secret, mid_public, mid_secret)?;
+
+        Ok(Secp256k1::_format_keys(final_public, final_secret))
+    }
 }
 
 impl Ed25519 {
@@ -217,16 +234,13 @@ impl CryptoImplementation for Secp256k1 {
         decoded_seed: &[u8],
         is_validator: bool,
     ) -> XRPLCoreResult<(String, String)> {
-        let (root_public, root_secret) = Self::_derive_part(decoded_seed, Secp256k1Phase::Root)?;
         if is_validator {
+            let (root_public, root_secret) =
+                Self::_derive_part(decoded_seed, Secp256k1Phase::Root)?;
+
             Ok(Secp256k1::_format_keys(root_public, root_secret))
         } else {
-            let (mid_public, mid_secret) =
-                Self::_derive_part(&root_public.serialize(), Secp256k1Phase::Mid)?;
-            let (final_public, final_secret) =
-                Self::_derive_final(root_public, root_secret, mid_public, mid_secret)?;
-
-            Ok(Secp256k1::_format_keys(final_public, final_secret))
+            self.derive_keypair_at_index(decoded_seed, 0)
         }
     }
 
@@ -315,7 +329,12 @@ impl CryptoImplementation for Secp256k1 {
             let public = secp256k1::PublicKey::from_str(public_key);
 
             if let (&Ok(s), &Ok(p)) = (&sig.as_ref(), &public.as_ref()) {
-                secp.verify_ecdsa(&msg, s, p).is_ok()
+                // rippled only accepts fully canonical signatures, whose
+                // S value is in the lower half of the curve order.
+                let mut normalized = *s;
+                normalized.normalize_s();
+
+                normalized == *s && secp.verify_ecdsa(&msg, s, p).is_ok()
             } else {
                 false
             }
@@ -454,7 +473,10 @@ impl CryptoImplementation for Ed25519 {
     /// ));
     /// ```
     fn is_valid_message(&self, message: &[u8], signature: &str, public_key: &str) -> bool {
-        let raw_public = hex::decode(&public_key[ED25519_PREFIX.len()..]);
+        let Some(raw_public) = public_key.get(ED25519_PREFIX.len()..) else {
+            return false;
+        };
+        let raw_public = hex::decode(raw_public);
         let decoded_sig = hex::decode(signature);
 
         if raw_public.is_err() || decoded_sig.is_err() {
@@ -462,7 +484,9 @@ impl CryptoImplementation for Ed25519 {
         };
 
         if let (Ok(rpub), Ok(dsig)) = (raw_public, decoded_sig) {
-            let rpub = rpub.as_slice().try_into().unwrap();
+            let Ok(rpub) = rpub.as_slice().try_into() else {
+                return false;
+            };
             let public = ed25519_dalek::VerifyingKey::from_bytes(rpub);
 
             if dsig.len() != ED25519_SIGNATURE_LENGTH {
@@ -517,6 +541,33 @@ mod test {
         assert!(Secp256k1.is_valid_message(message, signature, PUBLIC_SECP256K1));
     }
 
+    #[test]
+    fn test_secp256k1_is_valid_message_rejects_high_s() {
+        let message: &[u8] = TEST_MESSAGE.as_bytes();
+        let signature = Secp256k1.sign(message, PRIVATE_SECP256K1).unwrap();
+        let compact = ecdsa::Signature::from_der(&signature)
+            .unwrap()
+            .serialize_compact();
+        let low_s = U256::from_be_slice(&compact[32..]);
+        let high_s = U256::from_be_bytes(secp256k1::constants::CURVE_ORDER).wrapping_sub(&low_s);
+        let mut high_s_compact = compact;
+        high_s_compact[32..].copy_from_slice(&high_s.to_be_bytes());
+        let high_s_signature = ecdsa::Signature::from_compact(&high_s_compact)
+            .unwrap()
+            .serialize_der();
+
+        assert!(Secp256k1.is_valid_message(
+            message,
+            &hex::encode_upper(&signature),
+            PUBLIC_SECP256K1
+        ));
+        assert!(!Secp256k1.is_valid_message(
+            message,
+            &hex::encode_upper(high_s_signature),
+            PUBLIC_SECP256K1
+        ));
+    }
+
     #[test]
     fn test_ed25519_derive_keypair() {
         let seed: &[u8] = SEED_ED25519.as_bytes();
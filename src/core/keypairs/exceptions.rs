@@ -14,6 +14,11 @@ pub enum XRPLKeypairsException {
     InvalidSecret,
     #[error("Unsupported validator algorithm: {expected:?}")]
     UnsupportedValidatorAlgorithm { expected: CryptoAlgorithm },
+    #[error("Account index {index} is not supported for {algorithm:?} keys")]
+    UnsupportedAccountIndex {
+        algorithm: CryptoAlgorithm,
+        index: u32,
+    },
     #[error("ed25519 error")]
     ED25519Error,
     #[error("secp256k1 error: {0:?}")]
@@ -12,11 +12,14 @@ pub use self::algorithms::Secp256k1;
 use crate::constants::CryptoAlgorithm;
 use crate::core::addresscodec::utils::SEED_LENGTH;
 use crate::core::addresscodec::*;
+use crate::core::binarycodec::encode_for_signing_claim;
 use crate::core::keypairs::exceptions::XRPLKeypairsException;
 use crate::core::keypairs::utils::*;
 use alloc::boxed::Box;
 use alloc::string::String;
+use alloc::vec;
 use alloc::vec::Vec;
+use ed25519_dalek::Verifier;
 use rand::Rng;
 use rand::SeedableRng;
 
@@ -32,9 +35,10 @@ const fn _get_algorithm_sig_length(algo: CryptoAlgorithm) -> usize {
 
 /// Return the CryptoAlgorithm from a key.
 fn _get_algorithm_from_key(key: &str) -> CryptoAlgorithm {
-    match &key[..2] {
-        ED25519_PREFIX => CryptoAlgorithm::ED25519,
-        _ => CryptoAlgorithm::SECP256K1,
+    if key.starts_with(ED25519_PREFIX) {
+        CryptoAlgorithm::ED25519
+    } else {
+        CryptoAlgorithm::SECP256K1
     }
 }
 
@@ -50,10 +54,19 @@ fn _get_algorithm_engine(algo: CryptoAlgorithm) -> Box<dyn CryptoImplementation>
 /// Return the trait implementation based on the
 /// provided key.
 fn _get_algorithm_engine_from_key(key: &str) -> Box<dyn CryptoImplementation> {
-    match &key[..2] {
-        ED25519_PREFIX => _get_algorithm_engine(CryptoAlgorithm::ED25519),
-        _ => _get_algorithm_engine(CryptoAlgorithm::SECP256K1),
-    }
+    _get_algorithm_engine(_get_algorithm_from_key(key))
+}
+
+/// Returns whether `public_key` is shaped like a public key: 33
+/// hex-encoded bytes starting with `ED` for Ed25519, or `02` or `03`
+/// for a compressed secp256k1 key. Whether it is on the curve is left
+/// to the verification.
+pub(crate) fn is_valid_public_key(public_key: &str) -> bool {
+    public_key.len() == SECP256K1_KEY_LENGTH
+        && public_key.bytes().all(|byte| byte.is_ascii_hexdigit())
+        && [ED25519_PREFIX, "02", "03"]
+            .iter()
+            .any(|prefix| public_key.starts_with(prefix))
 }
 
 /// Generate a seed value that cryptographic keys
@@ -150,6 +163,55 @@ pub fn derive_keypair(seed: &str, validator: bool) -> XRPLCoreResult<(String, St
     let (decoded_seed, algorithm) = decode_seed(seed)?;
     let module = _get_algorithm_engine(algorithm);
     let (public, private) = module.derive_keypair(&decoded_seed, validator)?;
+
+    _verify_keypair(module.as_ref(), public, private)
+}
+
+/// Derive the public and private keys of the account at
+/// `index` from a given seed value, so that several
+/// accounts can be derived from one secp256k1 seed.
+///
+/// Index 0 returns the same keys as `derive_keypair`.
+/// Ed25519 seeds and validator keys only support index 0.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::keypairs::{derive_keypair, derive_keypair_at_index};
+///
+/// let seed: &str = "sp5fghtJtpUorTwvof1NpDXAzNwf5";
+///
+/// assert_eq!(
+///     derive_keypair(seed, false).unwrap(),
+///     derive_keypair_at_index(seed, 0, false).unwrap(),
+/// );
+/// assert!(derive_keypair_at_index(seed, 1, false).is_ok());
+/// ```
+pub fn derive_keypair_at_index(
+    seed: &str,
+    index: u32,
+    validator: bool,
+) -> XRPLCoreResult<(String, String)> {
+    if index == 0 {
+        return derive_keypair(seed, validator);
+    }
+    let (decoded_seed, algorithm) = decode_seed(seed)?;
+    if validator || algorithm == CryptoAlgorithm::ED25519 {
+        return Err(XRPLKeypairsException::UnsupportedAccountIndex { algorithm, index }.into());
+    }
+    let (public, private) = Secp256k1.derive_keypair_at_index(&decoded_seed, index)?;
+
+    _verify_keypair(&Secp256k1, public, private)
+}
+
+/// Make sure a derived keypair can sign and verify a message.
+fn _verify_keypair(
+    module: &dyn CryptoImplementation,
+    public: String,
+    private: String,
+) -> XRPLCoreResult<(String, String)> {
     let signature = sign(SIGNATURE_VERIFICATION_MESSAGE, &private)?;
 
     if module.is_valid_message(SIGNATURE_VERIFICATION_MESSAGE, &signature, &public) {
@@ -260,6 +322,148 @@ pub fn is_valid_message(message: &[u8], signature: &str, public_key: &str) -> bo
     module.is_valid_message(message, signature, public_key)
 }
 
+/// Verifies a list of signatures, e.g. all transactions of a ledger.
+/// Each item is a message with its signature and public key, and the
+/// result holds whether each one is valid, in order.
+///
+/// Ed25519 signatures are checked together with a single batch
+/// verification, which is considerably faster than checking them one by
+/// one. A batch only tells whether all of its signatures are valid, so
+/// if one is not, the Ed25519 items are checked one by one to find it.
+/// secp256k1 signatures are always checked one by one.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::keypairs::batch_verify;
+///
+/// let message: &[u8] = "test message".as_bytes();
+/// let signature: &str = "CB199E1BFD4E3DAA105E4832EEDFA36413E1F44205E4EFB9\
+///                        E27E826044C21E3E2E848BBC8195E8959BADF887599B7310\
+///                        AD1B7047EF11B682E0D068F73749750E";
+/// let public_key: &str = "ED01FA53FA5A7E77798F882ECE20B1ABC00\
+///                         BB358A9E55A202D0D0676BD0CE37A63";
+///
+/// assert_eq!(
+///     batch_verify(&[(message, signature, public_key), (b"other", signature, public_key)]),
+///     [true, false],
+/// );
+/// ```
+pub fn batch_verify(items: &[(&[u8], &str, &str)]) -> Vec<bool> {
+    let mut results = vec![false; items.len()];
+    let mut indexes = Vec::new();
+    let mut messages = Vec::new();
+    let mut signatures = Vec::new();
+    let mut verifying_keys = Vec::new();
+    for (index, (message, signature, public_key)) in items.iter().enumerate() {
+        match _get_algorithm_from_key(public_key) {
+            CryptoAlgorithm::ED25519 => {
+                // Malformed keys and signatures are invalid and stay out
+                // of the batch.
+                if let Some((signature, verifying_key)) = _parse_ed25519(signature, public_key) {
+                    indexes.push(index);
+                    messages.push(*message);
+                    signatures.push(signature);
+                    verifying_keys.push(verifying_key);
+                }
+            }
+            CryptoAlgorithm::SECP256K1 => {
+                results[index] = is_valid_message(message, signature, public_key);
+            }
+        }
+    }
+    if ed25519_dalek::verify_batch(&messages, &signatures, &verifying_keys).is_ok() {
+        for index in indexes {
+            results[index] = true;
+        }
+    } else {
+        for (position, index) in indexes.into_iter().enumerate() {
+            results[index] = verifying_keys[position]
+                .verify(messages[position], &signatures[position])
+                .is_ok();
+        }
+    }
+
+    results
+}
+
+/// Parses a hex-encoded Ed25519 signature and `ED`-prefixed public key.
+fn _parse_ed25519(
+    signature: &str,
+    public_key: &str,
+) -> Option<(ed25519_dalek::Signature, ed25519_dalek::VerifyingKey)> {
+    let public_key: [u8; ed25519_dalek::PUBLIC_KEY_LENGTH] =
+        hex::decode(public_key.get(ED25519_PREFIX.len()..)?)
+            .ok()?
+            .try_into()
+            .ok()?;
+    let signature: [u8; ED25519_SIGNATURE_LENGTH] = hex::decode(signature).ok()?.try_into().ok()?;
+
+    Some((
+        ed25519_dalek::Signature::from(signature),
+        ed25519_dalek::VerifyingKey::from_bytes(&public_key).ok()?,
+    ))
+}
+
+/// Signs a claim against the payment channel `channel_id` for
+/// `amount` drops of XRP. The signature can be passed to the channel's
+/// receiver, who redeems it with a `PaymentChannelClaim`.
+pub fn sign_payment_channel_claim(
+    channel_id: &str,
+    amount: &str,
+    private_key: &str,
+) -> XRPLCoreResult<String> {
+    let message = hex::decode(encode_for_signing_claim(channel_id, amount)?)?;
+    sign(&message, private_key)
+}
+
+/// Verifies a claim against the payment channel `channel_id` for
+/// `amount` drops of XRP without asking a node, e.g. before accepting
+/// it as payment. `public_key` is the channel's public key. Malformed
+/// input is treated as an invalid claim.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::keypairs::{
+///     derive_keypair, sign_payment_channel_claim, verify_payment_channel_claim,
+/// };
+///
+/// let channel_id = "43904CBFCDCEC530B4037871F86EE90BF799DF8D2E0EA564BC8A3F332E4F5FB1";
+/// let (public_key, private_key) =
+///     derive_keypair("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r", false).unwrap();
+/// let signature = sign_payment_channel_claim(channel_id, "1000", &private_key).unwrap();
+///
+/// assert!(verify_payment_channel_claim(
+///     channel_id,
+///     "1000",
+///     &signature,
+///     &public_key,
+/// ));
+/// ```
+pub fn verify_payment_channel_claim(
+    channel_id: &str,
+    amount: &str,
+    signature: &str,
+    public_key: &str,
+) -> bool {
+    if !is_valid_public_key(public_key) {
+        return false;
+    }
+    let Ok(message) = encode_for_signing_claim(channel_id, amount) else {
+        return false;
+    };
+    let Ok(message) = hex::decode(message) else {
+        return false;
+    };
+
+    is_valid_message(&message, signature, public_key)
+}
+
 /// Trait for cryptographic algorithms in the XRP Ledger.
 /// The classes for all cryptographic algorithms are
 /// derived from this trait.
@@ -313,6 +517,25 @@ mod test {
         assert_eq!(PUBLIC_SECP256K1, public_secp256k1);
     }
 
+    #[test]
+    fn test_derive_keypair_at_index() {
+        let first = derive_keypair_at_index(SEED_SECP256K1, 0, false).unwrap();
+        let second = derive_keypair_at_index(SEED_SECP256K1, 1, false).unwrap();
+
+        assert_eq!(
+            (PUBLIC_SECP256K1, PRIVATE_SECP256K1),
+            (&*first.0, &*first.1)
+        );
+        assert_ne!(first, second);
+        assert_eq!(
+            derive_keypair_at_index(SEED_SECP256K1, 1, false).unwrap(),
+            second
+        );
+        assert!(derive_keypair_at_index(SEED_ED25519, 0, false).is_ok());
+        assert!(derive_keypair_at_index(SEED_ED25519, 1, false).is_err());
+        assert!(derive_keypair_at_index(SEED_SECP256K1, 1, true).is_err());
+    }
+
     #[test]
     fn test_derive_classic_address() {
         assert_eq!(
@@ -347,5 +570,148 @@ mod test {
 
         assert!(is_valid_message(message, sig_ed25519, PUBLIC_ED25519));
         assert!(is_valid_message(message, sig_secp256k1, PUBLIC_SECP256K1));
+        for public_key in ["", "E", "ED", "ED00", "0"] {
+            assert!(!is_valid_message(message, sig_ed25519, public_key));
+        }
+    }
+
+    #[test]
+    fn test_is_valid_public_key() {
+        assert!(is_valid_public_key(PUBLIC_ED25519));
+        assert!(is_valid_public_key(PUBLIC_SECP256K1));
+        assert!(!is_valid_public_key(""));
+        assert!(!is_valid_public_key("ED00"));
+        assert!(!is_valid_public_key(
+            &PUBLIC_ED25519.replacen("ED", "04", 1)
+        ));
+        assert!(!is_valid_public_key(
+            &PUBLIC_ED25519.replacen("ED", "EZ", 1)
+        ));
+    }
+
+    #[test]
+    fn test_batch_verify() {
+        let message: &[u8] = TEST_MESSAGE.as_bytes();
+        let sig_ed25519: &str = &hex::encode_upper(SIGNATURE_ED25519);
+        let sig_secp256k1: &str = &hex::encode_upper(SIGNATURE_SECP256K1);
+        let items: [(&[u8], &str, &str); 6] = [
+            (message, sig_ed25519, PUBLIC_ED25519),
+            (message, sig_secp256k1, PUBLIC_SECP256K1),
+            (b"other message", sig_ed25519, PUBLIC_ED25519),
+            (message, sig_secp256k1, PUBLIC_ED25519),
+            (message, "", PUBLIC_SECP256K1),
+            (message, sig_ed25519, "ED00"),
+        ];
+        let expected: Vec<bool> = items
+            .iter()
+            .map(|(message, signature, public_key)| {
+                is_valid_message(message, signature, public_key)
+            })
+            .collect();
+
+        assert_eq!(batch_verify(&items), expected);
+        assert_eq!(expected, [true, true, false, false, false, false]);
+        assert!(batch_verify(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_batch_verify_ed25519() {
+        let messages: Vec<String> = (0..16).map(|index| index.to_string()).collect();
+        let keypairs: Vec<(String, String)> = (0..16u8)
+            .map(|index| {
+                let seed = generate_seed(Some([index; SEED_LENGTH]), None).unwrap();
+                derive_keypair(&seed, false).unwrap()
+            })
+            .collect();
+        let signatures: Vec<String> = messages
+            .iter()
+            .zip(&keypairs)
+            .map(|(message, (_, private_key))| sign(message.as_bytes(), private_key).unwrap())
+            .collect();
+        let mut items: Vec<(&[u8], &str, &str)> = messages
+            .iter()
+            .zip(&signatures)
+            .zip(&keypairs)
+            .map(|((message, signature), (public_key, _))| {
+                (message.as_bytes(), signature.as_str(), public_key.as_str())
+            })
+            .collect();
+
+        assert!(batch_verify(&items).iter().all(|valid| *valid));
+
+        // A single bad signature only invalidates its own item.
+        items[7].0 = b"tampered";
+        let expected: Vec<bool> = (0..16).map(|index| index != 7).collect();
+        assert_eq!(batch_verify(&items), expected);
+    }
+
+    #[test]
+    fn test_verify_payment_channel_claim() {
+        let channel_id = "43904CBFCDCEC530B4037871F86EE90BF799DF8D2E0EA564BC8A3F332E4F5FB1";
+        let other_channel_id = "5DB01B7FFED6B67E6B0414DED11E051D2EE2B7619CE0EAA6286D67A3A4D5BDB3";
+
+        for (public_key, private_key) in [
+            (PUBLIC_ED25519, PRIVATE_ED25519),
+            (PUBLIC_SECP256K1, PRIVATE_SECP256K1),
+        ] {
+            let signature = sign_payment_channel_claim(channel_id, "1000000", private_key).unwrap();
+
+            assert!(verify_payment_channel_claim(
+                channel_id, "1000000", &signature, public_key
+            ));
+            assert!(!verify_payment_channel_claim(
+                channel_id, "1000001", &signature, public_key
+            ));
+            assert!(!verify_payment_channel_claim(
+                other_channel_id,
+                "1000000",
+                &signature,
+                public_key
+            ));
+        }
+    }
+
+    #[test]
+    fn test_verify_payment_channel_claim_invalid() {
+        let channel_id = "43904CBFCDCEC530B4037871F86EE90BF799DF8D2E0EA564BC8A3F332E4F5FB1";
+        let signature = sign_payment_channel_claim(channel_id, "1000", PRIVATE_ED25519).unwrap();
+
+        // Signed by a different key.
+        assert!(!verify_payment_channel_claim(
+            channel_id,
+            "1000",
+            &signature,
+            PUBLIC_SECP256K1
+        ));
+        assert!(!verify_payment_channel_claim(
+            channel_id,
+            "1000",
+            &signature[2..],
+            PUBLIC_ED25519
+        ));
+        assert!(!verify_payment_channel_claim(
+            channel_id,
+            "1.5",
+            &signature,
+            PUBLIC_ED25519
+        ));
+        assert!(!verify_payment_channel_claim(
+            "ABCD",
+            "1000",
+            &signature,
+            PUBLIC_ED25519
+        ));
+    }
+
+    #[test]
+    fn test_verify_payment_channel_claim_malformed_key() {
+        let channel_id = "43904CBFCDCEC530B4037871F86EE90BF799DF8D2E0EA564BC8A3F332E4F5FB1";
+        let signature = sign_payment_channel_claim(channel_id, "1000", PRIVATE_ED25519).unwrap();
+
+        for public_key in ["", "E", "ED", "ED00", &PUBLIC_ED25519[..64]] {
+            assert!(!verify_payment_channel_claim(
+                channel_id, "1000", &signature, public_key
+            ));
+        }
     }
 }
@@ -5,9 +5,6 @@ use core::convert::TryInto;
 use ripemd::Ripemd160;
 use sha2::{Digest, Sha256, Sha512};
 
-/// Intermediate private keys are always padded with
-/// 4 bytes of zeros.
-pub(crate) const SECP256K1_INTERMEDIATE_KEYPAIR_PADDING: [u8; 4] = [0, 0, 0, 0];
 /// String keys must be _KEY_LENGTH long
 pub(crate) const SECP256K1_KEY_LENGTH: usize = 66;
 /// SECP256K1 sequence size.
@@ -33,7 +30,8 @@ pub const SECP256K1_PREFIX: char = '0';
 #[derive(Debug, PartialEq)]
 pub(crate) enum Secp256k1Phase {
     Root,
-    Mid,
+    /// The intermediate keypair of the account at the given index.
+    Mid(u32),
 }
 
 /// Returns the first 32 bytes of SHA-512 hash of message.
@@ -0,0 +1,258 @@
+//! Functions for verifying that an object is part of a ledger's
+//! state or transaction tree.
+//!
+//! Both trees are SHAMaps: radix trees with a branching factor of 16
+//! keyed by the 256-bit index of their leaves. The hash of an inner
+//! node covers the hashes of its 16 children, so the root hash (the
+//! ledger's `account_hash` or `transaction_hash`) commits to every leaf.
+//!
+//! See SHAMap:
+//! `<https://xrpl.org/docs/concepts/ledgers/ledger-structure>`
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::core::binarycodec::exceptions::XRPLBinaryCodecException;
+use crate::core::binarycodec::{BinarySerializer, Serialization};
+use crate::core::exceptions::XRPLCoreResult;
+use crate::core::keypairs::utils::{sha512_first_half, SHA512_HASH_LENGTH};
+
+/// The number of children of an inner node.
+pub const BRANCH_FACTOR: usize = 16;
+
+/// Prefix of inner nodes ("MIN\0").
+const INNER_NODE_PREFIX: u32 = 0x4D494E00;
+/// Prefix of account state leaves ("MLN\0").
+const ACCOUNT_STATE_PREFIX: u32 = 0x4D4C4E00;
+/// Prefix of transaction leaves with metadata ("SND\0").
+const TRANSACTION_WITH_META_PREFIX: u32 = 0x534E4400;
+/// The deepest a leaf can be: one level per nibble of its index.
+const MAX_DEPTH: usize = SHA512_HASH_LENGTH * 2;
+
+type Hash = [u8; SHA512_HASH_LENGTH];
+
+/// A leaf of a SHAMap. All values are hex encoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SHAMapLeaf<'a> {
+    /// A ledger object in the state tree, keyed by its index.
+    AccountState { index: &'a str, data: &'a str },
+    /// A transaction and its metadata in the transaction tree, keyed
+    /// by the transaction hash.
+    TransactionWithMeta {
+        index: &'a str,
+        transaction: &'a str,
+        metadata: &'a str,
+    },
+}
+
+impl<'a> SHAMapLeaf<'a> {
+    pub fn index(&self) -> &'a str {
+        match self {
+            SHAMapLeaf::AccountState { index, .. } => index,
+            SHAMapLeaf::TransactionWithMeta { index, .. } => index,
+        }
+    }
+
+    /// Returns the hash of the leaf node.
+    pub fn hash(&self) -> XRPLCoreResult<String> {
+        Ok(hex::encode_upper(self._hash()?))
+    }
+
+    fn _hash(&self) -> XRPLCoreResult<Hash> {
+        let index = _decode_hash(self.index())?;
+        let mut preimage = Vec::new();
+        match self {
+            SHAMapLeaf::AccountState { data, .. } => {
+                preimage.extend(ACCOUNT_STATE_PREFIX.to_be_bytes());
+                preimage.extend(hex::decode(data)?);
+            }
+            SHAMapLeaf::TransactionWithMeta {
+                transaction,
+                metadata,
+                ..
+            } => {
+                let mut serializer = BinarySerializer::new();
+                serializer.write_length_encoded(&hex::decode(transaction)?, true);
+                serializer.write_length_encoded(&hex::decode(metadata)?, true);
+                preimage.extend(TRANSACTION_WITH_META_PREFIX.to_be_bytes());
+                preimage.extend(serializer);
+            }
+        }
+        preimage.extend(index);
+
+        Ok(sha512_first_half(&preimage))
+    }
+}
+
+/// Returns the hash of an inner node from the hashes of its children.
+/// Empty branches are given as an empty string.
+pub fn inner_node_hash(children: &[&str; BRANCH_FACTOR]) -> XRPLCoreResult<String> {
+    Ok(hex::encode_upper(_inner_node_hash(children)?))
+}
+
+fn _inner_node_hash(children: &[&str; BRANCH_FACTOR]) -> XRPLCoreResult<Hash> {
+    let mut preimage = Vec::from(INNER_NODE_PREFIX.to_be_bytes());
+    for child in children {
+        preimage.extend(_decode_child(child)?);
+    }
+
+    Ok(sha512_first_half(&preimage))
+}
+
+/// Verifies that `leaf` is part of the SHAMap with the given root hash.
+///
+/// `proof` holds the children of each inner node on the path from the
+/// root down to the leaf, starting with the root. The branch taken at
+/// each level is given by the leaf's index, one nibble per level.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::shamap::{inner_node_hash, verify_proof, SHAMapLeaf};
+///
+/// let leaf = SHAMapLeaf::AccountState {
+///     index: "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8",
+///     data: "1100612200000000",
+/// };
+/// let mut root = [""; 16];
+/// let leaf_hash = leaf.hash().unwrap();
+/// root[0x1] = &leaf_hash;
+/// let root_hash = inner_node_hash(&root).unwrap();
+///
+/// assert!(verify_proof(&leaf, &[root], &root_hash).unwrap());
+/// ```
+pub fn verify_proof(
+    leaf: &SHAMapLeaf<'_>,
+    proof: &[[&str; BRANCH_FACTOR]],
+    root_hash: &str,
+) -> XRPLCoreResult<bool> {
+    if proof.is_empty() || proof.len() > MAX_DEPTH {
+        return Ok(false);
+    }
+    let index = _decode_hash(leaf.index())?;
+    let mut hash = leaf._hash()?;
+    for (depth, node) in proof.iter().enumerate().rev() {
+        if _decode_child(node[_nibble(&index, depth)])? != hash {
+            return Ok(false);
+        }
+        hash = _inner_node_hash(node)?;
+    }
+
+    Ok(hash == _decode_hash(root_hash)?)
+}
+
+/// Returns the branch taken at `depth` to reach `index`.
+fn _nibble(index: &Hash, depth: usize) -> usize {
+    let byte = index[depth / 2];
+    if depth.is_multiple_of(2) {
+        (byte >> 4) as usize
+    } else {
+        (byte & 0x0F) as usize
+    }
+}
+
+fn _decode_child(hash: &str) -> XRPLCoreResult<Hash> {
+    if hash.is_empty() {
+        Ok([0; SHA512_HASH_LENGTH])
+    } else {
+        _decode_hash(hash)
+    }
+}
+
+fn _decode_hash(hash: &str) -> XRPLCoreResult<Hash> {
+    let bytes = hex::decode(hash)?;
+    let found = bytes.len();
+
+    Ok(bytes
+        .try_into()
+        .map_err(|_| XRPLBinaryCodecException::InvalidHashLength {
+            expected: SHA512_HASH_LENGTH,
+            found,
+        })?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INDEX1: &str = "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8";
+    const INDEX2: &str = "1B8C6E9A6B4C4D7E1A2B3C4D5E6F708192A3B4C5D6E7F8091A2B3C4D5E6F7081";
+    const INDEX3: &str = "A2E30A6B4C4D7E1A2B3C4D5E6F708192A3B4C5D6E7F8091A2B3C4D5E6F708123";
+
+    fn leaf(index: &str) -> SHAMapLeaf<'_> {
+        SHAMapLeaf::AccountState {
+            index,
+            data: "1100612200000000",
+        }
+    }
+
+    /// Builds a tree holding the leaves at `INDEX1` and `INDEX2`, which
+    /// share their first nibble, and at `INDEX3`. Returns the proof for
+    /// `INDEX1` and the root hash.
+    fn tree() -> ([[String; BRANCH_FACTOR]; 2], String) {
+        let mut inner: [String; BRANCH_FACTOR] = Default::default();
+        inner[0x3] = leaf(INDEX1).hash().unwrap();
+        inner[0xB] = leaf(INDEX2).hash().unwrap();
+        let mut root: [String; BRANCH_FACTOR] = Default::default();
+        root[0x1] = inner_node_hash(&inner.each_ref().map(String::as_str)).unwrap();
+        root[0xA] = leaf(INDEX3).hash().unwrap();
+        let root_hash = inner_node_hash(&root.each_ref().map(String::as_str)).unwrap();
+
+        ([root, inner], root_hash)
+    }
+
+    #[test]
+    fn test_verify_proof() {
+        let (proof, root_hash) = tree();
+        let proof = proof
+            .each_ref()
+            .map(|node| node.each_ref().map(String::as_str));
+
+        assert!(verify_proof(&leaf(INDEX1), &proof, &root_hash).unwrap());
+        // The inner node also proves its sibling.
+        assert!(verify_proof(&leaf(INDEX2), &proof, &root_hash).unwrap());
+        assert!(verify_proof(&leaf(INDEX3), &proof[..1], &root_hash).unwrap());
+    }
+
+    #[test]
+    fn test_verify_proof_rejects_tampering() {
+        let (proof, root_hash) = tree();
+        let proof = proof
+            .each_ref()
+            .map(|node| node.each_ref().map(String::as_str));
+        let tampered = SHAMapLeaf::AccountState {
+            index: INDEX1,
+            data: "1100612200010000",
+        };
+
+        assert!(!verify_proof(&tampered, &proof, &root_hash).unwrap());
+        // The path to this index ends in an empty branch.
+        assert!(!verify_proof(&leaf(&INDEX1.replacen('3', "5", 1)), &proof, &root_hash).unwrap());
+        assert!(!verify_proof(&leaf(INDEX1), &proof[1..], &root_hash).unwrap());
+        assert!(!verify_proof(&leaf(INDEX1), &[], &root_hash).unwrap());
+    }
+
+    #[test]
+    fn test_transaction_leaf_hash() {
+        let leaf = SHAMapLeaf::TransactionWithMeta {
+            index: INDEX1,
+            transaction: "120003",
+            metadata: "201C00000000",
+        };
+        // The prefix, then both blobs with their length prefixes.
+        let mut preimage = hex::decode("534E44000312000306201C00000000").unwrap();
+        preimage.extend(hex::decode(INDEX1).unwrap());
+
+        assert_eq!(
+            leaf.hash().unwrap(),
+            hex::encode_upper(sha512_first_half(&preimage))
+        );
+    }
+
+    #[test]
+    fn test_invalid_hash_length() {
+        assert!(verify_proof(&leaf("ABCD"), &[[""; BRANCH_FACTOR]], "").is_err());
+    }
+}
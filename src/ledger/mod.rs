@@ -1,19 +1,27 @@
+use alloc::borrow::Cow;
+use core::time::Duration;
+
 use embassy_futures::block_on;
+use serde::de::DeserializeOwned;
 
 use crate::{
     asynch::{
         clients::XRPLClient,
         exceptions::XRPLHelperResult,
         ledger::{
-            get_fee as async_get_fee,
+            get_amm_info as async_get_amm_info, get_fee as async_get_fee,
             get_latest_open_ledger_sequence as async_get_latest_open_ledger_sequence,
             get_latest_validated_ledger_sequence as async_get_latest_validated_ledger_sequence,
+            get_ledger_entry as async_get_ledger_entry, get_order_book as async_get_order_book,
+            wait_for_ledger as async_wait_for_ledger,
         },
     },
-    models::XRPAmount,
+    models::{
+        requests::ledger_entry::LedgerEntry, results::amm_info::AMMDescription, Currency, XRPAmount,
+    },
 };
 
-pub use crate::asynch::ledger::FeeType;
+pub use crate::asynch::ledger::{FeeType, OrderBook};
 
 pub fn get_latest_validated_ledger_sequence<C>(client: &C) -> XRPLHelperResult<u32>
 where
@@ -39,3 +47,61 @@ where
 {
     block_on(async_get_fee(client, max_fee, fee_type))
 }
+
+pub fn get_ledger_entry<T, C>(request: LedgerEntry<'_>, client: &C) -> XRPLHelperResult<T>
+where
+    T: DeserializeOwned,
+    C: XRPLClient,
+{
+    block_on(async_get_ledger_entry(request, client))
+}
+
+pub fn wait_for_ledger<C>(
+    client: &C,
+    target_ledger_index: u32,
+    poll_interval: Duration,
+    timeout: Duration,
+) -> XRPLHelperResult<u32>
+where
+    C: XRPLClient,
+{
+    block_on(async_wait_for_ledger(
+        client,
+        target_ledger_index,
+        poll_interval,
+        timeout,
+    ))
+}
+
+pub fn get_order_book<'a, C>(
+    base: Currency<'a>,
+    counter: Currency<'a>,
+    client: &C,
+    limit: Option<u16>,
+    ledger_index: Option<Cow<'a, str>>,
+    filter_expired: bool,
+) -> XRPLHelperResult<OrderBook<'a>>
+where
+    C: XRPLClient,
+{
+    block_on(async_get_order_book(
+        base,
+        counter,
+        client,
+        limit,
+        ledger_index,
+        filter_expired,
+    ))
+}
+
+pub fn get_amm_info<'a, C>(
+    asset: Currency<'a>,
+    asset2: Currency<'a>,
+    client: &C,
+    ledger_index: Option<Cow<'a, str>>,
+) -> XRPLHelperResult<AMMDescription<'a>>
+where
+    C: XRPLClient,
+{
+    block_on(async_get_amm_info(asset, asset2, client, ledger_index))
+}
@@ -32,7 +32,12 @@ extern crate std as alloc;
 
 #[cfg(feature = "helpers")]
 pub mod account;
-#[cfg(any(feature = "json-rpc", feature = "websocket", feature = "helpers"))]
+#[cfg(any(
+    feature = "json-rpc",
+    feature = "websocket",
+    feature = "helpers",
+    feature = "test-util"
+))]
 pub mod asynch;
 #[cfg(any(feature = "json-rpc", feature = "websocket"))]
 pub mod clients;
@@ -1,7 +1,15 @@
+use crate::core::addresscodec::is_valid_classic_address;
 use crate::models::{Model, XRPLModelException, XRPLModelResult};
+use crate::utils::{
+    exceptions::XRPRangeException, is_iso_code, is_iso_hex, MAX_IOU_EXPONENT, MAX_IOU_PRECISION,
+    MIN_IOU_EXPONENT,
+};
 use alloc::borrow::Cow;
-use bigdecimal::BigDecimal;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use bigdecimal::{BigDecimal, Signed, Zero};
 use core::convert::TryInto;
+use core::fmt::Display;
 use core::str::FromStr;
 use serde::{Deserialize, Serialize};
 
@@ -12,7 +20,11 @@ pub struct IssuedCurrencyAmount<'a> {
     pub value: Cow<'a, str>,
 }
 
-impl<'a> Model for IssuedCurrencyAmount<'a> {}
+impl<'a> Model for IssuedCurrencyAmount<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self._get_value_range_error()
+    }
+}
 
 impl<'a> IssuedCurrencyAmount<'a> {
     pub fn new(currency: Cow<'a, str>, issuer: Cow<'a, str>, value: Cow<'a, str>) -> Self {
@@ -22,6 +34,41 @@ impl<'a> IssuedCurrencyAmount<'a> {
             value,
         }
     }
+
+    /// The value must fit XRPL's token amount representation: at most
+    /// 16 significant digits and, once the mantissa is normalized to
+    /// 16 digits, an exponent within `MIN_IOU_EXPONENT..=MAX_IOU_EXPONENT`.
+    fn _get_value_range_error(&self) -> XRPLModelResult<()> {
+        let decimal = BigDecimal::from_str(&self.value)?;
+        if decimal.is_zero() {
+            return Ok(());
+        }
+        let (mantissa, scale) = decimal.normalized().as_bigint_and_exponent();
+        let digits = mantissa.abs().to_string().len();
+        if digits > MAX_IOU_PRECISION as usize {
+            return Err(XRPRangeException::InvalidICMantissaTooLong {
+                max: MAX_IOU_PRECISION,
+                found: digits,
+            }
+            .into());
+        }
+        let exponent = -(scale as i32) - (MAX_IOU_PRECISION as i32 - digits as i32);
+        if exponent > MAX_IOU_EXPONENT {
+            Err(XRPRangeException::InvalidICPrecisionTooLarge {
+                max: MAX_IOU_EXPONENT,
+                found: exponent,
+            }
+            .into())
+        } else if exponent < MIN_IOU_EXPONENT {
+            Err(XRPRangeException::InvalidICPrecisionTooSmall {
+                min: MIN_IOU_EXPONENT,
+                found: exponent,
+            }
+            .into())
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl<'a> TryInto<BigDecimal> for IssuedCurrencyAmount<'a> {
@@ -32,6 +79,52 @@ impl<'a> TryInto<BigDecimal> for IssuedCurrencyAmount<'a> {
     }
 }
 
+/// Parses an amount written as `value/currency/issuer`,
+/// for example `10.5/USD/rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn`.
+impl<'a> FromStr for IssuedCurrencyAmount<'a> {
+    type Err = XRPLModelException;
+
+    fn from_str(s: &str) -> XRPLModelResult<Self> {
+        let parts: Vec<&str> = s.split('/').collect();
+        let [value, currency, issuer] = parts[..] else {
+            return Err(XRPLModelException::InvalidValueFormat {
+                field: "IssuedCurrencyAmount".to_string(),
+                format: "value/currency/issuer".to_string(),
+                found: s.to_string(),
+            });
+        };
+        BigDecimal::from_str(value)?;
+        if !(is_iso_code(currency) && currency != "XRP" || is_iso_hex(currency)) {
+            return Err(XRPLModelException::InvalidValueFormat {
+                field: "currency".to_string(),
+                format: "3-character ISO code or 40-character hex code".to_string(),
+                found: currency.to_string(),
+            });
+        }
+        if !is_valid_classic_address(issuer) {
+            return Err(XRPLModelException::InvalidValueFormat {
+                field: "issuer".to_string(),
+                format: "classic address".to_string(),
+                found: issuer.to_string(),
+            });
+        }
+        let amount = Self::new(
+            currency.to_string().into(),
+            issuer.to_string().into(),
+            value.to_string().into(),
+        );
+        amount.validate()?;
+
+        Ok(amount)
+    }
+}
+
+impl<'a> Display for IssuedCurrencyAmount<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}/{}/{}", self.value, self.currency, self.issuer)
+    }
+}
+
 impl<'a> PartialOrd for IssuedCurrencyAmount<'a> {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
@@ -43,3 +136,83 @@ impl<'a> Ord for IssuedCurrencyAmount<'a> {
         self.value.cmp(&other.value)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn amount(value: &str) -> IssuedCurrencyAmount<'_> {
+        IssuedCurrencyAmount::new(
+            "USD".into(),
+            "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+            value.into(),
+        )
+    }
+
+    #[test]
+    fn test_value_range() {
+        assert!(amount("0").validate().is_ok());
+        assert!(amount("-123.456").validate().is_ok());
+        assert!(amount("9999999999999999e80").validate().is_ok());
+        assert!(amount("1e-81").validate().is_ok());
+
+        assert_eq!(
+            amount("1e96").validate(),
+            Err(XRPRangeException::InvalidICPrecisionTooLarge {
+                max: MAX_IOU_EXPONENT,
+                found: 81
+            }
+            .into())
+        );
+        assert_eq!(
+            amount("1e-82").validate(),
+            Err(XRPRangeException::InvalidICPrecisionTooSmall {
+                min: MIN_IOU_EXPONENT,
+                found: -97
+            }
+            .into())
+        );
+        assert_eq!(
+            amount("1.2345678901234567").validate(),
+            Err(XRPRangeException::InvalidICMantissaTooLong {
+                max: MAX_IOU_PRECISION,
+                found: 17
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        let parsed: IssuedCurrencyAmount = "10.5/USD/rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"
+            .parse()
+            .unwrap();
+        assert_eq!(parsed, amount("10.5"));
+        assert_eq!(
+            parsed.to_string(),
+            "10.5/USD/rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"
+        );
+
+        let nonstandard: IssuedCurrencyAmount =
+            "1/0158415500000000C1F76FF6ECB0BAC600000000/rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            nonstandard.currency,
+            "0158415500000000C1F76FF6ECB0BAC600000000"
+        );
+
+        assert!(IssuedCurrencyAmount::from_str("10.5/USD").is_err());
+        assert!(
+            IssuedCurrencyAmount::from_str("10.5/USD/rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn/x")
+                .is_err()
+        );
+        assert!(
+            IssuedCurrencyAmount::from_str("ten/USD/rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").is_err()
+        );
+        assert!(
+            IssuedCurrencyAmount::from_str("10/XRP/rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").is_err()
+        );
+        assert!(IssuedCurrencyAmount::from_str("10/USD/rNotAnAddress").is_err());
+    }
+}
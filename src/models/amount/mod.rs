@@ -6,7 +6,9 @@ pub use issued_currency_amount::*;
 pub use xrp_amount::*;
 
 use crate::models::Model;
+use alloc::string::ToString;
 use core::convert::TryInto;
+use core::str::FromStr;
 use serde::{Deserialize, Serialize};
 use strum_macros::Display;
 
@@ -30,7 +32,14 @@ impl<'a> TryInto<BigDecimal> for Amount<'a> {
     }
 }
 
-impl<'a> Model for Amount<'a> {}
+impl<'a> Model for Amount<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        match self {
+            Amount::IssuedCurrencyAmount(amount) => amount.get_errors(),
+            Amount::XRPAmount(amount) => amount.get_errors(),
+        }
+    }
+}
 
 impl<'a> Default for Amount<'a> {
     fn default() -> Self {
@@ -51,6 +60,29 @@ impl<'a> Amount<'a> {
     }
 }
 
+/// Parses a bare integer as an amount of XRP drops and a
+/// `value/currency/issuer` string as an issued currency amount.
+impl<'a> FromStr for Amount<'a> {
+    type Err = XRPLModelException;
+
+    fn from_str(s: &str) -> XRPLModelResult<Self> {
+        if s.contains('/') {
+            Ok(Self::IssuedCurrencyAmount(s.parse()?))
+        } else if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+            let amount = XRPAmount::from(s.to_string());
+            amount.validate()?;
+
+            Ok(Self::XRPAmount(amount))
+        } else {
+            Err(XRPLModelException::InvalidValueFormat {
+                field: "Amount".to_string(),
+                format: "drops or value/currency/issuer".to_string(),
+                found: s.to_string(),
+            })
+        }
+    }
+}
+
 impl<'a> From<IssuedCurrencyAmount<'a>> for Amount<'a> {
     fn from(value: IssuedCurrencyAmount<'a>) -> Self {
         Self::IssuedCurrencyAmount(value)
@@ -68,3 +100,28 @@ impl<'a> From<&'a str> for Amount<'a> {
         Self::XRPAmount(value.into())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            Amount::from_str("1000000"),
+            Ok(Amount::XRPAmount(XRPAmount::from("1000000")))
+        );
+        assert_eq!(
+            Amount::from_str("10/USD/rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"),
+            Ok(Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+                "10".into(),
+            )))
+        );
+        assert!(Amount::from_str("10.5").is_err());
+        assert!(Amount::from_str("-10").is_err());
+        assert!(Amount::from_str("").is_err());
+        assert!(Amount::from_str("100000000000000001").is_err());
+    }
+}
@@ -1,9 +1,10 @@
 use crate::models::{Model, XRPLModelException, XRPLModelResult};
+use crate::utils::{exceptions::XRPRangeException, MAX_DROPS};
 use alloc::{
     borrow::Cow,
     string::{String, ToString},
 };
-use bigdecimal::BigDecimal;
+use bigdecimal::{BigDecimal, Signed};
 use core::str::FromStr;
 use core::{
     convert::{TryFrom, TryInto},
@@ -16,7 +17,37 @@ use serde_json::Value;
 #[derive(Debug, PartialEq, Eq, Clone, Serialize)]
 pub struct XRPAmount<'a>(pub Cow<'a, str>);
 
-impl<'a> Model for XRPAmount<'a> {}
+impl<'a> Model for XRPAmount<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self._get_drops_range_error()
+    }
+}
+
+impl<'a> XRPAmount<'a> {
+    /// XRP amounts are unsigned integers of drops on the wire and can not
+    /// exceed the total supply of 100 billion XRP.
+    fn _get_drops_range_error(&self) -> XRPLModelResult<()> {
+        let drops = BigDecimal::from_str(&self.0)?;
+        let max_drops = BigDecimal::from(MAX_DROPS);
+        if drops.is_negative() {
+            Err(XRPRangeException::InvalidXRPAmountTooSmall {
+                min: "0".into(),
+                found: self.0.to_string(),
+            }
+            .into())
+        } else if !drops.is_integer() {
+            Err(XRPRangeException::InvalidValueContainsDecimal.into())
+        } else if drops > max_drops {
+            Err(XRPRangeException::InvalidDropsAmountTooLarge {
+                max: MAX_DROPS.to_string(),
+                found: self.0.to_string(),
+            }
+            .into())
+        } else {
+            Ok(())
+        }
+    }
+}
 
 impl Default for XRPAmount<'_> {
     fn default() -> Self {
@@ -60,8 +91,14 @@ impl<'a> From<String> for XRPAmount<'a> {
 }
 
 impl<'a> From<BigDecimal> for XRPAmount<'a> {
+    /// Whole amounts are written as plain integers of drops, e.g. `10`
+    /// rather than `10.000` or `1e+1`, which rippled would reject.
     fn from(value: BigDecimal) -> Self {
-        Self(value.to_string().into())
+        if value.is_integer() {
+            Self(value.with_scale(0).to_string().into())
+        } else {
+            Self(value.normalized().to_string().into())
+        }
     }
 }
 
@@ -81,6 +118,9 @@ impl<'a> TryFrom<Value> for XRPAmount<'a> {
     type Error = XRPLModelException;
 
     fn try_from(value: Value) -> XRPLModelResult<Self, Self::Error> {
+        if let Value::Number(number) = &value {
+            return Ok(BigDecimal::from_str(&number.to_string())?.into());
+        }
         match serde_json::to_string(&value) {
             Ok(amount_string) => {
                 let amount_string = amount_string.clone().replace("\"", "");
@@ -136,3 +176,69 @@ impl<'a> Ord for XRPAmount<'a> {
         self_decimal.cmp(&other_decimal)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_drops_range() {
+        assert!(XRPAmount::from("0").validate().is_ok());
+        assert!(XRPAmount::from("10").validate().is_ok());
+        assert!(XRPAmount::from("100000000000000000").validate().is_ok());
+
+        assert_eq!(
+            XRPAmount::from("-1").validate(),
+            Err(XRPRangeException::InvalidXRPAmountTooSmall {
+                min: "0".into(),
+                found: "-1".into()
+            }
+            .into())
+        );
+        assert_eq!(
+            XRPAmount::from("1.5").validate(),
+            Err(XRPRangeException::InvalidValueContainsDecimal.into())
+        );
+        assert_eq!(
+            XRPAmount::from("100000000000000001").validate(),
+            Err(XRPRangeException::InvalidDropsAmountTooLarge {
+                max: "100000000000000000".into(),
+                found: "100000000000000001".into()
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn test_from_decimal_as_integer_drops() {
+        let amount: XRPAmount = BigDecimal::from_str("10.000").unwrap().into();
+        assert_eq!(amount.0, "10");
+        let amount: XRPAmount = BigDecimal::from_str("1E+20").unwrap().into();
+        assert_eq!(amount.0, "100000000000000000000");
+        let amount: XRPAmount = serde_json::from_value(serde_json::json!(10.0)).unwrap();
+        assert_eq!(amount.0, "10");
+    }
+
+    #[test]
+    fn test_fee_encode_decode() {
+        use crate::core::binarycodec::{decode, encode};
+
+        for fee in [
+            XRPAmount::from("10"),
+            XRPAmount::from(BigDecimal::from_str("1E+1").unwrap()),
+        ] {
+            let transaction = serde_json::json!({
+                "TransactionType": "AccountSet",
+                "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "Fee": fee,
+                "Sequence": 1
+            });
+            assert_eq!(transaction["Fee"], Value::String("10".into()));
+
+            let decoded = decode(&encode(&transaction).unwrap()).unwrap();
+            assert_eq!(decoded["Fee"], Value::String("10".into()));
+            let decoded_fee: XRPAmount = serde_json::from_value(decoded["Fee"].clone()).unwrap();
+            assert_eq!(decoded_fee, XRPAmount::from("10"));
+        }
+    }
+}
@@ -5,7 +5,7 @@ use core::num::{ParseFloatError, ParseIntError};
 use alloc::string::String;
 use thiserror_no_std::Error;
 
-use crate::XRPLSerdeJsonError;
+use crate::{utils::exceptions::XRPRangeException, XRPLSerdeJsonError};
 
 use super::{
     results::exceptions::XRPLResultException,
@@ -62,6 +62,8 @@ pub enum XRPLModelException {
     ValueEqualsValue { field1: String, field2: String },
     #[error("The value of the field `{0:?}` is not allowed to be zero")]
     ValueZero(String),
+    #[error("The value of the field `{0:?}` is not allowed to be negative")]
+    ValueNegative(String),
     #[error("If the field `{field1:?}` is defined, the field `{field2:?}` must also be defined")]
     FieldRequiresField { field1: String, field2: String },
 
@@ -78,6 +80,8 @@ pub enum XRPLModelException {
     SerdeJsonError(#[from] XRPLSerdeJsonError),
     #[error("BigDecimal error: {0}")]
     BigDecimalError(#[from] bigdecimal::ParseBigDecimalError),
+    #[error("XRP Range error: {0}")]
+    XRPRangeError(#[from] XRPRangeException),
     #[error("{0}")]
     XRPLResultError(#[from] XRPLResultException),
     #[error("{0}")]
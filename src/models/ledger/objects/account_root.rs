@@ -8,7 +8,7 @@ use serde_repr::{Deserialize_repr, Serialize_repr};
 use serde_with::skip_serializing_none;
 use strum_macros::{AsRefStr, Display, EnumIter};
 
-use super::{CommonFields, LedgerObject};
+use super::{CommonFields, LedgerObject, LedgerObjectFlags};
 
 /// There are several options which can be either enabled or disabled for an account.
 /// These options can be changed with an `AccountSet` transaction.
@@ -127,6 +127,14 @@ impl<'a> LedgerObject<AccountRootFlag> for AccountRoot<'a> {
     }
 }
 
+impl<'a> LedgerObjectFlags for AccountRoot<'a> {
+    type Flag = AccountRootFlag;
+
+    fn get_flags(&self) -> &FlagCollection<AccountRootFlag> {
+        &self.common_fields.flags
+    }
+}
+
 impl<'a> AccountRoot<'a> {
     pub fn new(
         flags: FlagCollection<AccountRootFlag>,
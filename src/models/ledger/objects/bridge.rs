@@ -2,7 +2,10 @@ use alloc::borrow::Cow;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::models::{Model, NoFlags, XChainBridge, XRPAmount};
+use crate::{
+    _serde::uint64_hex,
+    models::{Model, NoFlags, XChainBridge, XRPAmount},
+};
 
 use super::{CommonFields, LedgerEntryType, LedgerObject};
 
@@ -15,8 +18,10 @@ pub struct Bridge<'a> {
     pub account: Cow<'a, str>,
     pub signature_reward: XRPAmount<'a>,
     #[serde(rename = "XChainAccountClaimCount")]
+    #[serde(with = "uint64_hex")]
     pub xchain_account_claim_count: u64,
     #[serde(rename = "XChainAccountCreateCount")]
+    #[serde(with = "uint64_hex")]
     pub xchain_account_create_count: u64,
     pub xchain_bridge: XChainBridge<'a>,
     #[serde(rename = "XChainClaimID")]
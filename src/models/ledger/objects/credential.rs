@@ -0,0 +1,148 @@
+use crate::models::ledger::objects::LedgerEntryType;
+use crate::models::FlagCollection;
+use crate::models::Model;
+use alloc::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use strum_macros::{AsRefStr, Display, EnumIter};
+
+use serde_with::skip_serializing_none;
+
+use super::{CommonFields, LedgerObject, LedgerObjectFlags};
+
+#[derive(
+    Debug, Eq, PartialEq, Clone, Serialize_repr, Deserialize_repr, Display, AsRefStr, EnumIter,
+)]
+#[repr(u32)]
+pub enum CredentialFlag {
+    /// The subject of the credential has accepted the credential.
+    LsfAccepted = 0x00010000,
+}
+
+/// A Credential object represents a credential, which contains an attestation
+/// about a subject account from a credential issuer account.
+///
+/// `<https://xrpl.org/docs/references/protocol/ledger-data/ledger-entry-types/credential>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Credential<'a> {
+    /// The base fields for all ledger object models.
+    ///
+    /// See Ledger Object Common Fields:
+    /// `<https://xrpl.org/ledger-entry-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields<'a, CredentialFlag>,
+    // The custom fields for the Credential model.
+    //
+    // See Credential fields:
+    // `<https://xrpl.org/docs/references/protocol/ledger-data/ledger-entry-types/credential#credential-fields>`
+    /// Arbitrary data defining the type of credential, as hex.
+    pub credential_type: Cow<'a, str>,
+    /// The account that issued the credential.
+    pub issuer: Cow<'a, str>,
+    /// A hint indicating which page of the issuer's owner directory links to this object.
+    pub issuer_node: Cow<'a, str>,
+    /// The identifying hash of the transaction that most recently modified this object.
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: Cow<'a, str>,
+    /// The index of the ledger that contains the transaction that most recently modified this object.
+    pub previous_txn_lgr_seq: u32,
+    /// The account that the credential is for.
+    pub subject: Cow<'a, str>,
+    /// A hint indicating which page of the subject's owner directory links to this object.
+    pub subject_node: Option<Cow<'a, str>>,
+    /// Time after which the credential is expired, in seconds since the Ripple Epoch.
+    pub expiration: Option<u32>,
+    /// Arbitrary additional data about the credential, as hex.
+    #[serde(rename = "URI")]
+    pub uri: Option<Cow<'a, str>>,
+}
+
+impl<'a> Model for Credential<'a> {}
+
+impl<'a> LedgerObject<CredentialFlag> for Credential<'a> {
+    fn has_flag(&self, flag: &CredentialFlag) -> bool {
+        self.common_fields.has_flag(flag)
+    }
+
+    fn get_ledger_entry_type(&self) -> LedgerEntryType {
+        self.common_fields.get_ledger_entry_type()
+    }
+}
+
+impl<'a> LedgerObjectFlags for Credential<'a> {
+    type Flag = CredentialFlag;
+
+    fn get_flags(&self) -> &FlagCollection<CredentialFlag> {
+        &self.common_fields.flags
+    }
+}
+
+impl<'a> Credential<'a> {
+    pub fn new(
+        flags: FlagCollection<CredentialFlag>,
+        index: Option<Cow<'a, str>>,
+        ledger_index: Option<Cow<'a, str>>,
+        credential_type: Cow<'a, str>,
+        issuer: Cow<'a, str>,
+        issuer_node: Cow<'a, str>,
+        previous_txn_id: Cow<'a, str>,
+        previous_txn_lgr_seq: u32,
+        subject: Cow<'a, str>,
+        subject_node: Option<Cow<'a, str>>,
+        expiration: Option<u32>,
+        uri: Option<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            common_fields: CommonFields {
+                flags,
+                ledger_entry_type: LedgerEntryType::Credential,
+                index,
+                ledger_index,
+            },
+            credential_type,
+            issuer,
+            issuer_node,
+            previous_txn_id,
+            previous_txn_lgr_seq,
+            subject,
+            subject_node,
+            expiration,
+            uri,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_serde() {
+        let credential = Credential::new(
+            vec![CredentialFlag::LsfAccepted].into(),
+            Some(Cow::from(
+                "DD40031C6C21164E7673A47C35513D52A6B0F1349A873EE0D188D8994CD4D001",
+            )),
+            None,
+            Cow::from("6D795F63726564656E7469616C"),
+            Cow::from("ra5nK24KXen9AHvsdFTKHSANinZseWnPcX"),
+            Cow::from("0"),
+            Cow::from("7D1257779E2D298C07C7E0C73CD446534B143FBD1F13DB268A878E40FD153B9A"),
+            8734523,
+            Cow::from("rsUiUMpnrgxQp24dJYZDhmV4bE3aBtQyt8"),
+            Some(Cow::from("0")),
+            None,
+            Some(Cow::from("")),
+        );
+        let serialized = serde_json::to_string(&credential).unwrap();
+
+        let deserialized: Credential = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(credential, deserialized);
+        assert!(deserialized.has_flag(&CredentialFlag::LsfAccepted));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::_serde::uint64_hex;
 use crate::models::FlagCollection;
 use crate::models::Model;
 use crate::models::{ledger::objects::LedgerEntryType, NoFlags};
@@ -44,9 +45,11 @@ pub struct DirectoryNode<'a> {
     pub root_index: Cow<'a, str>,
     /// If this `Directory` consists of multiple pages, this ID links to the next object in the chain,
     /// wrapping around at the end.
+    #[serde(default, with = "uint64_hex::option")]
     pub index_next: Option<u64>,
     /// If this `Directory` consists of multiple pages, this ID links to the previous object in the
     /// chain, wrapping around at the beginning.
+    #[serde(default, with = "uint64_hex::option")]
     pub index_previous: Option<u64>,
     /// (Owner Directories only) The address of the account that owns the objects in this directory.
     pub owner: Option<Cow<'a, str>>,
@@ -139,4 +142,28 @@ mod tests {
 
         assert_eq!(directory_node, deserialized);
     }
+
+    #[test]
+    fn test_large_uint64_fields() {
+        let directory_node: DirectoryNode = serde_json::from_value(serde_json::json!({
+            "Flags": 0,
+            "Indexes": ["AD7EAE148287EF12D213A251015F86E6D4BD34B3C4A0A1ED9A17198373F908AD"],
+            "IndexNext": "0000000100000001",
+            "IndexPrevious": "FFFFFFFFFFFFFFFF",
+            "LedgerEntryType": "DirectoryNode",
+            "Owner": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "RootIndex": "1BBEF97EDE88D40CEE2ADE6FEF121166AFE80D99EBADB01A4F069BA8FF484000",
+            "index": "1BBEF97EDE88D40CEE2ADE6FEF121166AFE80D99EBADB01A4F069BA8FF484000"
+        }))
+        .unwrap();
+
+        assert_eq!(directory_node.index_next, Some(0x1_0000_0001));
+        assert_eq!(directory_node.index_previous, Some(u64::MAX));
+        let serialized = serde_json::to_value(&directory_node).unwrap();
+        assert_eq!(serialized["IndexNext"], "0000000100000001");
+        assert_eq!(
+            serde_json::from_value::<DirectoryNode>(serialized).unwrap(),
+            directory_node
+        );
+    }
 }
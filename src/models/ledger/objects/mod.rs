@@ -3,6 +3,7 @@ pub mod amendments;
 pub mod amm;
 pub mod bridge;
 pub mod check;
+pub mod credential;
 pub mod deposit_preauth;
 pub mod directory_node;
 pub mod escrow;
@@ -23,6 +24,7 @@ pub use account_root::*;
 pub use amendments::*;
 pub use amm::*;
 pub use check::*;
+pub use credential::*;
 pub use deposit_preauth::*;
 pub use directory_node::*;
 pub use escrow::*;
@@ -40,12 +42,13 @@ use derive_new::new;
 use strum::IntoEnumIterator;
 
 use alloc::borrow::Cow;
+use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use strum_macros::Display;
 
 use crate::_serde::lgr_obj_flags;
-use crate::models::{Amount, FlagCollection};
+use crate::models::{Amount, FlagCollection, XRPLModelResult};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Display, PartialEq, Eq)]
 pub enum LedgerEntryType {
@@ -54,6 +57,7 @@ pub enum LedgerEntryType {
     AMM = 0x0079,
     Bridge = 0x0069,
     Check = 0x0043,
+    Credential = 0x0081,
     DepositPreauth = 0x0070,
     DirectoryNode = 0x0064,
     Escrow = 0x0075,
@@ -133,3 +137,44 @@ where
 
     fn get_ledger_entry_type(&self) -> LedgerEntryType;
 }
+
+/// Decodes the `Flags` of ledger objects which define `lsf*` flags.
+pub trait LedgerObjectFlags {
+    /// The flags defined for this ledger object type.
+    type Flag: IntoEnumIterator + Serialize + PartialEq + core::fmt::Debug;
+
+    /// The flags set on this object.
+    fn get_flags(&self) -> &FlagCollection<Self::Flag>;
+
+    /// Returns whether `flag` is set on this object.
+    fn is_flag_set(&self, flag: &Self::Flag) -> bool {
+        self.get_flags().0.contains(flag)
+    }
+
+    /// Returns every flag defined for this object type, paired with
+    /// whether it is set on this object.
+    fn flag_map(&self) -> Vec<(Self::Flag, bool)> {
+        Self::Flag::iter()
+            .map(|flag| {
+                let is_set = self.is_flag_set(&flag);
+                (flag, is_set)
+            })
+            .collect()
+    }
+
+    /// Maps a raw `Flags` value to every flag defined for this object
+    /// type, paired with whether it is set. Unknown bits are ignored.
+    fn decode_flags(flags: u32) -> XRPLModelResult<Vec<(Self::Flag, bool)>>
+    where
+        Self: Sized,
+    {
+        let set = FlagCollection::<Self::Flag>::try_from(flags)?;
+
+        Ok(Self::Flag::iter()
+            .map(|flag| {
+                let is_set = set.0.contains(&flag);
+                (flag, is_set)
+            })
+            .collect())
+    }
+}
@@ -9,7 +9,7 @@ use serde_repr::{Deserialize_repr, Serialize_repr};
 use serde_with::skip_serializing_none;
 use strum_macros::{AsRefStr, Display, EnumIter};
 
-use super::{CommonFields, LedgerObject};
+use super::{CommonFields, LedgerObject, LedgerObjectFlags};
 
 #[derive(
     Debug, Eq, PartialEq, Clone, Serialize_repr, Deserialize_repr, Display, AsRefStr, EnumIter,
@@ -79,6 +79,14 @@ impl<'a> LedgerObject<NFTokenOfferFlag> for NFTokenOffer<'a> {
     }
 }
 
+impl<'a> LedgerObjectFlags for NFTokenOffer<'a> {
+    type Flag = NFTokenOfferFlag;
+
+    fn get_flags(&self) -> &FlagCollection<NFTokenOfferFlag> {
+        &self.common_fields.flags
+    }
+}
+
 impl<'a> NFTokenOffer<'a> {
     pub fn new(
         flags: FlagCollection<NFTokenOfferFlag>,
@@ -9,7 +9,7 @@ use strum_macros::{AsRefStr, Display, EnumIter};
 
 use serde_with::skip_serializing_none;
 
-use super::{CommonFields, LedgerObject};
+use super::{CommonFields, LedgerObject, LedgerObjectFlags};
 
 #[derive(
     Debug, Eq, PartialEq, Clone, Serialize_repr, Deserialize_repr, Display, AsRefStr, EnumIter,
@@ -77,6 +77,14 @@ impl<'a> LedgerObject<OfferFlag> for Offer<'a> {
     }
 }
 
+impl<'a> LedgerObjectFlags for Offer<'a> {
+    type Flag = OfferFlag;
+
+    fn get_flags(&self) -> &FlagCollection<OfferFlag> {
+        &self.common_fields.flags
+    }
+}
+
 impl<'a> Offer<'a> {
     pub fn new(
         flags: FlagCollection<OfferFlag>,
@@ -150,4 +158,17 @@ mod tests {
 
         assert_eq!(offer, deserialized);
     }
+
+    #[test]
+    fn test_flags() {
+        assert_eq!(
+            Offer::decode_flags(0x00020000).unwrap(),
+            vec![(OfferFlag::LsfPassive, false), (OfferFlag::LsfSell, true)]
+        );
+        // Bits not defined for offers are ignored.
+        assert_eq!(
+            Offer::decode_flags(0x00010001).unwrap(),
+            vec![(OfferFlag::LsfPassive, true), (OfferFlag::LsfSell, false)]
+        );
+    }
 }
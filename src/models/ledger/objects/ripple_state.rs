@@ -9,7 +9,7 @@ use strum_macros::{AsRefStr, Display, EnumIter};
 
 use serde_with::skip_serializing_none;
 
-use super::{CommonFields, LedgerObject};
+use super::{CommonFields, LedgerObject, LedgerObjectFlags};
 
 #[derive(
     Debug, Eq, PartialEq, Clone, Serialize_repr, Deserialize_repr, Display, AsRefStr, EnumIter,
@@ -97,6 +97,14 @@ impl<'a> LedgerObject<RippleStateFlag> for RippleState<'a> {
     }
 }
 
+impl<'a> LedgerObjectFlags for RippleState<'a> {
+    type Flag = RippleStateFlag;
+
+    fn get_flags(&self) -> &FlagCollection<RippleStateFlag> {
+        &self.common_fields.flags
+    }
+}
+
 impl<'a> RippleState<'a> {
     pub fn new(
         flags: FlagCollection<RippleStateFlag>,
@@ -180,4 +188,70 @@ mod tests {
 
         assert_eq!(ripple_state, deserialized);
     }
+
+    #[test]
+    fn test_previous_txn_lgr_seq_range() {
+        let mut ripple_state: serde_json::Value = serde_json::from_str(
+            r#"{
+                "Balance": {"currency": "USD", "issuer": "rrrrrrrrrrrrrrrrrrrrBZbvji", "value": "-10"},
+                "Flags": 0,
+                "HighLimit": {"currency": "USD", "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn", "value": "110"},
+                "HighNode": "0000000000000000",
+                "LedgerEntryType": "RippleState",
+                "LowLimit": {"currency": "USD", "issuer": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW", "value": "0"},
+                "LowNode": "FFFFFFFFFFFFFFFF",
+                "PreviousTxnID": "E3FE6EA3D48F0C2B639448020EA4F03D4F4F8FFDB243A852A0F59177921B4879",
+                "PreviousTxnLgrSeq": 4294967295
+            }"#,
+        )
+        .unwrap();
+        let deserialized: RippleState = serde_json::from_value(ripple_state.clone()).unwrap();
+        assert_eq!(deserialized.previous_txn_lgr_seq, u32::MAX);
+        assert_eq!(deserialized.low_node, "FFFFFFFFFFFFFFFF");
+
+        // A `UInt32` field must not wrap around silently.
+        ripple_state["PreviousTxnLgrSeq"] = 4294967296u64.into();
+        assert!(serde_json::from_value::<RippleState>(ripple_state).is_err());
+    }
+
+    #[test]
+    fn test_flags() {
+        let ripple_state: RippleState = serde_json::from_str(
+            r#"{
+                "Balance": {
+                    "currency": "USD",
+                    "issuer": "rrrrrrrrrrrrrrrrrrrrBZbvji",
+                    "value": "-10"
+                },
+                "Flags": 3276800,
+                "HighLimit": {
+                    "currency": "USD",
+                    "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                    "value": "110"
+                },
+                "HighNode": "0000000000000000",
+                "LedgerEntryType": "RippleState",
+                "LowLimit": {
+                    "currency": "USD",
+                    "issuer": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+                    "value": "0"
+                },
+                "LowNode": "0000000000000000",
+                "PreviousTxnID": "E3FE6EA3D48F0C2B639448020EA4F03D4F4F8FFDB243A852A0F59177921B4879",
+                "PreviousTxnLgrSeq": 14090896,
+                "index": "9CA88CDEDFF9252B3DE183CE35B038F57282BC9503CDFA1923EF9A95DF0D6F7B"
+            }"#,
+        )
+        .unwrap();
+
+        // lsfHighReserve | lsfHighNoRipple | lsfLowNoRipple
+        assert!(ripple_state.is_flag_set(&RippleStateFlag::LsfHighReserve));
+        assert!(ripple_state.is_flag_set(&RippleStateFlag::LsfLowNoRipple));
+        assert!(ripple_state.is_flag_set(&RippleStateFlag::LsfHighNoRipple));
+        assert!(!ripple_state.is_flag_set(&RippleStateFlag::LsfLowReserve));
+        let flag_map = ripple_state.flag_map();
+        assert_eq!(flag_map.len(), 8);
+        assert_eq!(flag_map.iter().filter(|(_, is_set)| *is_set).count(), 3);
+        assert_eq!(RippleState::decode_flags(3276800).unwrap(), flag_map);
+    }
 }
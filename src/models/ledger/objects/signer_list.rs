@@ -12,7 +12,7 @@ use strum_macros::{AsRefStr, Display, EnumIter};
 use crate::serde_with_tag;
 use serde_with::skip_serializing_none;
 
-use super::{CommonFields, LedgerObject};
+use super::{CommonFields, LedgerObject, LedgerObjectFlags};
 
 #[derive(
     Debug, Eq, PartialEq, Clone, Serialize_repr, Deserialize_repr, Display, AsRefStr, EnumIter,
@@ -86,6 +86,14 @@ impl<'a> LedgerObject<SignerListFlag> for SignerList<'a> {
     }
 }
 
+impl<'a> LedgerObjectFlags for SignerList<'a> {
+    type Flag = SignerListFlag;
+
+    fn get_flags(&self) -> &FlagCollection<SignerListFlag> {
+        &self.common_fields.flags
+    }
+}
+
 impl<'a> SignerList<'a> {
     pub fn new(
         flags: FlagCollection<SignerListFlag>,
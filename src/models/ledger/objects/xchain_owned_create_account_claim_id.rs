@@ -2,7 +2,10 @@ use alloc::{borrow::Cow, vec::Vec};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::models::{NoFlags, XChainBridge};
+use crate::{
+    _serde::uint64_hex,
+    models::{NoFlags, XChainBridge},
+};
 
 use super::{CommonFields, XChainClaimProofSig};
 
@@ -14,6 +17,7 @@ pub struct XChainOwnedCreateAccountClaimID<'a> {
     pub common_fields: CommonFields<'a, NoFlags>,
     pub account: Cow<'a, str>,
     #[serde(rename = "XChainAccountCreateCount")]
+    #[serde(with = "uint64_hex")]
     pub xchain_account_create_count: u64,
     #[serde(rename = "XChainBridge")]
     pub xchain_bridge: XChainBridge<'a>,
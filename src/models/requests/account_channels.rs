@@ -75,6 +75,7 @@ impl<'a> AccountChannels<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::AccountChannels,
                 id,
+                api_version: None,
             },
             account,
             ledger_hash,
@@ -84,6 +85,11 @@ impl<'a> AccountChannels<'a> {
             marker,
         }
     }
+
+    /// Creates a request for `account`, leaving every other field unset.
+    pub fn for_account(account: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(None, account.into(), None, None, None, None, None)
+    }
 }
 
 impl<'a> Request<'a> for AccountChannels<'a> {
@@ -59,6 +59,7 @@ impl<'a> AccountCurrencies<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::AccountCurrencies,
                 id,
+                api_version: None,
             },
             account,
             ledger_hash,
@@ -66,4 +67,9 @@ impl<'a> AccountCurrencies<'a> {
             strict,
         }
     }
+
+    /// Creates a request for `account`, leaving every other field unset.
+    pub fn for_account(account: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(None, account.into(), None, None, None)
+    }
 }
@@ -68,6 +68,7 @@ impl<'a> AccountInfo<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::AccountInfo,
                 id,
+                api_version: None,
             },
             account,
             ledger_hash,
@@ -77,4 +78,54 @@ impl<'a> AccountInfo<'a> {
             signer_lists,
         }
     }
+
+    /// Creates a request for `account`, leaving every other field unset.
+    pub fn for_account(account: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            account: account.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the request `id`, which the response echoes.
+    pub fn with_id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.common_fields.id = Some(id.into());
+        self
+    }
+
+    /// Sets `ledger_hash`.
+    pub fn with_ledger_hash(mut self, ledger_hash: impl Into<Cow<'a, str>>) -> Self {
+        self.ledger_hash = Some(ledger_hash.into());
+        self
+    }
+
+    /// Sets `ledger_index`.
+    pub fn with_ledger_index(mut self, ledger_index: impl Into<Cow<'a, str>>) -> Self {
+        self.ledger_index = Some(ledger_index.into());
+        self
+    }
+
+    /// Sets `strict`.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = Some(strict);
+        self
+    }
+
+    /// Sets `queue`.
+    pub fn with_queue(mut self, queue: bool) -> Self {
+        self.queue = Some(queue);
+        self
+    }
+
+    /// Sets `signer_lists`.
+    pub fn with_signer_lists(mut self, signer_lists: bool) -> Self {
+        self.signer_lists = Some(signer_lists);
+        self
+    }
+}
+
+impl<'a> Default for AccountInfo<'a> {
+    fn default() -> Self {
+        Self::new(None, "".into(), None, None, None, None, None)
+    }
 }
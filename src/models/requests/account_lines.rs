@@ -61,6 +61,7 @@ impl<'a> AccountLines<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::AccountLines,
                 id,
+                api_version: None,
             },
             account,
             ledger_hash,
@@ -69,4 +70,48 @@ impl<'a> AccountLines<'a> {
             peer,
         }
     }
+
+    /// Creates a request for `account`, leaving every other field unset.
+    pub fn for_account(account: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            account: account.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the request `id`, which the response echoes.
+    pub fn with_id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.common_fields.id = Some(id.into());
+        self
+    }
+
+    /// Sets `ledger_hash`.
+    pub fn with_ledger_hash(mut self, ledger_hash: impl Into<Cow<'a, str>>) -> Self {
+        self.ledger_hash = Some(ledger_hash.into());
+        self
+    }
+
+    /// Sets `ledger_index`.
+    pub fn with_ledger_index(mut self, ledger_index: impl Into<Cow<'a, str>>) -> Self {
+        self.ledger_index = Some(ledger_index.into());
+        self
+    }
+
+    /// Sets `limit`.
+    pub fn with_limit(mut self, limit: u16) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets `peer`.
+    pub fn with_peer(mut self, peer: impl Into<Cow<'a, str>>) -> Self {
+        self.peer = Some(peer.into());
+        self
+    }
+}
+
+impl<'a> Default for AccountLines<'a> {
+    fn default() -> Self {
+        Self::new(None, "".into(), None, None, None, None)
+    }
 }
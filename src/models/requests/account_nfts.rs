@@ -50,10 +50,16 @@ impl<'a> AccountNfts<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::AccountNfts,
                 id,
+                api_version: None,
             },
             account,
             limit,
             marker,
         }
     }
+
+    /// Creates a request for `account`, leaving every other field unset.
+    pub fn for_account(account: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(None, account.into(), None, None)
+    }
 }
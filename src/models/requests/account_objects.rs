@@ -12,7 +12,6 @@ use super::{CommonFields, Request};
 #[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Display)]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
-#[serde(tag = "type")]
 pub enum AccountObjectType {
     Check,
     DepositPreauth,
@@ -88,6 +87,7 @@ impl<'a> AccountObjects<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::AccountObjects,
                 id,
+                api_version: None,
             },
             account,
             ledger_hash,
@@ -98,4 +98,60 @@ impl<'a> AccountObjects<'a> {
             marker,
         }
     }
+
+    /// Creates a request for `account`, leaving every other field unset.
+    pub fn for_account(account: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            account: account.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the request `id`, which the response echoes.
+    pub fn with_id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.common_fields.id = Some(id.into());
+        self
+    }
+
+    /// Sets `ledger_hash`.
+    pub fn with_ledger_hash(mut self, ledger_hash: impl Into<Cow<'a, str>>) -> Self {
+        self.ledger_hash = Some(ledger_hash.into());
+        self
+    }
+
+    /// Sets `ledger_index`.
+    pub fn with_ledger_index(mut self, ledger_index: impl Into<Cow<'a, str>>) -> Self {
+        self.ledger_index = Some(ledger_index.into());
+        self
+    }
+
+    /// Sets `type`.
+    pub fn with_type(mut self, object_type: AccountObjectType) -> Self {
+        self.r#type = Some(object_type);
+        self
+    }
+
+    /// Sets `deletion_blockers_only`.
+    pub fn with_deletion_blockers_only(mut self, deletion_blockers_only: bool) -> Self {
+        self.deletion_blockers_only = Some(deletion_blockers_only);
+        self
+    }
+
+    /// Sets `limit`.
+    pub fn with_limit(mut self, limit: u16) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets `marker`.
+    pub fn with_marker(mut self, marker: u32) -> Self {
+        self.marker = Some(marker);
+        self
+    }
+}
+
+impl<'a> Default for AccountObjects<'a> {
+    fn default() -> Self {
+        Self::new(None, "".into(), None, None, None, None, None, None)
+    }
 }
@@ -64,6 +64,7 @@ impl<'a> AccountOffers<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::AccountOffers,
                 id,
+                api_version: None,
             },
             account,
             ledger_hash,
@@ -73,4 +74,9 @@ impl<'a> AccountOffers<'a> {
             marker,
         }
     }
+
+    /// Creates a request for `account`, leaving every other field unset.
+    pub fn for_account(account: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(None, account.into(), None, None, None, None, None)
+    }
 }
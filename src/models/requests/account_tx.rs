@@ -1,5 +1,6 @@
 use alloc::borrow::Cow;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use serde_with::skip_serializing_none;
 
 use crate::models::{requests::RequestMethod, Model};
@@ -47,8 +48,9 @@ pub struct AccountTx<'a> {
     /// Value from a previous paginated response. Resume retrieving
     /// data where that response left off. This value is stable even
     /// if there is a change in the server's range of available
-    /// ledgers.
-    pub marker: Option<u32>,
+    /// ledgers. Only valid with the same `ledger_index_min`,
+    /// `ledger_index_max` and `forward` as that request.
+    pub marker: Option<Value>,
 }
 
 impl<'a> Model for AccountTx<'a> {}
@@ -74,12 +76,13 @@ impl<'a> AccountTx<'a> {
         ledger_index_min: Option<u32>,
         ledger_index_max: Option<u32>,
         limit: Option<u16>,
-        marker: Option<u32>,
+        marker: Option<Value>,
     ) -> Self {
         Self {
             common_fields: CommonFields {
                 command: RequestMethod::AccountTx,
                 id,
+                api_version: None,
             },
             account,
             ledger_hash,
@@ -92,4 +95,83 @@ impl<'a> AccountTx<'a> {
             marker,
         }
     }
+
+    /// Creates a request for `account`, leaving every other field unset.
+    pub fn for_account(account: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            account: account.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the request `id`, which the response echoes.
+    pub fn with_id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.common_fields.id = Some(id.into());
+        self
+    }
+
+    /// Sets `ledger_hash`.
+    pub fn with_ledger_hash(mut self, ledger_hash: impl Into<Cow<'a, str>>) -> Self {
+        self.ledger_hash = Some(ledger_hash.into());
+        self
+    }
+
+    /// Sets `ledger_index`.
+    pub fn with_ledger_index(mut self, ledger_index: impl Into<Cow<'a, str>>) -> Self {
+        self.ledger_index = Some(ledger_index.into());
+        self
+    }
+
+    /// Sets `binary`.
+    pub fn with_binary(mut self, binary: bool) -> Self {
+        self.binary = Some(binary);
+        self
+    }
+
+    /// Sets `forward`.
+    pub fn with_forward(mut self, forward: bool) -> Self {
+        self.forward = Some(forward);
+        self
+    }
+
+    /// Sets `ledger_index_min`.
+    pub fn with_ledger_index_min(mut self, ledger_index_min: u32) -> Self {
+        self.ledger_index_min = Some(ledger_index_min);
+        self
+    }
+
+    /// Sets `ledger_index_max`.
+    pub fn with_ledger_index_max(mut self, ledger_index_max: u32) -> Self {
+        self.ledger_index_max = Some(ledger_index_max);
+        self
+    }
+
+    /// Sets `limit`.
+    pub fn with_limit(mut self, limit: u16) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets `marker`.
+    pub fn with_marker(mut self, marker: Value) -> Self {
+        self.marker = Some(marker);
+        self
+    }
+}
+
+impl<'a> Default for AccountTx<'a> {
+    fn default() -> Self {
+        Self::new(
+            None,
+            "".into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
 }
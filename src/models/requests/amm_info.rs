@@ -15,6 +15,11 @@ pub struct AMMInfo<'a> {
     pub amm_account: Option<Cow<'a, str>>,
     pub asset: Option<Currency<'a>>,
     pub asset2: Option<Currency<'a>>,
+    /// A 20-byte hex string for the ledger version to use.
+    pub ledger_hash: Option<Cow<'a, str>>,
+    /// The ledger index of the ledger to use, or a shortcut
+    /// string to choose a ledger automatically.
+    pub ledger_index: Option<Cow<'a, str>>,
 }
 
 impl Model for AMMInfo<'_> {}
@@ -35,15 +40,20 @@ impl<'a> AMMInfo<'a> {
         amm_account: Option<Cow<'a, str>>,
         asset: Option<Currency<'a>>,
         asset2: Option<Currency<'a>>,
+        ledger_hash: Option<Cow<'a, str>>,
+        ledger_index: Option<Cow<'a, str>>,
     ) -> Self {
         Self {
             common_fields: CommonFields {
                 command: super::RequestMethod::AMMInfo,
                 id,
+                api_version: None,
             },
             amm_account,
             asset,
             asset2,
+            ledger_hash,
+            ledger_index,
         }
     }
 }
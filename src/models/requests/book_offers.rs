@@ -70,6 +70,7 @@ impl<'a> BookOffers<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::BookOffers,
                 id,
+                api_version: None,
             },
             taker_gets,
             taker_pays,
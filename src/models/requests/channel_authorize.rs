@@ -126,6 +126,7 @@ impl<'a> ChannelAuthorize<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::ChannelAuthorize,
                 id,
+                api_version: None,
             },
             channel_id,
             amount,
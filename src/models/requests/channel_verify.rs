@@ -52,6 +52,7 @@ impl<'a> ChannelVerify<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::ChannelVerify,
                 id,
+                api_version: None,
             },
             channel_id,
             amount,
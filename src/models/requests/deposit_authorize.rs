@@ -52,6 +52,7 @@ impl<'a> DepositAuthorized<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::DepositAuthorized,
                 id,
+                api_version: None,
             },
             source_account,
             destination_account,
@@ -39,6 +39,7 @@ impl<'a> Fee<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::Fee,
                 id,
+                api_version: None,
             },
         }
     }
@@ -59,6 +59,7 @@ impl<'a> GatewayBalances<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::GatewayBalances,
                 id,
+                api_version: None,
             },
             account,
             strict,
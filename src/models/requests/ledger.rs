@@ -85,6 +85,7 @@ impl<'a> Ledger<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::Ledger,
                 id,
+                api_version: None,
             },
             ledger_hash,
             ledger_index,
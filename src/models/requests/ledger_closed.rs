@@ -38,6 +38,7 @@ impl<'a> LedgerClosed<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::LedgerClosed,
                 id,
+                api_version: None,
             },
         }
     }
@@ -38,6 +38,7 @@ impl<'a> LedgerCurrent<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::LedgerCurrent,
                 id,
+                api_version: None,
             },
         }
     }
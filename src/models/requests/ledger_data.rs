@@ -59,6 +59,7 @@ impl<'a> LedgerData<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::LedgerData,
                 id,
+                api_version: None,
             },
             ledger_hash,
             ledger_index,
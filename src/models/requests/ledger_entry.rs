@@ -1,4 +1,6 @@
 use alloc::borrow::Cow;
+use alloc::vec;
+use alloc::vec::Vec;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -52,7 +54,8 @@ pub struct Ticket<'a> {
 /// Required fields for requesting a RippleState.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, new)]
 pub struct RippleState<'a> {
-    pub account: Cow<'a, str>,
+    /// The two accounts linked by the trust line.
+    pub accounts: Vec<Cow<'a, str>>,
     pub currency: Cow<'a, str>,
 }
 
@@ -183,6 +186,7 @@ impl<'a> LedgerEntry<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::LedgerEntry,
                 id,
+                api_version: None,
             },
             index,
             account_root,
@@ -201,6 +205,76 @@ impl<'a> LedgerEntry<'a> {
     }
 }
 
+impl<'a> LedgerEntry<'a> {
+    fn from_selector(selector: impl FnOnce(&mut Self)) -> Self {
+        let mut ledger_entry = Self::new(
+            None, None, None, None, None, None, None, None, None, None, None, None, None, None,
+        );
+        selector(&mut ledger_entry);
+
+        ledger_entry
+    }
+
+    /// Request an object by its ledger object ID.
+    pub fn index(index: impl Into<Cow<'a, str>>) -> Self {
+        Self::from_selector(|req| req.index = Some(index.into()))
+    }
+
+    /// Request the `AccountRoot` of an account.
+    pub fn account_root(address: impl Into<Cow<'a, str>>) -> Self {
+        Self::from_selector(|req| req.account_root = Some(address.into()))
+    }
+
+    /// Request a `Check` by its object ID.
+    pub fn check(check_id: impl Into<Cow<'a, str>>) -> Self {
+        Self::from_selector(|req| req.check = Some(check_id.into()))
+    }
+
+    /// Request a `DepositPreauth` granted by `owner` to `authorized`.
+    pub fn deposit_preauth(
+        owner: impl Into<Cow<'a, str>>,
+        authorized: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self::from_selector(|req| {
+            req.deposit_preauth = Some(DepositPreauth::new(authorized.into(), owner.into()))
+        })
+    }
+
+    /// Request an `Escrow` created by `owner` with the given sequence.
+    pub fn escrow(owner: impl Into<Cow<'a, str>>, seq: u64) -> Self {
+        Self::from_selector(|req| req.escrow = Some(Escrow::new(owner.into(), seq)))
+    }
+
+    /// Request an `Offer` created by `account` with the given sequence.
+    pub fn offer(account: impl Into<Cow<'a, str>>, seq: u64) -> Self {
+        Self::from_selector(|req| req.offer = Some(Offer::new(account.into(), seq)))
+    }
+
+    /// Request a `PayChannel` by its channel ID.
+    pub fn payment_channel(channel_id: impl Into<Cow<'a, str>>) -> Self {
+        Self::from_selector(|req| req.payment_channel = Some(channel_id.into()))
+    }
+
+    /// Request the `RippleState` (trust line) between two accounts.
+    pub fn ripple_state(
+        account1: impl Into<Cow<'a, str>>,
+        account2: impl Into<Cow<'a, str>>,
+        currency: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self::from_selector(|req| {
+            req.ripple_state = Some(RippleState::new(
+                vec![account1.into(), account2.into()],
+                currency.into(),
+            ))
+        })
+    }
+
+    /// Request a `Ticket` owned by `owner` with the given ticket sequence.
+    pub fn ticket(owner: impl Into<Cow<'a, str>>, ticket_sequence: u64) -> Self {
+        Self::from_selector(|req| req.ticket = Some(Ticket::new(owner.into(), ticket_sequence)))
+    }
+}
+
 pub trait LedgerEntryError {
     #[allow(clippy::result_large_err)]
     fn _get_field_error(&self) -> XRPLModelResult<()>;
@@ -280,4 +354,42 @@ mod test_ledger_entry_errors {
 
         assert_eq!(req, deserialized);
     }
+
+    #[test]
+    fn test_selector_constructors() {
+        let account = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+        let cases = [
+            (LedgerEntry::account_root(account), "account_root"),
+            (LedgerEntry::offer(account, 359), "offer"),
+            (
+                LedgerEntry::ripple_state(account, "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW", "USD"),
+                "ripple_state",
+            ),
+            (LedgerEntry::escrow(account, 7), "escrow"),
+            (LedgerEntry::ticket(account, 389), "ticket"),
+        ];
+        for (req, selector) in cases {
+            assert!(req.validate().is_ok());
+            let value = serde_json::to_value(&req).unwrap();
+            let object = value.as_object().unwrap();
+            assert_eq!(object["command"], "ledger_entry");
+            assert!(object.contains_key(selector));
+            assert_eq!(object.len(), 2);
+        }
+
+        let value = serde_json::to_value(LedgerEntry::offer(account, 359)).unwrap();
+        assert_eq!(value["offer"]["account"], account);
+        assert_eq!(value["offer"]["seq"], 359);
+        let value = serde_json::to_value(LedgerEntry::ripple_state(
+            account,
+            "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+            "USD",
+        ))
+        .unwrap();
+        assert_eq!(
+            value["ripple_state"]["accounts"],
+            serde_json::json!([account, "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW"])
+        );
+        assert_eq!(value["ripple_state"]["currency"], "USD");
+    }
 }
@@ -43,6 +43,7 @@ impl<'a> Manifest<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::Manifest,
                 id,
+                api_version: None,
             },
             public_key,
         }
@@ -28,6 +28,7 @@ pub mod random;
 pub mod ripple_path_find;
 pub mod server_info;
 pub mod server_state;
+pub mod simulate;
 pub mod submit;
 pub mod submit_multisigned;
 pub mod subscribe;
@@ -64,6 +65,7 @@ pub enum RequestMethod {
     // Transaction methods
     Sign,
     SignFor,
+    Simulate,
     Submit,
     SubmitMultisigned,
     TransactionEntry,
@@ -117,6 +119,7 @@ pub enum XRPLRequest<'a> {
     AMMInfo(amm_info::AMMInfo<'a>),
     GatewayBalances(gateway_balances::GatewayBalances<'a>),
     NoRippleCheck(no_ripple_check::NoRippleCheck<'a>),
+    Simulate(simulate::Simulate<'a>),
     Submit(submit::Submit<'a>),
     SubmitMultisigned(submit_multisigned::SubmitMultisigned<'a>),
     TransactionEntry(transaction_entry::TransactionEntry<'a>),
@@ -210,6 +213,12 @@ impl<'a> From<no_ripple_check::NoRippleCheck<'a>> for XRPLRequest<'a> {
     }
 }
 
+impl<'a> From<simulate::Simulate<'a>> for XRPLRequest<'a> {
+    fn from(request: simulate::Simulate<'a>) -> Self {
+        XRPLRequest::Simulate(request)
+    }
+}
+
 impl<'a> From<submit::Submit<'a>> for XRPLRequest<'a> {
     fn from(request: submit::Submit<'a>) -> Self {
         XRPLRequest::Submit(request)
@@ -374,6 +383,7 @@ impl<'a> Request<'a> for XRPLRequest<'a> {
             XRPLRequest::AMMInfo(request) => request.get_common_fields(),
             XRPLRequest::GatewayBalances(request) => request.get_common_fields(),
             XRPLRequest::NoRippleCheck(request) => request.get_common_fields(),
+            XRPLRequest::Simulate(request) => request.get_common_fields(),
             XRPLRequest::Submit(request) => request.get_common_fields(),
             XRPLRequest::SubmitMultisigned(request) => request.get_common_fields(),
             XRPLRequest::TransactionEntry(request) => request.get_common_fields(),
@@ -415,6 +425,7 @@ impl<'a> Request<'a> for XRPLRequest<'a> {
             XRPLRequest::AMMInfo(request) => request.get_common_fields_mut(),
             XRPLRequest::GatewayBalances(request) => request.get_common_fields_mut(),
             XRPLRequest::NoRippleCheck(request) => request.get_common_fields_mut(),
+            XRPLRequest::Simulate(request) => request.get_common_fields_mut(),
             XRPLRequest::Submit(request) => request.get_common_fields_mut(),
             XRPLRequest::SubmitMultisigned(request) => request.get_common_fields_mut(),
             XRPLRequest::TransactionEntry(request) => request.get_common_fields_mut(),
@@ -452,6 +463,11 @@ pub struct CommonFields<'a> {
     pub command: RequestMethod,
     /// The unique request id.
     pub id: Option<Cow<'a, str>>,
+    /// The API version the server should answer with, which decides
+    /// the shape of some responses. The server's default is used if
+    /// unset.
+    #[new(default)]
+    pub api_version: Option<u8>,
 }
 
 /// The base trait for all request models.
@@ -459,6 +475,15 @@ pub struct CommonFields<'a> {
 pub trait Request<'a> {
     fn get_common_fields(&self) -> &CommonFields<'a>;
     fn get_common_fields_mut(&mut self) -> &mut CommonFields<'a>;
+
+    /// Sets the API version the server should answer this request with.
+    fn with_api_version(mut self, api_version: u8) -> Self
+    where
+        Self: Sized,
+    {
+        self.get_common_fields_mut().api_version = Some(api_version);
+        self
+    }
 }
 
 #[skip_serializing_none]
@@ -469,3 +494,203 @@ pub struct FundFaucet<'a> {
     pub usage_context: Option<Cow<'a, str>>,
     pub user_agent: Option<Cow<'a, str>>,
 }
+
+#[cfg(test)]
+mod test_builders {
+    use serde_json::json;
+
+    use super::{
+        account_info::AccountInfo,
+        account_lines::AccountLines,
+        account_objects::{AccountObjectType, AccountObjects},
+        account_tx::AccountTx,
+    };
+
+    const ACCOUNT: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+
+    #[test]
+    fn test_account_info() {
+        let positional = AccountInfo::new(
+            Some("1".into()),
+            ACCOUNT.into(),
+            None,
+            Some("validated".into()),
+            None,
+            None,
+            Some(true),
+        );
+        let built = AccountInfo {
+            account: ACCOUNT.into(),
+            ..Default::default()
+        }
+        .with_id("1")
+        .with_ledger_index("validated")
+        .with_signer_lists(true);
+
+        assert_eq!(built, positional);
+        assert_eq!(
+            serde_json::to_value(&built).unwrap(),
+            serde_json::to_value(&positional).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_account_lines() {
+        let positional = AccountLines::new(
+            None,
+            ACCOUNT.into(),
+            None,
+            None,
+            Some(10),
+            Some("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".into()),
+        );
+        let built = AccountLines {
+            account: ACCOUNT.into(),
+            ..Default::default()
+        }
+        .with_limit(10)
+        .with_peer("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW");
+
+        assert_eq!(
+            serde_json::to_value(&built).unwrap(),
+            serde_json::to_value(&positional).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_account_objects() {
+        let positional = AccountObjects::new(
+            None,
+            ACCOUNT.into(),
+            None,
+            Some("validated".into()),
+            Some(AccountObjectType::State),
+            Some(true),
+            None,
+            None,
+        );
+        let built = AccountObjects {
+            account: ACCOUNT.into(),
+            ..Default::default()
+        }
+        .with_ledger_index("validated")
+        .with_type(AccountObjectType::State)
+        .with_deletion_blockers_only(true);
+
+        assert_eq!(
+            serde_json::to_value(&built).unwrap(),
+            serde_json::to_value(&positional).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_account_tx() {
+        let marker = json!({"ledger": 12345, "seq": 3});
+        let positional = AccountTx::new(
+            None,
+            ACCOUNT.into(),
+            None,
+            None,
+            None,
+            Some(true),
+            Some(100),
+            None,
+            Some(20),
+            Some(marker.clone()),
+        );
+        let built = AccountTx {
+            account: ACCOUNT.into(),
+            ..Default::default()
+        }
+        .with_forward(true)
+        .with_ledger_index_min(100)
+        .with_limit(20)
+        .with_marker(marker);
+
+        assert_eq!(
+            serde_json::to_value(&built).unwrap(),
+            serde_json::to_value(&positional).unwrap()
+        );
+        assert_eq!(
+            serde_json::to_value(&built).unwrap()["command"],
+            "account_tx"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_for_account {
+    use alloc::string::String;
+    use serde_json::{json, Value};
+
+    use super::{
+        account_channels::AccountChannels, account_currencies::AccountCurrencies,
+        account_info::AccountInfo, account_lines::AccountLines, account_nfts::AccountNfts,
+        account_objects::AccountObjects, account_offers::AccountOffers, account_tx::AccountTx,
+    };
+
+    const ACCOUNT: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+
+    fn request(command: &str) -> Value {
+        json!({ "command": command, "account": ACCOUNT })
+    }
+
+    #[test]
+    fn test_for_account() {
+        let cases = [
+            (
+                serde_json::to_value(AccountChannels::for_account(ACCOUNT)),
+                "account_channels",
+            ),
+            (
+                serde_json::to_value(AccountCurrencies::for_account(ACCOUNT)),
+                "account_currencies",
+            ),
+            (
+                serde_json::to_value(AccountInfo::for_account(ACCOUNT)),
+                "account_info",
+            ),
+            (
+                serde_json::to_value(AccountLines::for_account(ACCOUNT)),
+                "account_lines",
+            ),
+            (
+                serde_json::to_value(AccountNfts::for_account(ACCOUNT)),
+                "account_nfts",
+            ),
+            (
+                serde_json::to_value(AccountObjects::for_account(ACCOUNT)),
+                "account_objects",
+            ),
+            (
+                serde_json::to_value(AccountOffers::for_account(ACCOUNT)),
+                "account_offers",
+            ),
+            (
+                serde_json::to_value(AccountTx::for_account(ACCOUNT)),
+                "account_tx",
+            ),
+        ];
+        for (value, command) in cases {
+            assert_eq!(value.unwrap(), request(command));
+        }
+    }
+
+    #[test]
+    fn test_for_owned_account() {
+        let account = String::from(ACCOUNT);
+
+        assert_eq!(
+            AccountInfo::for_account(account).with_ledger_index("validated"),
+            AccountInfo::new(
+                None,
+                ACCOUNT.into(),
+                None,
+                Some("validated".into()),
+                None,
+                None,
+                None
+            )
+        );
+    }
+}
@@ -54,6 +54,7 @@ impl<'a> NftBuyOffers<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::NftBuyOffers,
                 id,
+                api_version: None,
             },
             nft_id,
             ledger_hash,
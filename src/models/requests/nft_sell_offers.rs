@@ -35,6 +35,7 @@ impl<'a> NftSellOffers<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::NftSellOffers,
                 id,
+                api_version: None,
             },
             nft_id,
         }
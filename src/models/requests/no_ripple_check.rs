@@ -82,6 +82,7 @@ impl<'a> NoRippleCheck<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::NoRippleCheck,
                 id,
+                api_version: None,
             },
             account,
             role,
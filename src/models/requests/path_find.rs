@@ -114,6 +114,7 @@ impl<'a> PathFind<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::PathFind,
                 id,
+                api_version: None,
             },
             subcommand,
             source_account,
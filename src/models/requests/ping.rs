@@ -37,6 +37,7 @@ impl<'a> Ping<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::Ping,
                 id,
+                api_version: None,
             },
         }
     }
@@ -38,6 +38,7 @@ impl<'a> Random<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::Random,
                 id,
+                api_version: None,
             },
         }
     }
@@ -87,6 +87,7 @@ impl<'a> RipplePathFind<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::RipplePathFind,
                 id,
+                api_version: None,
             },
             destination_account,
             destination_amount,
@@ -38,6 +38,7 @@ impl<'a> ServerInfo<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::ServerInfo,
                 id,
+                api_version: None,
             },
         }
     }
@@ -43,6 +43,7 @@ impl<'a> ServerState<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::ServerState,
                 id,
+                api_version: None,
             },
         }
     }
@@ -0,0 +1,118 @@
+use alloc::borrow::Cow;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::{requests::RequestMethod, Model, XRPLModelResult};
+
+use super::{CommonFields, Request};
+
+/// The simulate method executes a dry run of any transaction type,
+/// returning the results and metadata it would have without
+/// submitting it to the network. The transaction must not be signed.
+///
+/// See Simulate:
+/// `<https://xrpl.org/docs/references/http-websocket-apis/public-api-methods/transaction-methods/simulate>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct Simulate<'a> {
+    /// The common fields shared by all requests.
+    #[serde(flatten)]
+    pub common_fields: CommonFields<'a>,
+    /// The transaction to simulate, in JSON format. Either this or
+    /// `tx_blob` must be provided.
+    pub tx_json: Option<Value>,
+    /// The transaction to simulate, in binary format. Either this or
+    /// `tx_json` must be provided.
+    pub tx_blob: Option<Cow<'a, str>>,
+    /// If true, return the metadata in binary format instead
+    /// of JSON. The default is false.
+    pub binary: Option<bool>,
+}
+
+impl<'a> Model for Simulate<'a> {}
+
+impl<'a> Request<'a> for Simulate<'a> {
+    fn get_common_fields(&self) -> &CommonFields<'a> {
+        &self.common_fields
+    }
+
+    fn get_common_fields_mut(&mut self) -> &mut CommonFields<'a> {
+        &mut self.common_fields
+    }
+}
+
+impl<'a> Simulate<'a> {
+    pub fn new(
+        id: Option<Cow<'a, str>>,
+        tx_json: Option<Value>,
+        tx_blob: Option<Cow<'a, str>>,
+        binary: Option<bool>,
+    ) -> Self {
+        Self {
+            common_fields: CommonFields {
+                command: RequestMethod::Simulate,
+                id,
+                api_version: None,
+            },
+            tx_json,
+            tx_blob,
+            binary,
+        }
+    }
+
+    /// Build the request from an unsigned transaction.
+    pub fn from_transaction<T: Serialize>(
+        id: Option<Cow<'a, str>>,
+        transaction: &T,
+        binary: Option<bool>,
+    ) -> XRPLModelResult<Self> {
+        Ok(Self::new(
+            id,
+            Some(serde_json::to_value(transaction)?),
+            None,
+            binary,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::transactions::account_set::AccountSet;
+
+    #[test]
+    fn test_from_transaction() {
+        let tx = AccountSet::new(
+            "rEuLyBCvcw4CFmzv8RepSiAoNgF8tTGJQC".into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some("6578616d706c652e636f6d".into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let request = Simulate::from_transaction(None, &tx, None).unwrap();
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["command"], "simulate");
+        assert_eq!(json["tx_json"]["TransactionType"], "AccountSet");
+        assert_eq!(
+            json["tx_json"]["Account"],
+            "rEuLyBCvcw4CFmzv8RepSiAoNgF8tTGJQC"
+        );
+        assert!(json.get("tx_blob").is_none());
+        assert!(json.get("binary").is_none());
+    }
+}
@@ -65,6 +65,7 @@ impl<'a> Submit<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::Submit,
                 id,
+                api_version: None,
             },
             tx_blob,
             fail_hard,
@@ -1,19 +1,17 @@
 use alloc::borrow::Cow;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use serde_with::skip_serializing_none;
 
-use crate::models::{requests::RequestMethod, Model};
+use crate::models::{requests::RequestMethod, Model, XRPLModelResult};
 
 use super::{CommonFields, Request};
 
-/// The server_state command asks the server for various
-/// machine-readable information about the rippled server's
-/// current state. The response is almost the same as the
-/// server_info method, but uses units that are easier to
-/// process instead of easier to read. (For example, XRP
-/// values are given in integer drops instead of scientific
-/// notation or decimal values, and time is given in
-/// milliseconds instead of seconds.)
+/// The submit_multisigned command applies a multi-signed
+/// transaction and sends it to the network to be included
+/// in future ledgers. (You can also submit multi-signed
+/// transactions in binary form using the submit command
+/// in submit-only mode.)
 ///
 /// See Submit Multisigned:
 /// `<https://xrpl.org/submit_multisigned.html>`
@@ -23,6 +21,10 @@ pub struct SubmitMultisigned<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
     pub common_fields: CommonFields<'a>,
+    /// Transaction in JSON format with an array of Signers.
+    /// To be successful, the weights of the signatures must
+    /// be equal or higher than the quorum of the SignerList.
+    pub tx_json: Value,
     /// If true, and the transaction fails locally, do not
     /// retry or relay the transaction to other servers.
     pub fail_hard: Option<bool>,
@@ -41,13 +43,80 @@ impl<'a> Request<'a> for SubmitMultisigned<'a> {
 }
 
 impl<'a> SubmitMultisigned<'a> {
-    pub fn new(id: Option<Cow<'a, str>>, fail_hard: Option<bool>) -> Self {
+    pub fn new(id: Option<Cow<'a, str>>, tx_json: Value, fail_hard: Option<bool>) -> Self {
         Self {
             common_fields: CommonFields {
                 command: RequestMethod::SubmitMultisigned,
                 id,
+                api_version: None,
             },
+            tx_json,
             fail_hard,
         }
     }
+
+    /// Build the request from a transaction whose `Signers`
+    /// have already been combined (see `transaction::multisign`).
+    pub fn from_transaction<T: Serialize>(
+        id: Option<Cow<'a, str>>,
+        transaction: &T,
+        fail_hard: Option<bool>,
+    ) -> XRPLModelResult<Self> {
+        Ok(Self::new(id, serde_json::to_value(transaction)?, fail_hard))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec;
+
+    use super::*;
+    use crate::models::transactions::{account_set::AccountSet, Signer};
+
+    #[test]
+    fn test_from_transaction() {
+        let mut tx = AccountSet::new(
+            "rEuLyBCvcw4CFmzv8RepSiAoNgF8tTGJQC".into(),
+            None,
+            Some("30000".into()),
+            None,
+            None,
+            None,
+            Some(1),
+            None,
+            None,
+            None,
+            None,
+            Some("6578616d706c652e636f6d".into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        tx.common_fields.signing_pub_key = Some("".into());
+        tx.common_fields.signers = Some(vec![
+            Signer::new(
+                "rJ8KhCi67VgbapiKCQN3r1ZA6BMUxUvvnD".into(),
+                "3045022100AB".into(),
+                "02B3EC4E5DD96029A647CFA20DA07FE1F85296505552CCAC114087E66B46BD77DF".into(),
+            ),
+            Signer::new(
+                "rPcNzota6B8YBokhYtcTNqQVCngtbnWfux".into(),
+                "3045022100CD".into(),
+                "028FFB276505F9AC3F57E8D5242B386A597EF6C40A7999F37F1948636FD484E25B".into(),
+            ),
+        ]);
+        let request = SubmitMultisigned::from_transaction(None, &tx, Some(true)).unwrap();
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["command"], "submit_multisigned");
+        assert_eq!(json["fail_hard"], true);
+        assert_eq!(json["tx_json"]["TransactionType"], "AccountSet");
+        assert_eq!(json["tx_json"]["SigningPubKey"], "");
+        let signers = json["tx_json"]["Signers"].as_array().unwrap();
+        assert_eq!(signers.len(), 2);
+        assert_eq!(signers[0]["Account"], "rJ8KhCi67VgbapiKCQN3r1ZA6BMUxUvvnD");
+    }
 }
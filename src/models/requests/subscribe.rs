@@ -102,6 +102,7 @@ impl<'a> Subscribe<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::Subscribe,
                 id,
+                api_version: None,
             },
             books,
             streams,
@@ -52,6 +52,7 @@ impl<'a> TransactionEntry<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::TransactionEntry,
                 id,
+                api_version: None,
             },
             tx_hash,
             ledger_hash,
@@ -58,6 +58,7 @@ impl<'a> Tx<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::Tx,
                 id,
+                api_version: None,
             },
             binary,
             min_ledger,
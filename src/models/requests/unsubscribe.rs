@@ -85,6 +85,7 @@ impl<'a> Unsubscribe<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::Unsubscribe,
                 id,
+                api_version: None,
             },
             books,
             streams,
@@ -1,15 +1,72 @@
 use core::convert::TryFrom;
 
-use alloc::string::ToString;
+use alloc::{string::ToString, vec::Vec};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-use crate::models::{ledger::objects::AccountRoot, XRPLModelException, XRPLModelResult};
+use crate::models::{
+    ledger::objects::{signer_list::SignerList, AccountRoot},
+    XRPLModelException, XRPLModelResult,
+};
 
 use super::{exceptions::XRPLResultException, XRPLResult};
 
+/// The result of an `account_info` request.
+///
+/// The result is normalized across API versions, so the same struct is
+/// returned whichever `api_version` the request used:
+///
+/// * API v1 returns the `signer_lists` requested with `signer_lists`
+///   inside `account_data`.
+/// * API v2 returns them next to `account_data`, at the top level of
+///   the result.
+///
+/// Both are read into [`AccountInfo::signer_lists`], and the result is
+/// serialized in the v2 shape.
+///
+/// See Account Info:
+/// `<https://xrpl.org/account_info.html#response-format>`
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "RawAccountInfo")]
 pub struct AccountInfo<'a> {
     pub account_data: AccountRoot<'a>,
+    /// The account's signer list, if requested with `signer_lists`.
+    /// API v1 returns it inside `account_data`, API v2 next to it; both
+    /// are read into this field.
+    pub signer_lists: Option<Vec<SignerList<'a>>>,
+}
+
+impl<'a> AccountInfo<'a> {
+    /// The account's signer list, if it has one and the request asked
+    /// for it with `signer_lists`.
+    pub fn signer_list(&self) -> Option<&SignerList<'a>> {
+        self.signer_lists.as_ref().and_then(|lists| lists.first())
+    }
+}
+
+/// The `account_info` result as returned, before the signer lists are
+/// moved out of `account_data`.
+#[derive(Deserialize)]
+struct RawAccountInfo {
+    account_data: Value,
+    signer_lists: Option<Value>,
+}
+
+impl<'a> TryFrom<RawAccountInfo> for AccountInfo<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(raw: RawAccountInfo) -> XRPLModelResult<Self> {
+        let mut account_data = raw.account_data;
+        let signer_lists = match account_data.as_object_mut() {
+            Some(fields) => fields.remove("signer_lists").or(raw.signer_lists),
+            None => raw.signer_lists,
+        };
+
+        Ok(AccountInfo {
+            account_data: serde_json::from_value(account_data)?,
+            signer_lists: signer_lists.map(serde_json::from_value).transpose()?,
+        })
+    }
 }
 
 impl<'a> TryFrom<XRPLResult<'a>> for AccountInfo<'a> {
@@ -26,3 +83,144 @@ impl<'a> TryFrom<XRPLResult<'a>> for AccountInfo<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+
+    use super::AccountInfo;
+    use crate::models::{
+        requests::{account_info::AccountInfo as AccountInfoRequest, Request, XRPLRequest},
+        results::XRPLResponse,
+    };
+
+    const ACCOUNT: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+
+    fn signer_lists() -> Value {
+        json!([{
+            "Flags": 0,
+            "LedgerEntryType": "SignerList",
+            "OwnerNode": "0000000000000000",
+            "PreviousTxnID": "5904C0DC72C58A83AEFED2FFC5386356AA83FCA6A88C89D00646E51E687CDBE4",
+            "PreviousTxnLgrSeq": 16061435,
+            "SignerEntries": [
+                { "SignerEntry": { "Account": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW", "SignerWeight": 2 } },
+                { "SignerEntry": { "Account": "raKEEVSGnKSD9Zyvxu4z6Pqpm4ABH8FS6n", "SignerWeight": 1 } }
+            ],
+            "SignerListID": 0,
+            "SignerQuorum": 3,
+            "index": "A9C28A28B85CD533217F5C0A0C7767666B093FA58A0F2D80026FCC4CD932DDC7"
+        }])
+    }
+
+    fn account_data() -> Value {
+        json!({
+            "Account": ACCOUNT,
+            "Balance": "999999988",
+            "Flags": 0,
+            "LedgerEntryType": "AccountRoot",
+            "OwnerCount": 1,
+            "PreviousTxnID": "5904C0DC72C58A83AEFED2FFC5386356AA83FCA6A88C89D00646E51E687CDBE4",
+            "PreviousTxnLgrSeq": 16061435,
+            "Sequence": 3,
+            "index": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8"
+        })
+    }
+
+    fn assert_signer_list(account_info: &AccountInfo) {
+        let signer_list = account_info.signer_list().unwrap();
+
+        assert_eq!(signer_list.signer_quorum, 3);
+        assert_eq!(signer_list.signer_entries.len(), 2);
+        assert_eq!(
+            signer_list.signer_entries[0].account,
+            "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW"
+        );
+        assert_eq!(signer_list.signer_entries[0].signer_weight, 2);
+        assert_eq!(account_info.account_data.account, ACCOUNT);
+    }
+
+    #[test]
+    fn test_signer_lists_api_v1() {
+        let mut account_data = account_data();
+        account_data["signer_lists"] = signer_lists();
+        let account_info: AccountInfo =
+            serde_json::from_value(json!({ "account_data": account_data })).unwrap();
+
+        assert_signer_list(&account_info);
+    }
+
+    #[test]
+    fn test_signer_lists_api_v2() {
+        let account_info: AccountInfo = serde_json::from_value(json!({
+            "account_data": account_data(),
+            "signer_lists": signer_lists()
+        }))
+        .unwrap();
+
+        assert_signer_list(&account_info);
+    }
+
+    #[test]
+    fn test_api_versions_normalized() {
+        let mut v1_account_data = account_data();
+        v1_account_data["signer_lists"] = signer_lists();
+        let v1: AccountInfo =
+            serde_json::from_value(json!({ "account_data": v1_account_data })).unwrap();
+        let v2: AccountInfo = serde_json::from_value(json!({
+            "account_data": account_data(),
+            "signer_lists": signer_lists()
+        }))
+        .unwrap();
+
+        assert_eq!(v1, v2);
+        let serialized = serde_json::to_value(&v1).unwrap();
+        assert!(serialized["account_data"].get("signer_lists").is_none());
+        assert_eq!(serialized["signer_lists"][0]["SignerQuorum"], 3);
+        assert_eq!(
+            serde_json::from_value::<AccountInfo>(serialized).unwrap(),
+            v2
+        );
+    }
+
+    #[test]
+    fn test_api_v2_request_and_response() {
+        let request = AccountInfoRequest::for_account(ACCOUNT)
+            .with_signer_lists(true)
+            .with_api_version(2);
+        assert_eq!(
+            serde_json::to_value(XRPLRequest::from(request)).unwrap(),
+            json!({
+                "command": "account_info",
+                "account": ACCOUNT,
+                "signer_lists": true,
+                "api_version": 2
+            })
+        );
+
+        let response: XRPLResponse = serde_json::from_value(json!({
+            "api_version": 2,
+            "id": "1",
+            "result": {
+                "account_data": account_data(),
+                "signer_lists": signer_lists(),
+                "ledger_current_index": 16061436,
+                "validated": false
+            },
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap();
+        let account_info = response.try_into_result::<AccountInfo>().unwrap();
+
+        assert_signer_list(&account_info);
+    }
+
+    #[test]
+    fn test_no_signer_lists() {
+        let account_info: AccountInfo =
+            serde_json::from_value(json!({ "account_data": account_data() })).unwrap();
+
+        assert!(account_info.signer_list().is_none());
+    }
+}
@@ -0,0 +1,88 @@
+use core::convert::TryFrom;
+
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::{XRPLModelException, XRPLModelResult};
+
+use super::{exceptions::XRPLResultException, XRPLResult};
+
+/// The result of an `account_lines` request.
+///
+/// See Account Lines:
+/// `<https://xrpl.org/account_lines.html#response-format>`
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AccountLines<'a> {
+    /// Unique address of the account this request corresponds to.
+    pub account: Cow<'a, str>,
+    /// The trust lines connected to this account.
+    pub lines: Vec<TrustLine<'a>>,
+    /// The ledger index of the current in-progress ledger version,
+    /// which was used to retrieve this information.
+    pub ledger_current_index: Option<u32>,
+    /// The ledger index of the ledger version used to retrieve
+    /// this information.
+    pub ledger_index: Option<u32>,
+    /// The identifying hash of the ledger version used to retrieve
+    /// this information.
+    pub ledger_hash: Option<Cow<'a, str>>,
+    /// Server-defined value indicating the response is paginated.
+    pub marker: Option<Value>,
+    pub validated: Option<bool>,
+}
+
+/// A trust line as returned by the `account_lines` method, from the
+/// perspective of the requested account.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TrustLine<'a> {
+    /// The unique address of the counterparty to this trust line.
+    pub account: Cow<'a, str>,
+    /// The current balance. A positive balance means the account holds
+    /// value; a negative balance means the account owes value.
+    pub balance: Cow<'a, str>,
+    /// The currency code of the currency this trust line can hold.
+    pub currency: Cow<'a, str>,
+    /// The maximum amount of the currency that this account is
+    /// willing to owe the peer account.
+    pub limit: Cow<'a, str>,
+    /// The maximum amount of currency that the counterparty account
+    /// is willing to owe this account.
+    pub limit_peer: Cow<'a, str>,
+    /// Rate at which the account values incoming balances on this
+    /// trust line, as a ratio of this value per 1 billion units.
+    pub quality_in: Option<u32>,
+    /// Rate at which the account values outgoing balances on this
+    /// trust line, as a ratio of this value per 1 billion units.
+    pub quality_out: Option<u32>,
+    /// If true, this account has enabled the No Ripple flag.
+    pub no_ripple: Option<bool>,
+    /// If true, the peer account has enabled the No Ripple flag.
+    pub no_ripple_peer: Option<bool>,
+    /// If true, this account has authorized this trust line.
+    pub authorized: Option<bool>,
+    /// If true, the peer account has authorized this trust line.
+    pub peer_authorized: Option<bool>,
+    /// If true, this account has frozen this trust line.
+    pub freeze: Option<bool>,
+    /// If true, the peer account has frozen this trust line.
+    pub freeze_peer: Option<bool>,
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for AccountLines<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::AccountLines(account_lines) => Ok(account_lines),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "AccountLines".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use core::convert::TryFrom;
+
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::{XRPLModelException, XRPLModelResult};
+
+use super::{exceptions::XRPLResultException, XRPLResult};
+
+/// The result of an `account_objects` request.
+///
+/// See Account Objects:
+/// `<https://xrpl.org/account_objects.html#response-format>`
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AccountObjects<'a> {
+    /// Unique address of the account this request corresponds to.
+    pub account: Cow<'a, str>,
+    /// The ledger objects owned by this account, in their raw
+    /// ledger format.
+    pub account_objects: Vec<Value>,
+    /// The ledger index of the current in-progress ledger version,
+    /// which was used to retrieve this information.
+    pub ledger_current_index: Option<u32>,
+    /// The ledger index of the ledger version used to retrieve
+    /// this information.
+    pub ledger_index: Option<u32>,
+    /// The identifying hash of the ledger version used to retrieve
+    /// this information.
+    pub ledger_hash: Option<Cow<'a, str>>,
+    /// The limit that was used in this request, if any.
+    pub limit: Option<u32>,
+    /// Server-defined value indicating the response is paginated.
+    pub marker: Option<Value>,
+    pub validated: Option<bool>,
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for AccountObjects<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::AccountObjects(account_objects) => Ok(account_objects),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "AccountObjects".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
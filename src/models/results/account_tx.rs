@@ -21,6 +21,46 @@ pub struct AccountTx<'a> {
     pub validated: Option<bool>,
 }
 
+/// A transaction in an [`AccountTx`] result.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AccountTxEntry<'a> {
+    /// The identifying hash of the transaction. (API v2)
+    pub hash: Option<Cow<'a, str>>,
+    /// The ledger index of the ledger that includes the transaction.
+    /// (API v2)
+    pub ledger_index: Option<u32>,
+    /// The transaction metadata, or the metadata blob of a binary
+    /// response.
+    pub meta: Option<Value>,
+    /// The transaction, `tx` in API v1.
+    #[serde(alias = "tx")]
+    pub tx_json: Option<Value>,
+    pub validated: Option<bool>,
+}
+
+impl<'a> AccountTx<'a> {
+    /// Deserializes the transactions of the result.
+    pub fn entries(&self) -> XRPLModelResult<Vec<AccountTxEntry<'static>>> {
+        self.transactions
+            .iter()
+            .map(|entry| Ok(serde_json::from_value(entry.clone())?))
+            .collect()
+    }
+}
+
+impl<'a> AccountTxEntry<'a> {
+    /// The ledger index of the ledger that includes the transaction,
+    /// read from the transaction itself in API v1.
+    pub fn in_ledger(&self) -> Option<u32> {
+        self.ledger_index.or_else(|| {
+            self.tx_json
+                .as_ref()
+                .and_then(|tx| tx["ledger_index"].as_u64())
+                .and_then(|index| u32::try_from(index).ok())
+        })
+    }
+}
+
 impl<'a> TryFrom<XRPLResult<'a>> for AccountTx<'a> {
     type Error = XRPLModelException;
 
@@ -0,0 +1,185 @@
+use core::convert::TryFrom;
+
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::{amount::Amount, XRPLModelException, XRPLModelResult};
+
+use super::{exceptions::XRPLResultException, XRPLResult};
+
+/// The result of an `amm_info` request.
+///
+/// See AMM Info:
+/// `<https://xrpl.org/amm_info.html#response-format>`
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AMMInfo<'a> {
+    /// The state of the AMM instance.
+    pub amm: AMMDescription<'a>,
+    /// The ledger index of the current in-progress ledger, which
+    /// was used to retrieve this information.
+    pub ledger_current_index: Option<u32>,
+    /// The ledger index of the ledger version used to retrieve
+    /// this information.
+    pub ledger_index: Option<u32>,
+    /// The identifying hash of the ledger version used to retrieve
+    /// this information.
+    pub ledger_hash: Option<Cow<'a, str>>,
+    pub validated: Option<bool>,
+}
+
+/// The pool state of an AMM instance as returned by `amm_info`.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AMMDescription<'a> {
+    /// The address of the AMM's special AccountRoot.
+    pub account: Cow<'a, str>,
+    /// The total amount of one asset in the AMM's pool.
+    pub amount: Amount<'a>,
+    /// The total amount of the other asset in the AMM's pool.
+    pub amount2: Amount<'a>,
+    /// Whether `amount` is currently frozen.
+    pub asset_frozen: Option<bool>,
+    /// Whether `amount2` is currently frozen.
+    pub asset2_frozen: Option<bool>,
+    /// The current holder of the auction slot, if any.
+    pub auction_slot: Option<AMMAuctionSlot<'a>>,
+    /// The total amount of this AMM's LP Tokens outstanding.
+    pub lp_token: Amount<'a>,
+    /// The AMM's current trading fee, in units of 1/100,000.
+    pub trading_fee: u16,
+    /// The current votes for the AMM's trading fee.
+    pub vote_slots: Option<Vec<AMMVoteSlot<'a>>>,
+}
+
+/// The auction slot of an AMM instance as returned by `amm_info`.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AMMAuctionSlot<'a> {
+    /// The address of the account that owns the auction slot.
+    pub account: Cow<'a, str>,
+    /// Additional accounts that may trade at the discounted fee.
+    pub auth_accounts: Option<Vec<AMMAuthAccount<'a>>>,
+    /// The discounted trading fee that applies to the slot holder.
+    pub discounted_fee: u32,
+    /// The time when the slot expires, as a human-readable UTC timestamp.
+    pub expiration: Cow<'a, str>,
+    /// The amount the slot holder paid, in LP Tokens.
+    pub price: Amount<'a>,
+    /// The current 72-minute interval of the auction slot, from 0 to 19.
+    pub time_interval: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AMMAuthAccount<'a> {
+    pub account: Cow<'a, str>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AMMVoteSlot<'a> {
+    /// The account that cast the vote.
+    pub account: Cow<'a, str>,
+    /// The proposed trading fee, in units of 1/100,000.
+    pub trading_fee: u16,
+    /// The weight of the vote, in units of 1/100,000.
+    pub vote_weight: u32,
+}
+
+impl<'a> AMMDescription<'a> {
+    /// Returns the current spot price of `amount` denominated in
+    /// `amount2`, ignoring the trading fee. XRP is counted in whole XRP.
+    pub fn get_spot_price(&self) -> XRPLModelResult<BigDecimal> {
+        Ok(amount_to_decimal(&self.amount2)? / amount_to_decimal(&self.amount)?)
+    }
+}
+
+fn amount_to_decimal(amount: &Amount<'_>) -> XRPLModelResult<BigDecimal> {
+    let value: BigDecimal = amount.clone().try_into()?;
+    match amount {
+        Amount::XRPAmount(_) => Ok(value / BigDecimal::from(1_000_000)),
+        Amount::IssuedCurrencyAmount(_) => Ok(value),
+    }
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for AMMInfo<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::AMMInfo(amm_info) => Ok(amm_info),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "AMMInfo".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::str::FromStr;
+
+    use bigdecimal::BigDecimal;
+
+    use super::AMMInfo;
+
+    #[test]
+    fn test_deserialize_and_spot_price() {
+        let amm_info: AMMInfo = serde_json::from_str(
+            r#"{
+                "amm": {
+                    "account": "rp9E3FN3gNmvePGhYnf414T2TkUuoxu8vM",
+                    "amount": "296890496",
+                    "amount2": {
+                        "currency": "TST",
+                        "issuer": "rP9jPyP5kyvFRb6ZiRghAGw5u8SGAmU4bd",
+                        "value": "25.81656470648473"
+                    },
+                    "asset2_frozen": false,
+                    "auction_slot": {
+                        "account": "rJVUeRqDFNs2xqA7ncVE6ZoAhPUoaJJSQm",
+                        "auth_accounts": [
+                            { "account": "r3f2WpQMsAd8k4Zoijv2SSGA3D8HJy6ri5" }
+                        ],
+                        "discounted_fee": 23,
+                        "expiration": "2023-Nov-24 08:10:20.000000000 UTC",
+                        "price": {
+                            "currency": "039C99CD9AB0B70B32ECDA51EAAE471625608EA2",
+                            "issuer": "rp9E3FN3gNmvePGhYnf414T2TkUuoxu8vM",
+                            "value": "0"
+                        },
+                        "time_interval": 0
+                    },
+                    "lp_token": {
+                        "currency": "039C99CD9AB0B70B32ECDA51EAAE471625608EA2",
+                        "issuer": "rp9E3FN3gNmvePGhYnf414T2TkUuoxu8vM",
+                        "value": "87533.41976112682"
+                    },
+                    "trading_fee": 235,
+                    "vote_slots": [
+                        {
+                            "account": "rJVUeRqDFNs2xqA7ncVE6ZoAhPUoaJJSQm",
+                            "trading_fee": 235,
+                            "vote_weight": 100000
+                        }
+                    ]
+                },
+                "ledger_current_index": 316745,
+                "validated": false
+            }"#,
+        )
+        .unwrap();
+        let amm = amm_info.amm;
+
+        assert_eq!(amm.trading_fee, 235);
+        assert_eq!(amm.vote_slots.as_ref().unwrap()[0].vote_weight, 100000);
+        assert_eq!(amm.auction_slot.as_ref().unwrap().discounted_fee, 23);
+        assert_eq!(
+            amm.get_spot_price().unwrap().round(12),
+            BigDecimal::from_str("0.086956521190").unwrap()
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use core::{convert::TryFrom, str::FromStr};
+
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::{
+    amount::Amount, ledger::objects::offer::Offer, transactions::offer_create::compute_quality,
+    XRPLModelException, XRPLModelResult,
+};
+
+use super::{exceptions::XRPLResultException, XRPLResult};
+
+/// The result of a `book_offers` request.
+///
+/// See Book Offers:
+/// `<https://xrpl.org/book_offers.html#response-format>`
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BookOffers<'a> {
+    /// Array of offer objects, each of which has the fields of an
+    /// `Offer` ledger object plus the funding details.
+    pub offers: Vec<BookOffer<'a>>,
+    /// The ledger index of the current in-progress ledger version,
+    /// which was used to retrieve this information.
+    pub ledger_current_index: Option<u32>,
+    /// The ledger index of the ledger version that was used when
+    /// retrieving this data, as requested.
+    pub ledger_index: Option<u32>,
+    /// The identifying hash of the ledger version that was used when
+    /// retrieving this data, as requested.
+    pub ledger_hash: Option<Cow<'a, str>>,
+    pub validated: Option<bool>,
+}
+
+/// An offer as returned by the `book_offers` method.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BookOffer<'a> {
+    #[serde(flatten)]
+    pub offer: Offer<'a>,
+    /// Amount of the `TakerGets` currency the side placing the offer
+    /// has available to be traded.
+    pub owner_funds: Option<Cow<'a, str>>,
+    /// The maximum amount of currency that the taker can get, given
+    /// the funding status of the offer.
+    pub taker_gets_funded: Option<Amount<'a>>,
+    /// The maximum amount of currency that the taker would pay, given
+    /// the funding status of the offer.
+    pub taker_pays_funded: Option<Amount<'a>>,
+    /// The exchange rate, as the ratio `TakerPays` divided by
+    /// `TakerGets`. Lower is better for the taker.
+    pub quality: Option<Cow<'a, str>>,
+}
+
+impl<'a> BookOffer<'a> {
+    /// Returns the offer's quality, computing it from `TakerPays` and
+    /// `TakerGets` if the server did not include it.
+    pub fn get_quality(&self) -> XRPLModelResult<BigDecimal> {
+        match &self.quality {
+            Some(quality) => Ok(BigDecimal::from_str(quality)?),
+            None => compute_quality(&self.offer.taker_gets, &self.offer.taker_pays),
+        }
+    }
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for BookOffers<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::BookOffers(book_offers) => Ok(book_offers),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "BookOffers".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use core::convert::TryFrom;
+
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::{XRPLModelException, XRPLModelResult};
+
+use super::{exceptions::XRPLResultException, XRPLResult};
+
+/// The result of a `deposit_authorized` request.
+///
+/// See Deposit Authorized:
+/// `<https://xrpl.org/deposit_authorized.html#response-format>`
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DepositAuthorized<'a> {
+    /// Whether the specified source account is authorized to send
+    /// payments directly to the destination account.
+    pub deposit_authorized: bool,
+    /// The destination account specified in the request.
+    pub destination_account: Cow<'a, str>,
+    /// The source account specified in the request.
+    pub source_account: Cow<'a, str>,
+    /// The credentials specified in the request, if any.
+    pub credentials: Option<Vec<Cow<'a, str>>>,
+    /// The ledger index of the current in-progress ledger version,
+    /// which was used to generate this response.
+    pub ledger_current_index: Option<u32>,
+    /// The ledger index of the ledger version used to generate this
+    /// response.
+    pub ledger_index: Option<u32>,
+    /// The identifying hash of the ledger used to generate this
+    /// response.
+    pub ledger_hash: Option<Cow<'a, str>>,
+    pub validated: Option<bool>,
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for DepositAuthorized<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::DepositAuthorized(deposit_authorized) => Ok(deposit_authorized),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "DepositAuthorized".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
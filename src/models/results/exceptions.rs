@@ -16,6 +16,12 @@ pub enum XRPLResultException {
     IndexNotFound,
     #[error("Called unwrap on `XRPLOtherResult`.")]
     UnwrapOnOther,
+    #[error("The ledger was not requested with expanded transactions.")]
+    LedgerNotExpanded,
+    #[error("The manifest could not be decoded: {0}")]
+    InvalidManifest(String),
+    #[error("The manifest's `{0}` does not verify.")]
+    InvalidManifestSignature(&'static str),
     #[error("Expected a XRPL Result model but got `XRPLOtherResult`: {0:?}.")]
     ExpectedResult(XRPLOtherResult),
 }
@@ -1,12 +1,22 @@
 use core::convert::TryFrom;
 
-use alloc::{borrow::Cow, string::ToString, vec::Vec};
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-use crate::models::{
-    results::exceptions::XRPLResultException, XRPLModelException, XRPLModelResult,
+use crate::{
+    core::keypairs::utils::sha512_first_half,
+    models::{results::exceptions::XRPLResultException, XRPLModelException, XRPLModelResult},
 };
 
+/// The prefix prepended to a ledger header before hashing it ("LWR\0").
+const LEDGER_HASH_PREFIX: u32 = 0x4C575200;
+const HASH_LENGTH: usize = 32;
+
 use super::XRPLResult;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -32,7 +42,90 @@ pub struct LedgerInner<'a> {
     pub parent_hash: Cow<'a, str>,
     pub total_coins: Cow<'a, str>,
     pub transaction_hash: Cow<'a, str>,
-    pub transactions: Option<Vec<Cow<'a, str>>>,
+    /// The ledger's transactions, present when requested with
+    /// `transactions: true`.
+    pub transactions: Option<LedgerTransactions<'a>>,
+}
+
+/// The transactions of a ledger: their hashes, or the full transactions
+/// if the ledger was requested with `expand: true`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum LedgerTransactions<'a> {
+    Hashes(Vec<Cow<'a, str>>),
+    Expanded(Vec<Value>),
+}
+
+impl<'a> Ledger<'a> {
+    /// Computes the hash of the ledger header. Compare it with
+    /// `ledger_hash` to verify the header was not tampered with.
+    pub fn compute_hash(&self) -> XRPLModelResult<String> {
+        self.ledger.compute_hash()
+    }
+
+    /// Returns the full transactions of a ledger requested with
+    /// `transactions: true` and `expand: true`.
+    pub fn expanded_transactions(&self) -> XRPLModelResult<&[Value]> {
+        match &self.ledger.transactions {
+            Some(LedgerTransactions::Expanded(transactions)) => Ok(transactions),
+            // An empty ledger looks the same either way.
+            Some(LedgerTransactions::Hashes(hashes)) if hashes.is_empty() => Ok(&[]),
+            _ => Err(XRPLResultException::LedgerNotExpanded.into()),
+        }
+    }
+}
+
+impl<'a> LedgerInner<'a> {
+    /// Serializes the ledger header fields in canonical order, as the
+    /// ledger does before hashing them.
+    pub fn encode_header(&self) -> XRPLModelResult<Vec<u8>> {
+        let mut buffer = Vec::new();
+        buffer.extend(self.ledger_index.parse::<u32>()?.to_be_bytes());
+        buffer.extend(self.total_coins.parse::<u64>()?.to_be_bytes());
+        buffer.extend(decode_hash("parent_hash", &self.parent_hash)?);
+        buffer.extend(decode_hash("transaction_hash", &self.transaction_hash)?);
+        buffer.extend(decode_hash("account_hash", &self.account_hash)?);
+        buffer.extend(self.parent_close_time.to_be_bytes());
+        buffer.extend(self.close_time.to_be_bytes());
+        buffer.push(u8::try_from(self.close_time_resolution).map_err(|_| {
+            XRPLModelException::ValueTooHigh {
+                field: "close_time_resolution".to_string(),
+                max: u8::MAX as u32,
+                found: self.close_time_resolution,
+            }
+        })?);
+        buffer.push(u8::try_from(self.close_flags).map_err(|_| {
+            XRPLModelException::ValueTooHigh {
+                field: "close_flags".to_string(),
+                max: u8::MAX as u32,
+                found: self.close_flags,
+            }
+        })?);
+
+        Ok(buffer)
+    }
+
+    /// Computes the hash of the ledger header: the SHA-512Half of the
+    /// `LWR\0` prefix followed by the encoded header.
+    pub fn compute_hash(&self) -> XRPLModelResult<String> {
+        let mut buffer = LEDGER_HASH_PREFIX.to_be_bytes().to_vec();
+        buffer.extend(self.encode_header()?);
+
+        Ok(hex::encode_upper(sha512_first_half(&buffer)))
+    }
+}
+
+fn decode_hash(field: &str, hash: &str) -> XRPLModelResult<Vec<u8>> {
+    let bytes = hex::decode(hash)?;
+    if bytes.len() != HASH_LENGTH {
+        return Err(XRPLModelException::InvalidValueFormat {
+            field: field.to_string(),
+            format: "256-bit hex hash".to_string(),
+            found: hash.to_string(),
+        });
+    }
+
+    Ok(bytes)
 }
 
 impl<'a> TryFrom<XRPLResult<'a>> for Ledger<'a> {
@@ -49,3 +142,118 @@ impl<'a> TryFrom<XRPLResult<'a>> for Ledger<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{Ledger, LedgerTransactions};
+
+    /// Ledger 32052277 from the `ledgerData` codec fixtures.
+    const LEDGER: &str = r#"{
+        "ledger": {
+            "account_hash": "3B5C3E520634D343EF5D9D9A4246643D64DAD278BA95DC0EAC6EB5350CF970D5",
+            "close_flags": 0,
+            "close_time": 556231910,
+            "close_time_resolution": 10,
+            "closed": true,
+            "ledger_hash": "",
+            "ledger_index": "32052277",
+            "parent_close_time": 556231902,
+            "parent_hash": "EACEB081770D8ADE216C85445DD6FB002C6B5A2930F2DECE006DA18150CB18F6",
+            "total_coins": "99994494362043555",
+            "transaction_hash": "DD33F6F0990754C962A7CCE62F332FF9C13939B03B864117F0BDA86B6E9B4F87"
+        },
+        "ledger_hash": "",
+        "ledger_index": 32052277,
+        "validated": true
+    }"#;
+    /// Ledger 15202439 with its published `ledger_hash`, as in the
+    /// xrpl.js `calcLedgerHash` fixture.
+    const PUBLISHED_LEDGER: &str = r#"{
+        "ledger": {
+            "account_hash": "D9ABF622DA26EEEE48203085D4BC23B0F77DC6F8724AC33D975DA3CA492D2E44",
+            "close_flags": 0,
+            "close_time": 492656470,
+            "close_time_resolution": 10,
+            "closed": true,
+            "ledger_hash": "F4D865D83EB88C1A1911B9E90641919A1314F36E1B099F8E95FE3B7C77BE3349",
+            "ledger_index": "15202439",
+            "parent_close_time": 492656460,
+            "parent_hash": "12724A65B030C15A1573AA28B1BBB5DF3DA4589AA3623675A31CAE69B23B1C4E",
+            "total_coins": "99998831688050493",
+            "transaction_hash": "325EACC5271322539EEEC2D6A5292471EF1B3E72AE7180533EFC3B8F0AD435C8"
+        },
+        "ledger_hash": "F4D865D83EB88C1A1911B9E90641919A1314F36E1B099F8E95FE3B7C77BE3349",
+        "ledger_index": 15202439,
+        "validated": true
+    }"#;
+
+    #[test]
+    fn test_encode_header() {
+        let ledger: Ledger = serde_json::from_str(LEDGER).unwrap();
+
+        assert_eq!(
+            hex::encode_upper(ledger.ledger.encode_header().unwrap()),
+            "01E91435016340767BF1C4A3EACEB081770D8ADE216C85445DD6FB002C6B5A2930F2DECE006DA18150CB18F6DD33F6F0990754C962A7CCE62F332FF9C13939B03B864117F0BDA86B6E9B4F873B5C3E520634D343EF5D9D9A4246643D64DAD278BA95DC0EAC6EB5350CF970D521276CDE21276CE60A00"
+        );
+    }
+
+    #[test]
+    fn test_compute_hash() {
+        let ledger: Ledger = serde_json::from_str(PUBLISHED_LEDGER).unwrap();
+
+        assert_eq!(
+            ledger.compute_hash().unwrap(),
+            "F4D865D83EB88C1A1911B9E90641919A1314F36E1B099F8E95FE3B7C77BE3349"
+        );
+        assert_eq!(ledger.compute_hash().unwrap(), ledger.ledger_hash);
+    }
+
+    #[test]
+    fn test_compute_hash_invalid_hash() {
+        let mut ledger: Ledger = serde_json::from_str(LEDGER).unwrap();
+        ledger.ledger.account_hash = "ABCD".into();
+
+        assert!(ledger.compute_hash().is_err());
+    }
+
+    #[test]
+    fn test_hash_only_transactions() {
+        let mut ledger: serde_json::Value = serde_json::from_str(LEDGER).unwrap();
+        ledger["ledger"]["transactions"] =
+            serde_json::json!(["1FC4D12C30CE206A6E23F46FAC62BD393BE9A79A1C452C6F3A04A13BC7A5E5A3"]);
+        let ledger: Ledger = serde_json::from_value(ledger).unwrap();
+
+        assert!(matches!(
+            &ledger.ledger.transactions,
+            Some(LedgerTransactions::Hashes(hashes)) if hashes.len() == 1
+        ));
+        assert!(ledger.expanded_transactions().is_err());
+    }
+
+    #[test]
+    fn test_expanded_transactions() {
+        let mut ledger: serde_json::Value = serde_json::from_str(LEDGER).unwrap();
+        ledger["ledger"]["transactions"] = serde_json::json!([{
+            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "Amount": "1000000",
+            "Destination": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+            "Fee": "12",
+            "Sequence": 1,
+            "TransactionType": "Payment",
+            "hash": "1FC4D12C30CE206A6E23F46FAC62BD393BE9A79A1C452C6F3A04A13BC7A5E5A3",
+            "metaData": { "TransactionIndex": 0, "TransactionResult": "tesSUCCESS" }
+        }]);
+        let ledger: Ledger = serde_json::from_value(ledger).unwrap();
+        let transactions = ledger.expanded_transactions().unwrap();
+
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(transactions[0]["TransactionType"], "Payment");
+    }
+
+    #[test]
+    fn test_no_transactions() {
+        let ledger: Ledger = serde_json::from_str(LEDGER).unwrap();
+
+        assert!(ledger.expanded_transactions().is_err());
+    }
+}
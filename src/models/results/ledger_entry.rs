@@ -0,0 +1,46 @@
+use core::convert::TryFrom;
+
+use alloc::{borrow::Cow, string::ToString};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::models::{
+    results::exceptions::XRPLResultException, XRPLModelException, XRPLModelResult,
+};
+
+use super::XRPLResult;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LedgerEntry<'a> {
+    /// The unique ID of this ledger object.
+    pub index: Cow<'a, str>,
+    /// The ledger index of the ledger that was used when
+    /// retrieving this data.
+    pub ledger_index: Option<u32>,
+    /// The ledger index of the current in-progress ledger,
+    /// if the data was retrieved from it.
+    pub ledger_current_index: Option<u32>,
+    /// Object containing the data of this ledger object,
+    /// according to the ledger format. Omitted if `binary`
+    /// was requested.
+    pub node: Option<Value>,
+    /// The binary representation of the ledger object, as
+    /// hexadecimal. Only present if `binary` was requested.
+    pub node_binary: Option<Cow<'a, str>>,
+    pub validated: Option<bool>,
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for LedgerEntry<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::LedgerEntry(ledger_entry) => Ok(ledger_entry),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "LedgerEntry".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use core::convert::TryFrom;
+
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{String, ToString},
+};
+use base64::{prelude::BASE64_STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::{
+    core::{
+        binarycodec::{decode, encode_for_signing_manifest},
+        keypairs::{is_valid_message, is_valid_public_key},
+    },
+    models::{results::exceptions::XRPLResultException, XRPLModelException, XRPLModelResult},
+};
+
+use super::XRPLResult;
+
+/// The result of a `manifest` request.
+///
+/// See Manifest:
+/// `<https://xrpl.org/docs/references/http-websocket-apis/admin-api-methods/status-and-debugging-methods/manifest>`
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Manifest<'a> {
+    /// The data contained in the manifest, if the server knows one
+    /// for the requested key.
+    pub details: Option<ManifestDetails<'a>>,
+    /// The full manifest data in base64 format.
+    pub manifest: Option<Cow<'a, str>>,
+    /// The public key from the request.
+    pub requested: Cow<'a, str>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ManifestDetails<'a> {
+    /// The domain name this validator claims to be associated with.
+    pub domain: Cow<'a, str>,
+    /// The ephemeral public key of the validator, in base58.
+    pub ephemeral_key: Cow<'a, str>,
+    /// The master public key of the validator, in base58.
+    pub master_key: Cow<'a, str>,
+    /// The sequence number of the manifest.
+    pub seq: u32,
+}
+
+/// The fields of a manifest blob. Keys and signatures are hex-encoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedManifest {
+    pub sequence: u32,
+    /// The master public key of the validator.
+    pub public_key: String,
+    /// The ephemeral key the validator signs validations with. A
+    /// revoked manifest has none.
+    pub signing_public_key: Option<String>,
+    /// The signature of the ephemeral key.
+    pub signature: Option<String>,
+    /// The signature of the master key.
+    pub master_signature: String,
+    pub domain: Option<String>,
+}
+
+impl Manifest<'_> {
+    /// Decodes the `manifest` blob and verifies its signatures.
+    pub fn decoded(&self) -> XRPLModelResult<DecodedManifest> {
+        match &self.manifest {
+            Some(manifest) => DecodedManifest::from_base64(manifest),
+            None => Err(XRPLModelException::MissingField("manifest".to_string())),
+        }
+    }
+}
+
+impl DecodedManifest {
+    /// Decodes a base64 manifest blob and verifies its signatures.
+    pub fn from_base64(manifest: &str) -> XRPLModelResult<Self> {
+        let invalid =
+            |error: &dyn ToString| XRPLResultException::InvalidManifest(error.to_string());
+        let bytes = BASE64_STANDARD
+            .decode(manifest)
+            .map_err(|error| invalid(&error))?;
+        let fields = decode(&hex::encode_upper(bytes)).map_err(|error| invalid(&error))?;
+        let text = |name: &str| fields.get(name).and_then(Value::as_str).map(String::from);
+        let required = |name: &str| {
+            text(name).ok_or_else(|| XRPLModelException::MissingField(name.to_string()))
+        };
+        let domain = match text("Domain") {
+            Some(domain) => {
+                Some(String::from_utf8(hex::decode(domain)?).map_err(|error| invalid(&error))?)
+            }
+            None => None,
+        };
+        let decoded = DecodedManifest {
+            sequence: fields
+                .get("Sequence")
+                .and_then(Value::as_u64)
+                .ok_or_else(|| XRPLModelException::MissingField("Sequence".to_string()))?
+                as u32,
+            public_key: required("PublicKey")?,
+            signing_public_key: text("SigningPubKey"),
+            signature: text("Signature"),
+            master_signature: required("MasterSignature")?,
+            domain,
+        };
+        let message =
+            hex::decode(encode_for_signing_manifest(&fields).map_err(|error| invalid(&error))?)?;
+        decoded.verify(&message)?;
+
+        Ok(decoded)
+    }
+
+    fn verify(&self, message: &[u8]) -> XRPLModelResult<()> {
+        for (name, key) in [
+            ("PublicKey", Some(&self.public_key)),
+            ("SigningPubKey", self.signing_public_key.as_ref()),
+        ] {
+            if key.is_some_and(|key| !is_valid_public_key(key)) {
+                return Err(
+                    XRPLResultException::InvalidManifest(format!("malformed {}", name)).into(),
+                );
+            }
+        }
+        if !is_valid_message(message, &self.master_signature, &self.public_key) {
+            return Err(XRPLResultException::InvalidManifestSignature("MasterSignature").into());
+        }
+        if let (Some(signature), Some(signing_public_key)) =
+            (&self.signature, &self.signing_public_key)
+        {
+            if !is_valid_message(message, signature, signing_public_key) {
+                return Err(XRPLResultException::InvalidManifestSignature("Signature").into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for Manifest<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::Manifest(manifest) => Ok(manifest),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "Manifest".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::{
+        binarycodec::encode,
+        keypairs::{derive_keypair, sign},
+    };
+    use serde_json::json;
+
+    /// Builds a manifest for `domain` signed by two freshly derived keys.
+    fn signed_manifest(sequence: u32, domain: &str) -> Value {
+        let (master_public_key, master_private_key) =
+            derive_keypair("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r", false).unwrap();
+        let (signing_public_key, signing_private_key) =
+            derive_keypair("sp5fghtJtpUorTwvof1NpDXAzNwf5", false).unwrap();
+        let mut manifest = json!({
+            "Sequence": sequence,
+            "PublicKey": master_public_key,
+            "SigningPubKey": signing_public_key,
+            "Domain": hex::encode_upper(domain),
+        });
+        let message = hex::decode(encode_for_signing_manifest(&manifest).unwrap()).unwrap();
+        manifest["Signature"] = sign(&message, &signing_private_key).unwrap().into();
+        manifest["MasterSignature"] = sign(&message, &master_private_key).unwrap().into();
+
+        manifest
+    }
+
+    fn to_base64(manifest: &Value) -> String {
+        BASE64_STANDARD.encode(hex::decode(encode(manifest).unwrap()).unwrap())
+    }
+
+    const MANIFEST: &str = "JAAAAAFxIe1FtwmimvGtH2iCcMJqC9gVFKilGfw1/vCxHXXLplc2GnMhAkE1agqXxBwDwDbID6OMSYuM0FDAlpAgNk8SKFn7MO2fdkcwRQIhAOngu9sAKqXYouJ+l2V0W+sAOkVB+ZRS6PShlJAfUsXfAiBsVJGesaadOJc/aAZokS1vymGmVrlHPKWX3Yywu6in8HASQKPugBD67kMaRFGvmpATHlGKJdvDFlWPYy5AqDedFv5TJa2w0i21eq3MYywLVJZnFOr7C0kw2AiTzSCjIzditQ8=";
+
+    #[test]
+    fn test_decoded() {
+        let manifest = Manifest {
+            details: None,
+            manifest: Some(MANIFEST.into()),
+            requested: "nHUFE9prPXPrHcG3SkwP1UzAQbSphqyQkQK9ATXLZsfkezhhda3p".into(),
+        };
+        let decoded = manifest.decoded().unwrap();
+
+        assert_eq!(decoded.sequence, 1);
+        assert_eq!(decoded.domain, None);
+        assert_eq!(
+            decoded.public_key,
+            "ED45B709A29AF1AD1F688270C26A0BD81514A8A519FC35FEF0B11D75CBA657361A"
+        );
+    }
+
+    #[test]
+    fn test_decoded_domain() {
+        let manifest = signed_manifest(7, "example.com");
+        let decoded = DecodedManifest::from_base64(&to_base64(&manifest)).unwrap();
+
+        assert_eq!(decoded.sequence, 7);
+        assert_eq!(decoded.domain.as_deref(), Some("example.com"));
+        assert_eq!(
+            decoded.signing_public_key,
+            manifest["SigningPubKey"].as_str().map(String::from)
+        );
+    }
+
+    #[test]
+    fn test_tampered_manifest() {
+        let mut manifest = signed_manifest(7, "example.com");
+        manifest["Domain"] = hex::encode_upper("attacker.example").into();
+
+        assert!(matches!(
+            DecodedManifest::from_base64(&to_base64(&manifest)),
+            Err(XRPLModelException::XRPLResultError(
+                XRPLResultException::InvalidManifestSignature("MasterSignature")
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_malformed_key() {
+        for (name, key) in [
+            ("PublicKey", ""),
+            ("PublicKey", "ED00"),
+            ("SigningPubKey", "ED"),
+        ] {
+            let mut manifest = signed_manifest(7, "example.com");
+            manifest[name] = key.into();
+
+            assert!(matches!(
+                DecodedManifest::from_base64(&to_base64(&manifest)),
+                Err(XRPLModelException::XRPLResultError(
+                    XRPLResultException::InvalidManifest(_)
+                ))
+            ));
+        }
+    }
+}
@@ -1,10 +1,19 @@
 pub mod account_info;
+pub mod account_lines;
+pub mod account_objects;
 pub mod account_tx;
+pub mod amm_info;
+pub mod book_offers;
+pub mod deposit_authorized;
 pub mod exceptions;
 pub mod fee;
 pub mod ledger;
+pub mod ledger_entry;
+pub mod manifest;
 pub mod server_state;
+pub mod simulate;
 pub mod submit;
+pub mod subscribe;
 pub mod tx;
 
 use crate::XRPLSerdeJsonError;
@@ -123,11 +132,19 @@ impl XRPLOtherResult {
 #[serde(untagged)]
 pub enum XRPLResult<'a> {
     AccountInfo(account_info::AccountInfo<'a>),
+    AccountLines(account_lines::AccountLines<'a>),
+    AccountObjects(account_objects::AccountObjects<'a>),
+    AMMInfo(amm_info::AMMInfo<'a>),
     AccountTx(account_tx::AccountTx<'a>),
+    BookOffers(book_offers::BookOffers<'a>),
+    DepositAuthorized(deposit_authorized::DepositAuthorized<'a>),
     Fee(fee::Fee<'a>),
     Ledger(ledger::Ledger<'a>),
+    LedgerEntry(ledger_entry::LedgerEntry<'a>),
+    Manifest(manifest::Manifest<'a>),
     ServerState(server_state::ServerState<'a>),
     Submit(submit::Submit<'a>),
+    Simulate(simulate::Simulate<'a>),
     Tx(tx::Tx<'a>),
     Other(XRPLOtherResult),
 }
@@ -138,12 +155,42 @@ impl<'a> From<account_info::AccountInfo<'a>> for XRPLResult<'a> {
     }
 }
 
+impl<'a> From<account_lines::AccountLines<'a>> for XRPLResult<'a> {
+    fn from(account_lines: account_lines::AccountLines<'a>) -> Self {
+        XRPLResult::AccountLines(account_lines)
+    }
+}
+
+impl<'a> From<account_objects::AccountObjects<'a>> for XRPLResult<'a> {
+    fn from(account_objects: account_objects::AccountObjects<'a>) -> Self {
+        XRPLResult::AccountObjects(account_objects)
+    }
+}
+
+impl<'a> From<amm_info::AMMInfo<'a>> for XRPLResult<'a> {
+    fn from(amm_info: amm_info::AMMInfo<'a>) -> Self {
+        XRPLResult::AMMInfo(amm_info)
+    }
+}
+
 impl<'a> From<account_tx::AccountTx<'a>> for XRPLResult<'a> {
     fn from(account_tx: account_tx::AccountTx<'a>) -> Self {
         XRPLResult::AccountTx(account_tx)
     }
 }
 
+impl<'a> From<book_offers::BookOffers<'a>> for XRPLResult<'a> {
+    fn from(book_offers: book_offers::BookOffers<'a>) -> Self {
+        XRPLResult::BookOffers(book_offers)
+    }
+}
+
+impl<'a> From<deposit_authorized::DepositAuthorized<'a>> for XRPLResult<'a> {
+    fn from(deposit_authorized: deposit_authorized::DepositAuthorized<'a>) -> Self {
+        XRPLResult::DepositAuthorized(deposit_authorized)
+    }
+}
+
 impl<'a> From<fee::Fee<'a>> for XRPLResult<'a> {
     fn from(fee: fee::Fee<'a>) -> Self {
         XRPLResult::Fee(fee)
@@ -156,12 +203,30 @@ impl<'a> From<ledger::Ledger<'a>> for XRPLResult<'a> {
     }
 }
 
+impl<'a> From<ledger_entry::LedgerEntry<'a>> for XRPLResult<'a> {
+    fn from(ledger_entry: ledger_entry::LedgerEntry<'a>) -> Self {
+        XRPLResult::LedgerEntry(ledger_entry)
+    }
+}
+
+impl<'a> From<manifest::Manifest<'a>> for XRPLResult<'a> {
+    fn from(manifest: manifest::Manifest<'a>) -> Self {
+        XRPLResult::Manifest(manifest)
+    }
+}
+
 impl<'a> From<server_state::ServerState<'a>> for XRPLResult<'a> {
     fn from(server_state: server_state::ServerState<'a>) -> Self {
         XRPLResult::ServerState(server_state)
     }
 }
 
+impl<'a> From<simulate::Simulate<'a>> for XRPLResult<'a> {
+    fn from(simulate: simulate::Simulate<'a>) -> Self {
+        XRPLResult::Simulate(simulate)
+    }
+}
+
 impl<'a> From<submit::Submit<'a>> for XRPLResult<'a> {
     fn from(submit: submit::Submit<'a>) -> Self {
         XRPLResult::Submit(submit)
@@ -201,11 +266,19 @@ impl XRPLResult<'_> {
     pub(crate) fn get_name(&self) -> String {
         match self {
             XRPLResult::AccountInfo(_) => "AccountInfo".to_string(),
+            XRPLResult::AccountLines(_) => "AccountLines".to_string(),
+            XRPLResult::AccountObjects(_) => "AccountObjects".to_string(),
+            XRPLResult::AMMInfo(_) => "AMMInfo".to_string(),
             XRPLResult::AccountTx(_) => "AccountTx".to_string(),
+            XRPLResult::BookOffers(_) => "BookOffers".to_string(),
+            XRPLResult::DepositAuthorized(_) => "DepositAuthorized".to_string(),
             XRPLResult::Fee(_) => "Fee".to_string(),
             XRPLResult::Ledger(_) => "Ledger".to_string(),
+            XRPLResult::LedgerEntry(_) => "LedgerEntry".to_string(),
+            XRPLResult::Manifest(_) => "Manifest".to_string(),
             XRPLResult::ServerState(_) => "ServerState".to_string(),
             XRPLResult::Submit(_) => "Submit".to_string(),
+            XRPLResult::Simulate(_) => "Simulate".to_string(),
             XRPLResult::Tx(_) => "Tx".to_string(),
             XRPLResult::Other(_) => "Other".to_string(),
         }
@@ -1,6 +1,7 @@
 use core::convert::TryFrom;
 
 use alloc::{borrow::Cow, string::ToString};
+use bigdecimal::BigDecimal;
 use serde::{Deserialize, Serialize};
 
 use crate::models::{
@@ -32,6 +33,23 @@ pub struct ValidatedLedger<'a> {
     pub seq: u32,
 }
 
+impl<'a> ServerState<'a> {
+    /// Returns the XRP an account owning `owner_count` objects must hold
+    /// in reserve, i.e. `reserve_base + owner_count * reserve_inc`, in
+    /// drops of the latest validated ledger.
+    pub fn required_reserve(&self, owner_count: u32) -> XRPLModelResult<XRPAmount<'static>> {
+        let validated_ledger = self
+            .state
+            .validated_ledger
+            .as_ref()
+            .ok_or_else(|| XRPLModelException::MissingField("validated_ledger".to_string()))?;
+        let reserve_base: BigDecimal = validated_ledger.reserve_base.clone().try_into()?;
+        let reserve_inc: BigDecimal = validated_ledger.reserve_inc.clone().try_into()?;
+
+        Ok((reserve_base + reserve_inc * BigDecimal::from(owner_count)).into())
+    }
+}
+
 impl<'a> TryFrom<XRPLResult<'a>> for ServerState<'a> {
     type Error = XRPLModelException;
 
@@ -46,3 +64,37 @@ impl<'a> TryFrom<XRPLResult<'a>> for ServerState<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_required_reserve() {
+        let server_state: ServerState = serde_json::from_value(json!({
+            "state": {
+                "build_version": "2.2.0",
+                "validated_ledger": {
+                    "base_fee": 10,
+                    "close_time": 638329271,
+                    "hash": "687F604EF6B2F67319E8DCC8C66EF49D84D18A1E18F948421FC24D2C7C3DB464",
+                    "reserve_base": 1000000,
+                    "reserve_inc": 200000,
+                    "seq": 62964740
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            server_state.required_reserve(3).unwrap(),
+            XRPAmount::from("1600000")
+        );
+        assert_eq!(
+            server_state.required_reserve(0).unwrap(),
+            XRPAmount::from("1000000")
+        );
+    }
+}
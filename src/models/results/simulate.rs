@@ -0,0 +1,60 @@
+use core::convert::TryFrom;
+
+use alloc::{borrow::Cow, string::ToString};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::{
+    results::exceptions::XRPLResultException, XRPLModelException, XRPLModelResult,
+};
+
+use super::XRPLResult;
+
+/// The result of a `simulate` request.
+///
+/// See Simulate:
+/// `<https://xrpl.org/docs/references/http-websocket-apis/public-api-methods/transaction-methods/simulate>`
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Simulate<'a> {
+    /// The result code the transaction would have had.
+    pub engine_result: Cow<'a, str>,
+    pub engine_result_code: i32,
+    pub engine_result_message: Cow<'a, str>,
+    /// Always false, since the transaction was not submitted.
+    pub applied: bool,
+    /// The ledger index of the ledger the transaction was simulated in.
+    pub ledger_index: u32,
+    /// The metadata the transaction would have had, in JSON format.
+    pub meta: Option<Value>,
+    /// The metadata in binary format, if `binary` was requested.
+    pub meta_blob: Option<Cow<'a, str>>,
+    /// The autofilled transaction, in JSON format.
+    pub tx_json: Option<Value>,
+    /// The autofilled transaction in binary format, if `binary`
+    /// was requested.
+    pub tx_blob: Option<Cow<'a, str>>,
+}
+
+impl<'a> Simulate<'a> {
+    /// The transaction would have been applied (`tesSUCCESS`).
+    pub fn is_success(&self) -> bool {
+        self.engine_result == "tesSUCCESS"
+    }
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for Simulate<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::Simulate(simulate) => Ok(simulate),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "Simulate".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
@@ -42,3 +42,96 @@ impl<'a> TryFrom<XRPLResult<'a>> for Submit<'a> {
         }
     }
 }
+
+impl<'a> Submit<'a> {
+    /// The transaction was applied (`tesSUCCESS`). It is not final
+    /// until it appears in a validated ledger.
+    pub fn is_success(&self) -> bool {
+        self.engine_result == "tesSUCCESS"
+    }
+
+    /// The transaction was held in the queue (`terQUEUED`) to be
+    /// applied to a later ledger.
+    pub fn is_queued(&self) -> bool {
+        self.engine_result == "terQUEUED" || self.queued == Some(true)
+    }
+
+    /// The transaction failed but was applied to claim the fee (`tec*`).
+    pub fn is_claimed_fee(&self) -> bool {
+        self.engine_result.starts_with("tec")
+    }
+
+    /// The transaction could not be applied yet but may succeed if
+    /// submitted again later (`ter*`, except `terQUEUED`).
+    pub fn is_retryable(&self) -> bool {
+        self.engine_result.starts_with("ter") && !self.is_queued()
+    }
+
+    /// The transaction can never succeed as submitted (`tef*` or `tem*`).
+    pub fn is_failure(&self) -> bool {
+        self.engine_result.starts_with("tef") || self.engine_result.starts_with("tem")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::borrow::Cow;
+    use serde_json::Value;
+
+    use super::Submit;
+
+    fn submit_result(engine_result: &str) -> Submit<'_> {
+        Submit {
+            engine_result: Cow::from(engine_result),
+            engine_result_code: 0,
+            engine_result_message: Cow::from(""),
+            tx_blob: Cow::from(""),
+            tx_json: Value::Null,
+            accepted: None,
+            account_sequence_available: None,
+            account_sequence_next: None,
+            applied: None,
+            broadcast: None,
+            kept: None,
+            queued: None,
+            open_ledger_cost: None,
+            validated_ledger_index: None,
+        }
+    }
+
+    /// Returns the classification flags in the order
+    /// success, queued, claimed fee, retryable, failure.
+    fn classify(engine_result: &str) -> [bool; 5] {
+        let result = submit_result(engine_result);
+
+        [
+            result.is_success(),
+            result.is_queued(),
+            result.is_claimed_fee(),
+            result.is_retryable(),
+            result.is_failure(),
+        ]
+    }
+
+    #[test]
+    fn test_classify_engine_result() {
+        assert_eq!(classify("tesSUCCESS"), [true, false, false, false, false]);
+        assert_eq!(classify("terQUEUED"), [false, true, false, false, false]);
+        assert_eq!(classify("tecPATH_DRY"), [false, false, true, false, false]);
+        assert_eq!(
+            classify("tecUNFUNDED_OFFER"),
+            [false, false, true, false, false]
+        );
+        assert_eq!(classify("terPRE_SEQ"), [false, false, false, true, false]);
+        assert_eq!(
+            classify("terNO_ACCOUNT"),
+            [false, false, false, true, false]
+        );
+        assert_eq!(classify("tefPAST_SEQ"), [false, false, false, false, true]);
+        assert_eq!(classify("temBAD_FEE"), [false, false, false, false, true]);
+        assert_eq!(
+            classify("telINSUF_FEE_P"),
+            [false, false, false, false, false]
+        );
+    }
+}
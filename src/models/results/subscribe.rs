@@ -0,0 +1,247 @@
+use core::convert::TryFrom;
+
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::{XRPLModelException, XRPLModelResult};
+
+use super::exceptions::XRPLResultException;
+
+/// The `type` of the messages of the `validations` stream.
+const VALIDATION_RECEIVED: &str = "validationReceived";
+/// The `type` of the server status messages of the `server` stream.
+const SERVER_STATUS: &str = "serverStatus";
+/// The `type` of the messages of the `consensus` stream.
+const CONSENSUS_PHASE: &str = "consensusPhase";
+
+/// A message of the `validations` stream, sent for every validation
+/// vote the server receives, whether or not the validator is trusted.
+///
+/// See Validations Stream:
+/// `<https://xrpl.org/subscribe.html#validations-stream>`
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ValidationMessage<'a> {
+    /// The amendments this server wants to be added to the protocol.
+    pub amendments: Option<Vec<Cow<'a, str>>>,
+    /// The unscaled transaction cost this server wants to set by
+    /// fee voting.
+    pub base_fee: Option<u64>,
+    /// An arbitrary value chosen by the server at startup, which lets
+    /// validators with the same key be told apart.
+    pub cookie: Option<Cow<'a, str>>,
+    /// The raw validation message in hex, if the server sends it.
+    pub data: Option<Cow<'a, str>>,
+    /// Bit-map of flags included with this validation message.
+    pub flags: u32,
+    /// Whether this is a full validation, rather than a partial one
+    /// that does not vote for a specific ledger version.
+    pub full: bool,
+    /// The identifying hash of the proposed ledger being validated.
+    pub ledger_hash: Cow<'a, str>,
+    /// The ledger index of the proposed ledger, as a string.
+    pub ledger_index: Cow<'a, str>,
+    /// The local load-scaled transaction cost this validator is
+    /// currently enforcing, in fee units.
+    pub load_fee: Option<u32>,
+    /// The validator's master public key, if it uses an ephemeral
+    /// signing key.
+    pub master_key: Option<Cow<'a, str>>,
+    /// The network this validation belongs to.
+    pub network_id: Option<u32>,
+    /// The minimum reserve requirement this validator wants to set by
+    /// fee voting.
+    pub reserve_base: Option<u64>,
+    /// The increment in the reserve requirement this validator wants
+    /// to set by fee voting.
+    pub reserve_inc: Option<u64>,
+    /// The rippled version of the validator, encoded as an integer.
+    pub server_version: Option<Cow<'a, str>>,
+    /// The signature that the validator used to sign its vote.
+    pub signature: Cow<'a, str>,
+    /// When this validation vote was signed, in seconds since the
+    /// Ripple Epoch.
+    pub signing_time: u32,
+    /// The hash of the latest ledger the validator considers
+    /// fully validated.
+    pub validated_hash: Option<Cow<'a, str>>,
+    /// The public key this validator used to sign the validation,
+    /// in base58.
+    pub validation_public_key: Cow<'a, str>,
+}
+
+impl<'a> TryFrom<Value> for ValidationMessage<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(value: Value) -> XRPLModelResult<Self> {
+        from_stream_message(value, VALIDATION_RECEIVED)
+    }
+}
+
+/// A message of the `server` stream, sent whenever the status of the
+/// server or its load changes.
+///
+/// See Server Stream:
+/// `<https://xrpl.org/subscribe.html#server-stream>`
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ServerStatusMessage<'a> {
+    /// The minimum transaction cost for a reference transaction,
+    /// in drops, before load scaling.
+    pub base_fee: Option<u64>,
+    /// The baseline amount of server load used in transaction cost
+    /// calculations.
+    pub load_base: u64,
+    /// The load factor the server is currently enforcing, which the
+    /// transaction cost is multiplied by.
+    pub load_factor: u64,
+    /// The load factor from fee escalation in the open ledger.
+    pub load_factor_fee_escalation: Option<u64>,
+    /// The load factor needed to get into the transaction queue.
+    pub load_factor_fee_queue: Option<u64>,
+    /// The load factor of a reference transaction without escalation.
+    pub load_factor_fee_reference: Option<u64>,
+    /// The load factor from the server's own load and the network.
+    pub load_factor_server: Option<u64>,
+    /// The state of the server, e.g. `full` or `proposing`.
+    pub server_status: Cow<'a, str>,
+}
+
+impl<'a> TryFrom<Value> for ServerStatusMessage<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(value: Value) -> XRPLModelResult<Self> {
+        from_stream_message(value, SERVER_STATUS)
+    }
+}
+
+/// A phase of the consensus process.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ConsensusPhase {
+    /// Transactions are collected into the open ledger.
+    Open,
+    /// Validators are agreeing on the set of transactions.
+    Establish,
+    /// The agreed transaction set is applied to build the ledger.
+    Accepted,
+}
+
+/// A message of the `consensus` stream, sent whenever the server
+/// moves to another phase of the consensus process.
+///
+/// See Consensus Stream:
+/// `<https://xrpl.org/subscribe.html#consensus-stream>`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ConsensusPhaseMessage {
+    /// The new consensus phase the server is in.
+    pub consensus: ConsensusPhase,
+}
+
+impl TryFrom<Value> for ConsensusPhaseMessage {
+    type Error = XRPLModelException;
+
+    fn try_from(value: Value) -> XRPLModelResult<Self> {
+        from_stream_message(value, CONSENSUS_PHASE)
+    }
+}
+
+/// Deserializes a stream message after checking its `type`.
+fn from_stream_message<T>(value: Value, expected_type: &str) -> XRPLModelResult<T>
+where
+    T: DeserializeOwned,
+{
+    match value.get("type").and_then(Value::as_str) {
+        Some(message_type) if message_type == expected_type => Ok(serde_json::from_value(value)?),
+        message_type => Err(XRPLResultException::UnexpectedResultType(
+            expected_type.to_string(),
+            message_type.map(String::from).unwrap_or_default(),
+        )
+        .into()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_validation_message() {
+        let message = json!({
+            "type": "validationReceived",
+            "cookie": "18432563289542396736",
+            "flags": 2147483649u32,
+            "full": true,
+            "ledger_hash": "EAB4AED3B5C3ED5D0C8D4D5D5E5E4AF9B38B2D4FEE8A3F6A92C6E3D9C1D25A7B",
+            "ledger_index": "6",
+            "load_fee": 256000,
+            "master_key": "nHUon2tpyJEHHYGmxqeGu37cvPYHzrMtUNQFVdCgGNvEkjmCpTqK",
+            "network_id": 1,
+            "server_version": "1745990418797494272",
+            "signature": "3045022100E199B55643F66BC6B37DBC5E185321CF952FD35D13D9E8001EB2564FFB94A07602201746C9A4F7A93647131A2DEB03B76F05E426EC67A5A27D77F4FF2603B9A528E6",
+            "signing_time": 515115322,
+            "validated_hash": "01CBE6A9A6C4AD6F74CE1EE5DDB25BCC8FBF02F5E2A6B6C5EDC8C37BBAF6B2B0",
+            "validation_public_key": "n94Gnc6svmaPPRHUAyyib1gQUov8sYbjLoEwUBYPH39qHZXuo8ZT"
+        });
+        let validation = ValidationMessage::try_from(message.clone()).unwrap();
+
+        assert_eq!(validation.ledger_index, "6");
+        assert_eq!(validation.signing_time, 515115322);
+        assert_eq!(validation.flags, 0x80000001);
+        assert!(validation.full);
+        assert_eq!(validation.cookie.as_deref(), Some("18432563289542396736"));
+        assert_eq!(
+            validation.validation_public_key,
+            "n94Gnc6svmaPPRHUAyyib1gQUov8sYbjLoEwUBYPH39qHZXuo8ZT"
+        );
+        assert_eq!(validation.amendments, None);
+
+        let mut ledger_closed = message;
+        ledger_closed["type"] = "ledgerClosed".into();
+        assert!(ValidationMessage::try_from(ledger_closed).is_err());
+    }
+
+    #[test]
+    fn test_server_status_message() {
+        let status = ServerStatusMessage::try_from(json!({
+            "type": "serverStatus",
+            "base_fee": 10,
+            "load_base": 256,
+            "load_factor": 256,
+            "load_factor_fee_escalation": 256,
+            "load_factor_fee_queue": 256,
+            "load_factor_fee_reference": 256,
+            "load_factor_server": 256,
+            "server_status": "full"
+        }))
+        .unwrap();
+
+        assert_eq!(status.server_status, "full");
+        assert_eq!(status.load_factor, 256);
+        assert_eq!(status.base_fee, Some(10));
+    }
+
+    #[test]
+    fn test_consensus_phase_message() {
+        let message = ConsensusPhaseMessage::try_from(json!({
+            "type": "consensusPhase",
+            "consensus": "accepted"
+        }))
+        .unwrap();
+
+        assert_eq!(message.consensus, ConsensusPhase::Accepted);
+        assert!(ConsensusPhaseMessage::try_from(json!({
+            "type": "serverStatus",
+            "consensus": "accepted"
+        }))
+        .is_err());
+    }
+}
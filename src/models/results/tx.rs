@@ -4,8 +4,12 @@ use alloc::{borrow::Cow, string::ToString};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::models::{
-    results::exceptions::XRPLResultException, XRPLModelException, XRPLModelResult,
+use crate::{
+    core::binarycodec::decode,
+    models::{
+        results::exceptions::XRPLResultException,
+        transactions::exceptions::XRPLTransactionException, XRPLModelException, XRPLModelResult,
+    },
 };
 
 use super::XRPLResult;
@@ -16,6 +20,9 @@ pub struct Tx<'a> {
     pub date: u32,
     pub hash: Cow<'a, str>,
     pub ledger_index: u32,
+    /// The transaction metadata, or the metadata blob (`meta_blob`) of
+    /// a binary response.
+    #[serde(alias = "meta_blob")]
     pub meta: Value,
     /// Various fields of the transaction
     #[serde(flatten)]
@@ -26,6 +33,37 @@ pub struct Tx<'a> {
     pub in_ledger: Option<u32>,
 }
 
+impl<'a> Tx<'a> {
+    /// Decodes the response to a `tx` request made with `binary: true`
+    /// into the JSON form. The transaction blob (`tx_blob`, or `tx` in
+    /// API v1) becomes the transaction fields and the metadata blob
+    /// (`meta_blob`, or `meta` in API v1) becomes `meta`. Responses in
+    /// JSON form are returned unchanged.
+    pub fn decode_binary(mut self) -> XRPLModelResult<Self> {
+        if let Value::Object(various) = &mut self.various {
+            let blob = match various.remove("tx_blob") {
+                Some(blob) => Some(blob),
+                None if various.get("tx").is_some_and(Value::is_string) => various.remove("tx"),
+                None => None,
+            };
+            if let Some(Value::String(blob)) = blob {
+                if let Value::Object(fields) = decode_blob(&blob)? {
+                    various.extend(fields);
+                }
+            }
+        }
+        if let Value::String(meta) = &self.meta {
+            self.meta = decode_blob(meta)?;
+        }
+
+        Ok(self)
+    }
+}
+
+fn decode_blob(blob: &str) -> XRPLModelResult<Value> {
+    Ok(decode(blob).map_err(XRPLTransactionException::XRPLCoreError)?)
+}
+
 impl<'a> TryFrom<XRPLResult<'a>> for Tx<'a> {
     type Error = XRPLModelException;
 
@@ -38,3 +76,77 @@ impl<'a> TryFrom<XRPLResult<'a>> for Tx<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use alloc::string::String;
+    use serde_json::json;
+
+    use super::Tx;
+    use crate::core::binarycodec::encode;
+
+    const ACCOUNT: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+
+    fn blobs() -> (serde_json::Value, serde_json::Value, String, String) {
+        let transaction = json!({
+            "Account": ACCOUNT,
+            "Fee": "12",
+            "Flags": 0,
+            "Sequence": 5,
+            "SigningPubKey": "ED01FA53FA5A7E77798F882ECE20B1ABC00BB358A9E55A202D0D0676BD0CE37A63",
+            "TransactionType": "AccountSet"
+        });
+        let meta = json!({
+            "AffectedNodes": [],
+            "TransactionIndex": 0,
+            "TransactionResult": "tesSUCCESS"
+        });
+        let tx_blob = encode(&transaction).unwrap();
+        let meta_blob = encode(&meta).unwrap();
+
+        (transaction, meta, tx_blob, meta_blob)
+    }
+
+    #[test]
+    fn test_decode_binary() {
+        let (transaction, meta, tx_blob, meta_blob) = blobs();
+        let tx: Tx = serde_json::from_value(json!({
+            "ctid": "C005523E00000000",
+            "date": 752629050,
+            "hash": "1E1DC2E5C0D4D8D4F4A1D8F0E3A4AB0F36E9C2D3C2B7DE80F8E53B04E1C2B1A0",
+            "ledger_index": 349758,
+            "meta_blob": meta_blob,
+            "tx_blob": tx_blob,
+            "validated": true
+        }))
+        .unwrap();
+        let tx = tx.decode_binary().unwrap();
+
+        assert_eq!(tx.various["Account"], ACCOUNT);
+        assert_eq!(tx.various["TransactionType"], "AccountSet");
+        assert_eq!(tx.various["Sequence"], transaction["Sequence"]);
+        assert!(tx.various.get("tx_blob").is_none());
+        assert_eq!(tx.meta, meta);
+        assert_eq!(tx.ledger_index, 349758);
+    }
+
+    #[test]
+    fn test_decode_binary_api_v1() {
+        let (_, meta, tx_blob, meta_blob) = blobs();
+        let tx: Tx = serde_json::from_value(json!({
+            "ctid": "C005523E00000000",
+            "date": 752629050,
+            "hash": "1E1DC2E5C0D4D8D4F4A1D8F0E3A4AB0F36E9C2D3C2B7DE80F8E53B04E1C2B1A0",
+            "ledger_index": 349758,
+            "meta": meta_blob,
+            "tx": tx_blob
+        }))
+        .unwrap();
+        let tx = tx.decode_binary().unwrap();
+
+        assert_eq!(tx.various["Account"], ACCOUNT);
+        assert_eq!(tx.meta, meta);
+        // Decoding a response in JSON form changes nothing.
+        assert_eq!(tx.clone().decode_binary().unwrap(), tx);
+    }
+}
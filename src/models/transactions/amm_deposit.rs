@@ -1,4 +1,5 @@
 use alloc::{borrow::Cow, vec::Vec};
+use bigdecimal::{BigDecimal, Signed};
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use serde_with::skip_serializing_none;
@@ -9,7 +10,7 @@ use crate::models::{
     XRPAmount, XRPLModelException, XRPLModelResult,
 };
 
-use super::{CommonFields, Memo, Signer, Transaction};
+use super::{amm_create::AMM_CREATE_MAX_FEE, CommonFields, Memo, Signer, Transaction};
 
 /// Transactions of the AMMDeposit type support additional values in the Flags field.
 /// This enum represents those options.
@@ -141,6 +142,143 @@ impl<'a> AMMDeposit<'a> {
     }
 }
 
+/// Returns the amounts of both pool assets required to receive
+/// `lp_tokens_out` LP Tokens with a two-asset (`tfLPToken`) deposit.
+///
+/// Proportional deposits are not charged the trading fee. The results
+/// are exact; the ledger rounds deposited amounts up.
+pub fn get_two_asset_deposit(
+    amount_balance: &BigDecimal,
+    amount2_balance: &BigDecimal,
+    lp_token_balance: &BigDecimal,
+    lp_tokens_out: &BigDecimal,
+) -> XRPLModelResult<(BigDecimal, BigDecimal)> {
+    get_non_positive_error("amount_balance", amount_balance)?;
+    get_non_positive_error("amount2_balance", amount2_balance)?;
+    get_non_positive_error("lp_token_balance", lp_token_balance)?;
+    let fraction = lp_tokens_out / lp_token_balance;
+
+    Ok((amount_balance * &fraction, amount2_balance * &fraction))
+}
+
+/// Returns the amount of a single pool asset required to receive
+/// `lp_tokens_out` LP Tokens with a single-asset (`tfOneAssetLPToken`)
+/// deposit, given the pool's `trading_fee` in units of 1/100,000.
+///
+/// Mirrors rippled's `ammAssetIn`. The result is exact; the ledger
+/// rounds deposited amounts up.
+pub fn get_single_asset_deposit(
+    asset_balance: &BigDecimal,
+    lp_token_balance: &BigDecimal,
+    lp_tokens_out: &BigDecimal,
+    trading_fee: u16,
+) -> XRPLModelResult<BigDecimal> {
+    get_non_positive_error("asset_balance", asset_balance)?;
+    get_non_positive_error("lp_token_balance", lp_token_balance)?;
+    get_non_positive_error("lp_tokens_out", lp_tokens_out)?;
+    let fee = get_trading_fee(trading_fee)?;
+    let one = BigDecimal::from(1);
+    let f1 = &one - &fee;
+    let f2 = (&one - fee.half()) / &f1;
+    let t1 = lp_tokens_out / lp_token_balance;
+    let t2 = &one + &t1;
+    let d = &f2 - &t1 / &t2;
+    let a = &one / (&t2 * &t2);
+    let b = BigDecimal::from(2) * &d / &t2 - &one / &f1;
+    let c = &d * &d - &f2 * &f2;
+    // The positive root of `a * x^2 + b * x + c = 0`.
+    let discriminant = (&b * &b - BigDecimal::from(4) * &a * &c)
+        .sqrt()
+        .ok_or(XRPLModelException::ValueZero("lp_tokens_out".into()))?;
+
+    Ok(asset_balance * ((discriminant - b) / (BigDecimal::from(2) * a)))
+}
+
+/// Rejects empty pools and non-positive LP Token amounts, which the
+/// AMM math is undefined for.
+pub(crate) fn get_non_positive_error(field: &str, balance: &BigDecimal) -> XRPLModelResult<()> {
+    if balance.is_positive() {
+        Ok(())
+    } else if balance.is_negative() {
+        Err(XRPLModelException::ValueNegative(field.into()))
+    } else {
+        Err(XRPLModelException::ValueZero(field.into()))
+    }
+}
+
+/// Converts a trading fee in units of 1/100,000 into a fraction.
+pub(crate) fn get_trading_fee(trading_fee: u16) -> XRPLModelResult<BigDecimal> {
+    if trading_fee > AMM_CREATE_MAX_FEE {
+        Err(XRPLModelException::ValueTooHigh {
+            field: "trading_fee".into(),
+            max: AMM_CREATE_MAX_FEE.into(),
+            found: trading_fee.into(),
+        })
+    } else {
+        Ok(BigDecimal::from(trading_fee) / BigDecimal::from(100_000))
+    }
+}
+
+#[cfg(test)]
+mod test_math {
+    use core::str::FromStr;
+
+    use super::*;
+
+    fn decimal(value: &str) -> BigDecimal {
+        BigDecimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn test_two_asset_deposit() {
+        let (amount, amount2) = get_two_asset_deposit(
+            &decimal("10000"),
+            &decimal("2500"),
+            &decimal("5000"),
+            &decimal("500"),
+        )
+        .unwrap();
+
+        assert_eq!(amount, decimal("1000"));
+        assert_eq!(amount2, decimal("250"));
+    }
+
+    #[test]
+    fn test_single_asset_deposit() {
+        // Without a fee the pool stays on its constant product curve:
+        // 10000 * (1.1^2 - 1).
+        let amount =
+            get_single_asset_deposit(&decimal("10000"), &decimal("5000"), &decimal("500"), 0)
+                .unwrap();
+        assert_eq!(amount.round(20), decimal("2100"));
+
+        // A 0.5% trading fee is charged on the non-proportional part.
+        let amount =
+            get_single_asset_deposit(&decimal("10000"), &decimal("5000"), &decimal("500"), 500)
+                .unwrap();
+        assert_eq!(amount.round(20), decimal("2105.52763819095477386935"));
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        assert_eq!(
+            get_single_asset_deposit(&decimal("10000"), &decimal("0"), &decimal("500"), 0),
+            Err(XRPLModelException::ValueZero("lp_token_balance".into()))
+        );
+        assert_eq!(
+            get_single_asset_deposit(&decimal("10000"), &decimal("5000"), &decimal("-500"), 0),
+            Err(XRPLModelException::ValueNegative("lp_tokens_out".into()))
+        );
+        assert!(get_single_asset_deposit(
+            &decimal("10000"),
+            &decimal("5000"),
+            &decimal("500"),
+            1001
+        )
+        .is_err());
+    }
+}
+
 #[cfg(test)]
 mod test_errors {
     use crate::models::{IssuedCurrency, XRP};
@@ -1,12 +1,18 @@
 use alloc::{borrow::Cow, vec::Vec};
+use bigdecimal::BigDecimal;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use serde_with::skip_serializing_none;
 use strum_macros::{AsRefStr, Display, EnumIter};
 
-use crate::models::{Amount, Currency, FlagCollection, IssuedCurrencyAmount, Model, XRPAmount};
+use crate::models::{
+    Amount, Currency, FlagCollection, IssuedCurrencyAmount, Model, XRPAmount, XRPLModelResult,
+};
 
-use super::{CommonFields, Memo, Signer, Transaction, TransactionType};
+use super::{
+    amm_deposit::{get_non_positive_error, get_trading_fee},
+    CommonFields, Memo, Signer, Transaction, TransactionType,
+};
 
 /// Transactions of the AMMWithdraw type support additional values in the Flags field.
 /// This enum represents those options.
@@ -128,3 +134,86 @@ impl<'a> AMMWithdraw<'a> {
         }
     }
 }
+
+/// Returns the amounts of both pool assets received for redeeming
+/// `lp_tokens_in` LP Tokens with a two-asset (`tfLPToken`) withdrawal.
+///
+/// Proportional withdrawals are not charged the trading fee. The results
+/// are exact; the ledger rounds withdrawn amounts down.
+pub fn get_two_asset_withdrawal(
+    amount_balance: &BigDecimal,
+    amount2_balance: &BigDecimal,
+    lp_token_balance: &BigDecimal,
+    lp_tokens_in: &BigDecimal,
+) -> XRPLModelResult<(BigDecimal, BigDecimal)> {
+    get_non_positive_error("amount_balance", amount_balance)?;
+    get_non_positive_error("amount2_balance", amount2_balance)?;
+    get_non_positive_error("lp_token_balance", lp_token_balance)?;
+    let fraction = lp_tokens_in / lp_token_balance;
+
+    Ok((amount_balance * &fraction, amount2_balance * &fraction))
+}
+
+/// Returns the amount of a single pool asset received for redeeming
+/// `lp_tokens_in` LP Tokens with a single-asset (`tfOneAssetLPToken`)
+/// withdrawal, given the pool's `trading_fee` in units of 1/100,000.
+///
+/// Mirrors rippled's `ammAssetOut`. The result is exact; the ledger
+/// rounds withdrawn amounts down.
+pub fn get_single_asset_withdrawal(
+    asset_balance: &BigDecimal,
+    lp_token_balance: &BigDecimal,
+    lp_tokens_in: &BigDecimal,
+    trading_fee: u16,
+) -> XRPLModelResult<BigDecimal> {
+    get_non_positive_error("asset_balance", asset_balance)?;
+    get_non_positive_error("lp_token_balance", lp_token_balance)?;
+    get_non_positive_error("lp_tokens_in", lp_tokens_in)?;
+    let fee = get_trading_fee(trading_fee)?;
+    let t1 = lp_tokens_in / lp_token_balance;
+    let numerator = &t1 * &t1 - &t1 * (BigDecimal::from(2) - &fee);
+    let denominator = &t1 * &fee - BigDecimal::from(1);
+
+    Ok(asset_balance * (numerator / denominator))
+}
+
+#[cfg(test)]
+mod test_math {
+    use core::str::FromStr;
+
+    use super::*;
+
+    fn decimal(value: &str) -> BigDecimal {
+        BigDecimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn test_two_asset_withdrawal() {
+        let (amount, amount2) = get_two_asset_withdrawal(
+            &decimal("10000"),
+            &decimal("2500"),
+            &decimal("5000"),
+            &decimal("500"),
+        )
+        .unwrap();
+
+        assert_eq!(amount, decimal("1000"));
+        assert_eq!(amount2, decimal("250"));
+    }
+
+    #[test]
+    fn test_single_asset_withdrawal() {
+        // Without a fee the pool stays on its constant product curve:
+        // 10000 * (1 - 0.9^2).
+        let amount =
+            get_single_asset_withdrawal(&decimal("10000"), &decimal("5000"), &decimal("500"), 0)
+                .unwrap();
+        assert_eq!(amount.round(20), decimal("1900"));
+
+        // A 0.5% trading fee is charged on the non-proportional part.
+        let amount =
+            get_single_asset_withdrawal(&decimal("10000"), &decimal("5000"), &decimal("500"), 500)
+                .unwrap();
+        assert_eq!(amount.round(20), decimal("1895.94797398699349674837"));
+    }
+}
@@ -45,7 +45,7 @@ pub struct CheckCash<'a> {
     pub deliver_min: Option<Amount<'a>>,
 }
 
-impl<'a: 'static> Model for CheckCash<'a> {
+impl<'a> Model for CheckCash<'a> {
     fn get_errors(&self) -> XRPLModelResult<()> {
         self._get_amount_and_deliver_min_error()?;
 
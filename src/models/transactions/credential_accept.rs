@@ -0,0 +1,127 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::{
+    amount::XRPAmount,
+    transactions::{Memo, Signer, Transaction, TransactionType},
+    FlagCollection, Model, NoFlags, XRPLModelResult,
+};
+
+use super::{credential_create::get_credential_type_error, CommonFields};
+
+/// Accepts a credential, which makes the credential valid. Only the
+/// subject of the credential can accept it.
+///
+/// See CredentialAccept:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/credentialaccept>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CredentialAccept<'a> {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields<'a, NoFlags>,
+    // The custom fields for the CredentialAccept model.
+    //
+    // See CredentialAccept fields:
+    // `<https://xrpl.org/docs/references/protocol/transactions/types/credentialaccept#credentialaccept-fields>`
+    /// The address of the issuer that created the credential.
+    pub issuer: Cow<'a, str>,
+    /// Arbitrary data defining the type of credential, as hex.
+    pub credential_type: Cow<'a, str>,
+}
+
+impl<'a> Model for CredentialAccept<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        get_credential_type_error(&self.credential_type)
+    }
+}
+
+impl<'a> Transaction<'a, NoFlags> for CredentialAccept<'a> {
+    fn get_transaction_type(&self) -> TransactionType {
+        self.common_fields.get_transaction_type()
+    }
+
+    fn get_common_fields(&self) -> &CommonFields<'_, NoFlags> {
+        self.common_fields.get_common_fields()
+    }
+
+    fn get_mut_common_fields(&mut self) -> &mut CommonFields<'a, NoFlags> {
+        self.common_fields.get_mut_common_fields()
+    }
+}
+
+impl<'a> CredentialAccept<'a> {
+    pub fn new(
+        account: Cow<'a, str>,
+        account_txn_id: Option<Cow<'a, str>>,
+        fee: Option<XRPAmount<'a>>,
+        last_ledger_sequence: Option<u32>,
+        memos: Option<Vec<Memo>>,
+        sequence: Option<u32>,
+        signers: Option<Vec<Signer<'a>>>,
+        source_tag: Option<u32>,
+        ticket_sequence: Option<u32>,
+        issuer: Cow<'a, str>,
+        credential_type: Cow<'a, str>,
+    ) -> Self {
+        Self {
+            common_fields: CommonFields {
+                account,
+                transaction_type: TransactionType::CredentialAccept,
+                account_txn_id,
+                fee,
+                flags: FlagCollection::default(),
+                last_ledger_sequence,
+                memos,
+                sequence,
+                signers,
+                source_tag,
+                ticket_sequence,
+                network_id: None,
+                signing_pub_key: None,
+                txn_signature: None,
+            },
+            issuer,
+            credential_type,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde() {
+        let default_txn = CredentialAccept::new(
+            "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".into(),
+            None,
+            Some("10".into()),
+            None,
+            None,
+            Some(234534),
+            None,
+            None,
+            None,
+            "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+            "6D795F63726564656E7469616C".into(),
+        );
+        let default_json_str = r#"{"Account":"rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW","TransactionType":"CredentialAccept","Fee":"10","Flags":0,"Sequence":234534,"Issuer":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","CredentialType":"6D795F63726564656E7469616C"}"#;
+        // Serialize
+        let default_json_value = serde_json::to_value(default_json_str).unwrap();
+        let serialized_string = serde_json::to_string(&default_txn).unwrap();
+        let serialized_value = serde_json::to_value(&serialized_string).unwrap();
+        assert_eq!(serialized_value, default_json_value);
+
+        // Deserialize
+        let deserialized: CredentialAccept = serde_json::from_str(default_json_str).unwrap();
+        assert_eq!(default_txn, deserialized);
+        assert!(deserialized.validate().is_ok());
+    }
+}
@@ -0,0 +1,205 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::{
+    constants::MAX_CREDENTIAL_TYPE_LENGTH,
+    models::{
+        amount::XRPAmount,
+        transactions::{Memo, Signer, Transaction, TransactionType},
+        FlagCollection, Model, NoFlags, XRPLModelException, XRPLModelResult,
+    },
+};
+
+use super::CommonFields;
+
+/// Creates a Credential object. It must be sent by the issuer.
+///
+/// See CredentialCreate:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/credentialcreate>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CredentialCreate<'a> {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields<'a, NoFlags>,
+    // The custom fields for the CredentialCreate model.
+    //
+    // See CredentialCreate fields:
+    // `<https://xrpl.org/docs/references/protocol/transactions/types/credentialcreate#credentialcreate-fields>`
+    /// The subject of the credential.
+    pub subject: Cow<'a, str>,
+    /// Arbitrary data defining the type of credential this entry represents,
+    /// as hex. The minimum length is 1 byte and the maximum length is 64 bytes.
+    pub credential_type: Cow<'a, str>,
+    /// Time after which the credential is expired, in seconds since the Ripple Epoch.
+    pub expiration: Option<u32>,
+    /// Arbitrary additional data about the credential, such as the URL where
+    /// users can look up an associated Verifiable Credential document, as hex.
+    #[serde(rename = "URI")]
+    pub uri: Option<Cow<'a, str>>,
+}
+
+impl<'a> Model for CredentialCreate<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        get_credential_type_error(&self.credential_type)
+    }
+}
+
+impl<'a> Transaction<'a, NoFlags> for CredentialCreate<'a> {
+    fn get_transaction_type(&self) -> TransactionType {
+        self.common_fields.get_transaction_type()
+    }
+
+    fn get_common_fields(&self) -> &CommonFields<'_, NoFlags> {
+        self.common_fields.get_common_fields()
+    }
+
+    fn get_mut_common_fields(&mut self) -> &mut CommonFields<'a, NoFlags> {
+        self.common_fields.get_mut_common_fields()
+    }
+}
+
+impl<'a> CredentialCreate<'a> {
+    pub fn new(
+        account: Cow<'a, str>,
+        account_txn_id: Option<Cow<'a, str>>,
+        fee: Option<XRPAmount<'a>>,
+        last_ledger_sequence: Option<u32>,
+        memos: Option<Vec<Memo>>,
+        sequence: Option<u32>,
+        signers: Option<Vec<Signer<'a>>>,
+        source_tag: Option<u32>,
+        ticket_sequence: Option<u32>,
+        subject: Cow<'a, str>,
+        credential_type: Cow<'a, str>,
+        expiration: Option<u32>,
+        uri: Option<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            common_fields: CommonFields {
+                account,
+                transaction_type: TransactionType::CredentialCreate,
+                account_txn_id,
+                fee,
+                flags: FlagCollection::default(),
+                last_ledger_sequence,
+                memos,
+                sequence,
+                signers,
+                source_tag,
+                ticket_sequence,
+                network_id: None,
+                signing_pub_key: None,
+                txn_signature: None,
+            },
+            subject,
+            credential_type,
+            expiration,
+            uri,
+        }
+    }
+}
+
+/// Validates a `CredentialType` value shared by all Credential transactions:
+/// it must be non-empty, hex encoded and at most 64 bytes long.
+pub(crate) fn get_credential_type_error(credential_type: &str) -> XRPLModelResult<()> {
+    if credential_type.is_empty() {
+        Err(XRPLModelException::ValueTooShort {
+            field: "credential_type".into(),
+            min: 1,
+            found: 0,
+        })
+    } else if credential_type.len() > MAX_CREDENTIAL_TYPE_LENGTH {
+        Err(XRPLModelException::ValueTooLong {
+            field: "credential_type".into(),
+            max: MAX_CREDENTIAL_TYPE_LENGTH,
+            found: credential_type.len(),
+        })
+    } else if hex::decode(credential_type).is_err() {
+        Err(XRPLModelException::InvalidValueFormat {
+            field: "credential_type".into(),
+            format: "hex".into(),
+            found: credential_type.into(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde() {
+        let default_txn = CredentialCreate::new(
+            "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+            None,
+            Some("10".into()),
+            None,
+            None,
+            Some(234203),
+            None,
+            None,
+            None,
+            "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".into(),
+            "6D795F63726564656E7469616C".into(),
+            Some(789004799),
+            Some("isc.example/credentials/my_credential.json".into()),
+        );
+        let default_json_str = r#"{"Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","TransactionType":"CredentialCreate","Fee":"10","Flags":0,"Sequence":234203,"Subject":"rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW","CredentialType":"6D795F63726564656E7469616C","Expiration":789004799,"URI":"isc.example/credentials/my_credential.json"}"#;
+        // Serialize
+        let default_json_value = serde_json::to_value(default_json_str).unwrap();
+        let serialized_string = serde_json::to_string(&default_txn).unwrap();
+        let serialized_value = serde_json::to_value(&serialized_string).unwrap();
+        assert_eq!(serialized_value, default_json_value);
+
+        // Deserialize
+        let deserialized: CredentialCreate = serde_json::from_str(default_json_str).unwrap();
+        assert_eq!(default_txn, deserialized);
+    }
+
+    #[test]
+    fn test_credential_type_error() {
+        let mut txn = CredentialCreate::new(
+            "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".into(),
+            "".into(),
+            None,
+            None,
+        );
+        assert!(matches!(
+            txn.validate(),
+            Err(XRPLModelException::ValueTooShort { .. })
+        ));
+
+        txn.credential_type = "my_credential".into();
+        assert!(matches!(
+            txn.validate(),
+            Err(XRPLModelException::InvalidValueFormat { .. })
+        ));
+
+        txn.credential_type = "AB".repeat(65).into();
+        assert!(matches!(
+            txn.validate(),
+            Err(XRPLModelException::ValueTooLong { .. })
+        ));
+
+        txn.credential_type = "AB".repeat(64).into();
+        assert!(txn.validate().is_ok());
+    }
+}
@@ -0,0 +1,166 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::{
+    amount::XRPAmount,
+    transactions::{Memo, Signer, Transaction, TransactionType},
+    FlagCollection, Model, NoFlags, XRPLModelException, XRPLModelResult,
+};
+
+use super::{credential_create::get_credential_type_error, CommonFields};
+
+/// Deletes a Credential object. The subject or issuer of the credential
+/// can delete it at any time; anyone can delete an expired credential.
+///
+/// See CredentialDelete:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/credentialdelete>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CredentialDelete<'a> {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields<'a, NoFlags>,
+    // The custom fields for the CredentialDelete model.
+    //
+    // See CredentialDelete fields:
+    // `<https://xrpl.org/docs/references/protocol/transactions/types/credentialdelete#credentialdelete-fields>`
+    /// Arbitrary data defining the type of credential to delete, as hex.
+    pub credential_type: Cow<'a, str>,
+    /// The subject of the credential to delete. If omitted, use the `Account`
+    /// from the transaction as the subject.
+    pub subject: Option<Cow<'a, str>>,
+    /// The issuer of the credential to delete. If omitted, use the `Account`
+    /// from the transaction as the issuer.
+    pub issuer: Option<Cow<'a, str>>,
+}
+
+impl<'a> Model for CredentialDelete<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self._get_subject_or_issuer_error()?;
+        get_credential_type_error(&self.credential_type)
+    }
+}
+
+impl<'a> Transaction<'a, NoFlags> for CredentialDelete<'a> {
+    fn get_transaction_type(&self) -> TransactionType {
+        self.common_fields.get_transaction_type()
+    }
+
+    fn get_common_fields(&self) -> &CommonFields<'_, NoFlags> {
+        self.common_fields.get_common_fields()
+    }
+
+    fn get_mut_common_fields(&mut self) -> &mut CommonFields<'a, NoFlags> {
+        self.common_fields.get_mut_common_fields()
+    }
+}
+
+impl<'a> CredentialDelete<'a> {
+    pub fn new(
+        account: Cow<'a, str>,
+        account_txn_id: Option<Cow<'a, str>>,
+        fee: Option<XRPAmount<'a>>,
+        last_ledger_sequence: Option<u32>,
+        memos: Option<Vec<Memo>>,
+        sequence: Option<u32>,
+        signers: Option<Vec<Signer<'a>>>,
+        source_tag: Option<u32>,
+        ticket_sequence: Option<u32>,
+        credential_type: Cow<'a, str>,
+        subject: Option<Cow<'a, str>>,
+        issuer: Option<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            common_fields: CommonFields {
+                account,
+                transaction_type: TransactionType::CredentialDelete,
+                account_txn_id,
+                fee,
+                flags: FlagCollection::default(),
+                last_ledger_sequence,
+                memos,
+                sequence,
+                signers,
+                source_tag,
+                ticket_sequence,
+                network_id: None,
+                signing_pub_key: None,
+                txn_signature: None,
+            },
+            credential_type,
+            subject,
+            issuer,
+        }
+    }
+
+    fn _get_subject_or_issuer_error(&self) -> XRPLModelResult<()> {
+        if self.subject.is_none() && self.issuer.is_none() {
+            Err(XRPLModelException::ExpectedOneOf(&["subject", "issuer"]))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde() {
+        let default_txn = CredentialDelete::new(
+            "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".into(),
+            None,
+            Some("10".into()),
+            None,
+            None,
+            Some(234535),
+            None,
+            None,
+            None,
+            "6D795F63726564656E7469616C".into(),
+            None,
+            Some("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into()),
+        );
+        let default_json_str = r#"{"Account":"rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW","TransactionType":"CredentialDelete","Fee":"10","Flags":0,"Sequence":234535,"CredentialType":"6D795F63726564656E7469616C","Issuer":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"}"#;
+        // Serialize
+        let default_json_value = serde_json::to_value(default_json_str).unwrap();
+        let serialized_string = serde_json::to_string(&default_txn).unwrap();
+        let serialized_value = serde_json::to_value(&serialized_string).unwrap();
+        assert_eq!(serialized_value, default_json_value);
+
+        // Deserialize
+        let deserialized: CredentialDelete = serde_json::from_str(default_json_str).unwrap();
+        assert_eq!(default_txn, deserialized);
+        assert!(deserialized.validate().is_ok());
+    }
+
+    #[test]
+    fn test_subject_or_issuer_error() {
+        let txn = CredentialDelete::new(
+            "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            "6D795F63726564656E7469616C".into(),
+            None,
+            None,
+        );
+
+        assert_eq!(
+            txn.validate(),
+            Err(XRPLModelException::ExpectedOneOf(&["subject", "issuer"]))
+        );
+    }
+}
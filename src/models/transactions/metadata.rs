@@ -0,0 +1,116 @@
+use alloc::{borrow::Cow, vec::Vec};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::Amount;
+
+/// The metadata of a transaction, describing its outcome and how it
+/// changed the ledger.
+///
+/// See Transaction Metadata:
+/// `<https://xrpl.org/transaction-metadata.html>`
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct TransactionMetadata<'a> {
+    /// The ledger objects the transaction created, modified or deleted.
+    pub affected_nodes: Vec<AffectedNode<'a>>,
+    /// The transaction's position within the ledger that included it.
+    pub transaction_index: u32,
+    /// The result code of the transaction, e.g. `tesSUCCESS`.
+    pub transaction_result: Cow<'a, str>,
+    /// The amount delivered by a partial payment, as stored in the
+    /// ledger.
+    pub delivered_amount: Option<Amount<'a>>,
+    /// The amount actually delivered by a payment. The server adds it to
+    /// metadata in JSON form, so it is never part of a metadata blob.
+    #[serde(rename = "delivered_amount")]
+    pub api_delivered_amount: Option<Amount<'a>>,
+}
+
+/// A ledger object affected by a transaction.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AffectedNode<'a> {
+    CreatedNode(CreatedNode<'a>),
+    ModifiedNode(ModifiedNode<'a>),
+    DeletedNode(DeletedNode<'a>),
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreatedNode<'a> {
+    pub ledger_entry_type: Cow<'a, str>,
+    pub ledger_index: Cow<'a, str>,
+    /// The fields of the new ledger object.
+    pub new_fields: Value,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModifiedNode<'a> {
+    pub ledger_entry_type: Cow<'a, str>,
+    pub ledger_index: Cow<'a, str>,
+    /// The fields of the ledger object after the transaction.
+    pub final_fields: Option<Value>,
+    /// The previous values of the fields the transaction changed.
+    pub previous_fields: Option<Value>,
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: Option<Cow<'a, str>>,
+    pub previous_txn_lgr_seq: Option<u32>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeletedNode<'a> {
+    pub ledger_entry_type: Cow<'a, str>,
+    pub ledger_index: Cow<'a, str>,
+    /// The fields of the ledger object before it was deleted.
+    pub final_fields: Option<Value>,
+    /// The previous values of the fields the transaction changed.
+    pub previous_fields: Option<Value>,
+}
+
+impl<'a> AffectedNode<'a> {
+    pub fn ledger_entry_type(&self) -> &str {
+        match self {
+            AffectedNode::CreatedNode(node) => &node.ledger_entry_type,
+            AffectedNode::ModifiedNode(node) => &node.ledger_entry_type,
+            AffectedNode::DeletedNode(node) => &node.ledger_entry_type,
+        }
+    }
+
+    pub fn ledger_index(&self) -> &str {
+        match self {
+            AffectedNode::CreatedNode(node) => &node.ledger_index,
+            AffectedNode::ModifiedNode(node) => &node.ledger_index,
+            AffectedNode::DeletedNode(node) => &node.ledger_index,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_both_delivered_amounts() {
+        let metadata: TransactionMetadata = serde_json::from_value(serde_json::json!({
+            "AffectedNodes": [],
+            "DeliveredAmount": "1000000",
+            "TransactionIndex": 3,
+            "TransactionResult": "tesSUCCESS",
+            "delivered_amount": "1000000"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            metadata.delivered_amount,
+            Some(Amount::XRPAmount("1000000".into()))
+        );
+        assert_eq!(metadata.delivered_amount, metadata.api_delivered_amount);
+    }
+}
@@ -9,11 +9,15 @@ pub mod amm_withdraw;
 pub mod check_cancel;
 pub mod check_cash;
 pub mod check_create;
+pub mod credential_accept;
+pub mod credential_create;
+pub mod credential_delete;
 pub mod deposit_preauth;
 pub mod escrow_cancel;
 pub mod escrow_create;
 pub mod escrow_finish;
 pub mod exceptions;
+pub mod metadata;
 pub mod nftoken_accept_offer;
 pub mod nftoken_burn;
 pub mod nftoken_cancel_offer;
@@ -29,6 +33,7 @@ pub mod pseudo_transactions;
 pub mod set_regular_key;
 pub mod signer_list_set;
 pub mod ticket_create;
+pub mod transaction_result_code;
 pub mod trust_set;
 pub mod xchain_account_create_commit;
 pub mod xchain_add_account_create_attestation;
@@ -40,7 +45,7 @@ pub mod xchain_create_claim_id;
 pub mod xchain_modify_bridge;
 
 use super::{FlagCollection, XRPLModelResult};
-use crate::core::binarycodec::encode;
+use crate::core::binarycodec::{decode, encode};
 use crate::models::amount::XRPAmount;
 use crate::{_serde::txn_flags, serde_with_tag};
 use alloc::borrow::Cow;
@@ -56,12 +61,15 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use sha2::{Digest, Sha512};
 use strum::IntoEnumIterator;
-use strum_macros::{AsRefStr, Display};
+use strum_macros::{AsRefStr, Display, EnumIter, EnumString};
 
 const TRANSACTION_HASH_PREFIX: u32 = 0x54584E00;
 
 /// Enum containing the different Transaction types.
-#[derive(Debug, Clone, Serialize, Deserialize, Display, PartialEq, Eq)]
+///
+/// The `Display` and `FromStr` representations match the canonical
+/// transaction type names used by rippled (e.g. `AMMBid`, `UNLModify`).
+#[derive(Debug, Clone, Serialize, Deserialize, Display, EnumString, EnumIter, PartialEq, Eq)]
 pub enum TransactionType {
     AccountDelete,
     AccountSet,
@@ -74,6 +82,9 @@ pub enum TransactionType {
     CheckCancel,
     CheckCash,
     CheckCreate,
+    CredentialAccept,
+    CredentialCreate,
+    CredentialDelete,
     DepositPreauth,
     EscrowCancel,
     EscrowCreate,
@@ -240,7 +251,7 @@ where
 
 impl<T> CommonFields<'_, T>
 where
-    T: IntoEnumIterator + Serialize + Debug + PartialEq + Clone,
+    T: IntoEnumIterator + Serialize + Debug,
 {
     pub fn is_signed(&self) -> bool {
         if let Some(signers) = &self.signers {
@@ -300,6 +311,52 @@ pub struct Memo {
 }
 }
 
+impl Memo {
+    /// Creates a memo from plain text, hex-encoding each field as
+    /// the ledger expects.
+    pub fn from_text(data: &str, memo_type: Option<&str>, memo_format: Option<&str>) -> Self {
+        Self {
+            memo_data: Some(hex::encode_upper(data)),
+            memo_format: memo_format.map(hex::encode_upper),
+            memo_type: memo_type.map(hex::encode_upper),
+        }
+    }
+
+    /// The bytes of `memo_data`, or `None` if it is missing or not
+    /// valid hex.
+    pub fn decoded_data(&self) -> Option<Vec<u8>> {
+        self.memo_data.as_deref().and_then(decode_memo_field)
+    }
+
+    /// The decoded `memo_type`, usually a URI such as
+    /// `text/plain` or `https://example.com/memo/generic`.
+    pub fn decoded_type(&self) -> Option<String> {
+        self.memo_type
+            .as_deref()
+            .and_then(decode_memo_field)
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+    }
+
+    /// The decoded `memo_format`, usually a MIME type.
+    pub fn decoded_format(&self) -> Option<String> {
+        self.memo_format
+            .as_deref()
+            .and_then(decode_memo_field)
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+    }
+
+    /// The decoded `memo_data` as text, or `None` if it is not valid
+    /// UTF-8.
+    pub fn as_utf8_data(&self) -> Option<String> {
+        self.decoded_data()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+    }
+}
+
+fn decode_memo_field(field: &str) -> Option<Vec<u8>> {
+    hex::decode(field).ok()
+}
+
 serde_with_tag! {
     /// Represents one entry in a list of AuthAccounts used in AMMBid transaction.
     #[derive(Debug, Clone, PartialEq, Eq, new)]
@@ -361,22 +418,38 @@ where
         {
             return Err(XRPLTransactionException::TxMustBeSigned.into());
         }
-        let prefix = format!("{:X}", TRANSACTION_HASH_PREFIX);
         let tx_hex = encode(self).map_err(XRPLTransactionException::XRPLCoreError)?;
-        let tx_hex = prefix + &tx_hex;
-        let tx_bytes = hex::decode(&tx_hex)?;
-        let mut hasher = Sha512::new();
-        hasher.update(&tx_bytes);
-        let hash = hasher.finalize();
-        let hex_string = hex::encode_upper(hash);
-        let result = hex_string[..64].to_string();
-
-        Ok(result.into())
+
+        Ok(hash_tx_hex(&tx_hex)?.into())
+    }
+}
+
+/// Hashes a signed transaction blob as the ledger does, e.g. to look up
+/// a submitted transaction, without deserializing it into a model.
+pub fn hash_signed_tx_blob(tx_blob: &str) -> XRPLModelResult<String> {
+    let tx = decode(tx_blob).map_err(XRPLTransactionException::XRPLCoreError)?;
+    if tx.get("TxnSignature").is_none() && tx.get("Signers").is_none() {
+        return Err(XRPLTransactionException::TxMustBeSigned.into());
     }
+
+    hash_tx_hex(tx_blob)
+}
+
+fn hash_tx_hex(tx_hex: &str) -> XRPLModelResult<String> {
+    let prefix = format!("{:X}", TRANSACTION_HASH_PREFIX);
+    let tx_bytes = hex::decode(prefix + tx_hex)?;
+    let mut hasher = Sha512::new();
+    hasher.update(&tx_bytes);
+    let hash = hasher.finalize();
+    let hex_string = hex::encode_upper(hash);
+
+    Ok(hex_string[..64].to_string())
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Display, AsRefStr)]
 pub enum Flag {
+    AMMDeposit(amm_deposit::AMMDepositFlag),
+    AMMWithdraw(amm_withdraw::AMMWithdrawFlag),
     AccountSet(account_set::AccountSetFlag),
     NFTokenCreateOffer(nftoken_create_offer::NFTokenCreateOfferFlag),
     NFTokenMint(nftoken_mint::NFTokenMintFlag),
@@ -384,6 +457,7 @@ pub enum Flag {
     Payment(payment::PaymentFlag),
     PaymentChannelClaim(payment_channel_claim::PaymentChannelClaimFlag),
     TrustSet(trust_set::TrustSetFlag),
+    XChainModifyBridge(xchain_modify_bridge::XChainModifyBridgeFlags),
     EnableAmendment(pseudo_transactions::enable_amendment::EnableAmendmentFlag),
 }
 
@@ -442,4 +516,132 @@ mod test_tx_common_fields {
         let tx: AccountSet = serde_json::from_str(tx_json_str).unwrap();
         assert_eq!(tx.get_hash().unwrap(), expected_hash);
     }
+
+    #[test]
+    fn test_transaction_type_round_trip() {
+        use crate::core::binarycodec::definitions::get_transaction_type_code;
+        use core::str::FromStr;
+
+        for transaction_type in TransactionType::iter() {
+            let name = transaction_type.to_string();
+            assert_eq!(
+                TransactionType::from_str(&name),
+                Ok(transaction_type.clone())
+            );
+            assert!(
+                get_transaction_type_code(&name).is_some(),
+                "`{}` is not a rippled transaction type",
+                name
+            );
+        }
+        assert!(TransactionType::from_str("ammbid").is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_hash_signed_tx_blob {
+    use super::*;
+
+    /// A signed `AccountSet` setting its `Domain` to `example.com`.
+    const SIGNED_ACCOUNT_SET: &str = "120003220000000024000919CB201B000919DF68400000000000000A\
+        7321EDAF73A0E6745EA9C17A2F4EB7043134A055213116CFF6F7888BBFF557B002874F74408666A7E6A\
+        F0D6A4B4F19F25D315FA1C31D132FB2E974686C415D5499D43710384FF851C75CCC4E57972DE5C53542\
+        89F574B2F604B6AF15E2DADA6BB9F1330A07770B6578616D706C652E636F6D811402C2A9642B35EFD9E\
+        A92DEC22C95254CB06206A2";
+
+    #[test]
+    fn test_hash_signed_tx_blob() {
+        assert_eq!(
+            hash_signed_tx_blob(SIGNED_ACCOUNT_SET).unwrap(),
+            "5B765D6C6058CF54F5DBF6230A7F51E23295004FCC043660A77D73AA8537737B"
+        );
+        assert_eq!(
+            hash_signed_tx_blob(&SIGNED_ACCOUNT_SET.to_lowercase()).unwrap(),
+            "5B765D6C6058CF54F5DBF6230A7F51E23295004FCC043660A77D73AA8537737B"
+        );
+    }
+
+    #[test]
+    fn test_unsigned_blob() {
+        let mut tx = decode(SIGNED_ACCOUNT_SET).unwrap();
+        tx.as_object_mut().unwrap().remove("TxnSignature");
+
+        assert_eq!(
+            hash_signed_tx_blob(&encode(&tx).unwrap()),
+            Err(XRPLTransactionException::TxMustBeSigned.into())
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_flag {
+    use alloc::string::ToString;
+    use serde_json::json;
+
+    use super::{amm_deposit, amm_withdraw, xchain_modify_bridge, Flag};
+
+    #[test]
+    fn test_amm_deposit_flag() {
+        let flag = Flag::AMMDeposit(amm_deposit::AMMDepositFlag::TfSingleAsset);
+
+        assert_eq!(flag.to_string(), "AMMDeposit");
+        assert_eq!(
+            serde_json::to_value(&flag).unwrap(),
+            json!({ "AMMDeposit": 0x00080000 })
+        );
+    }
+
+    #[test]
+    fn test_amm_withdraw_flag() {
+        let flag = Flag::AMMWithdraw(amm_withdraw::AMMWithdrawFlag::TfWithdrawAll);
+
+        assert_eq!(flag.to_string(), "AMMWithdraw");
+        assert_eq!(
+            serde_json::to_value(&flag).unwrap(),
+            json!({ "AMMWithdraw": 0x00020000 })
+        );
+    }
+
+    #[test]
+    fn test_xchain_modify_bridge_flag() {
+        let flag = Flag::XChainModifyBridge(
+            xchain_modify_bridge::XChainModifyBridgeFlags::TfClearAccountCreateAmount,
+        );
+        let value = json!({ "XChainModifyBridge": 0x00010000 });
+
+        assert_eq!(flag.to_string(), "XChainModifyBridge");
+        assert_eq!(serde_json::to_value(&flag).unwrap(), value);
+        assert_eq!(serde_json::from_value::<Flag>(value).unwrap(), flag);
+    }
+}
+
+#[cfg(test)]
+mod test_memo {
+    use super::Memo;
+
+    #[test]
+    fn test_text_round_trip() {
+        let memo = Memo::from_text("Hello, XRPL!", Some("text/plain"), Some("text/plain"));
+
+        assert_eq!(memo.memo_data.as_deref(), Some("48656C6C6F2C205852504C21"));
+        assert_eq!(memo.as_utf8_data().as_deref(), Some("Hello, XRPL!"));
+        assert_eq!(memo.decoded_type().as_deref(), Some("text/plain"));
+        assert_eq!(memo.decoded_format().as_deref(), Some("text/plain"));
+    }
+
+    #[test]
+    fn test_binary_data() {
+        let memo = Memo::new(Some("FF00".into()), None, None);
+
+        assert_eq!(memo.decoded_data(), Some(alloc::vec![0xFF, 0x00]));
+        assert_eq!(memo.as_utf8_data(), None);
+        assert_eq!(memo.decoded_type(), None);
+    }
+
+    #[test]
+    fn test_invalid_hex() {
+        let memo = Memo::new(Some("not hex".into()), None, None);
+
+        assert_eq!(memo.decoded_data(), None);
+    }
 }
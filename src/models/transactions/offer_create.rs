@@ -1,6 +1,9 @@
 use alloc::borrow::Cow;
+use alloc::string::ToString;
 use alloc::vec::Vec;
+use core::num::NonZeroU64;
 
+use bigdecimal::{BigDecimal, RoundingMode, Zero};
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use serde_with::skip_serializing_none;
@@ -9,7 +12,7 @@ use strum_macros::{AsRefStr, Display, EnumIter};
 use crate::models::{
     amount::Amount,
     transactions::{Memo, Signer, Transaction, TransactionType},
-    Model,
+    Model, XRPLModelException, XRPLModelResult,
 };
 
 use crate::models::amount::XRPAmount;
@@ -141,6 +144,100 @@ impl<'a> OfferCreate<'a> {
     }
 }
 
+/// Significant digits kept by the ledger for amounts and qualities.
+const QUALITY_PRECISION: u64 = 16;
+
+/// Returns the quality of an offer, the price it pays per unit it
+/// gets: `TakerPays` divided by `TakerGets`. XRP is counted in drops
+/// and the result is truncated to 16 significant digits, so it matches
+/// the `quality` the server reports and encodes in the offer's
+/// `BookDirectory`. Lower is better for the taker.
+pub fn compute_quality(
+    taker_gets: &Amount<'_>,
+    taker_pays: &Amount<'_>,
+) -> XRPLModelResult<BigDecimal> {
+    let taker_gets: BigDecimal = taker_gets.clone().try_into()?;
+    let taker_pays: BigDecimal = taker_pays.clone().try_into()?;
+    if taker_gets.is_zero() {
+        return Err(XRPLModelException::ValueZero("taker_gets".to_string()));
+    }
+    let precision = NonZeroU64::new(QUALITY_PRECISION).expect("precision is not zero");
+
+    Ok((taker_pays / taker_gets).with_precision_round(precision, RoundingMode::Down))
+}
+
+#[cfg(test)]
+mod test_compute_quality {
+    use core::str::FromStr;
+
+    use bigdecimal::BigDecimal;
+
+    use super::compute_quality;
+    use crate::models::amount::{Amount, IssuedCurrencyAmount, XRPAmount};
+
+    fn usd(value: &str) -> Amount<'_> {
+        IssuedCurrencyAmount::new(
+            "USD".into(),
+            "rhxbkK9jGqPVLZSWPvCEmmf15xHBfJfCEy".into(),
+            value.into(),
+        )
+        .into()
+    }
+
+    fn quality(value: &str) -> BigDecimal {
+        BigDecimal::from_str(value).unwrap()
+    }
+
+    // The expected qualities are those encoded in the `BookDirectory` of
+    // the matching offers in the codec fixtures.
+    #[test]
+    fn test_xrp_for_issued_currency() {
+        let taker_gets = XRPAmount::from("3000000").into();
+
+        assert_eq!(
+            compute_quality(&taker_gets, &usd("31.5")).unwrap(),
+            quality("0.00001050000000000000")
+        );
+    }
+
+    #[test]
+    fn test_truncates_to_ledger_precision() {
+        let taker_gets = XRPAmount::from("1739130").into();
+
+        // 0.0000011500002875000718... is truncated, not rounded.
+        assert_eq!(
+            compute_quality(&taker_gets, &usd("2")).unwrap(),
+            quality("0.000001150000287500071")
+        );
+    }
+
+    #[test]
+    fn test_issued_currency_for_issued_currency() {
+        let taker_pays = IssuedCurrencyAmount::new(
+            "JPY".into(),
+            "rhxbkK9jGqPVLZSWPvCEmmf15xHBfJfCEy".into(),
+            "1320".into(),
+        )
+        .into();
+
+        assert_eq!(
+            compute_quality(&usd("110"), &taker_pays).unwrap(),
+            quality("12")
+        );
+    }
+
+    #[test]
+    fn test_issued_currency_for_xrp() {
+        let taker_pays = XRPAmount::from("79550000000").into();
+
+        assert_eq!(
+            compute_quality(&usd("37"), &taker_pays).unwrap(),
+            quality("2150000000")
+        );
+        assert!(compute_quality(&usd("0"), &taker_pays).is_err());
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::models::amount::{IssuedCurrencyAmount, XRPAmount};
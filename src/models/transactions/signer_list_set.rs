@@ -19,6 +19,10 @@ use crate::serde_with_tag;
 
 use super::CommonFields;
 
+/// The maximum number of entries in a signer list, since the
+/// ExpandedSignerList amendment.
+pub const MAX_SIGNER_ENTRIES: usize = 32;
+
 serde_with_tag! {
     #[derive(Debug, PartialEq, Eq, Default, Clone, new)]
     #[skip_serializing_none]
@@ -102,10 +106,10 @@ impl<'a> SignerListSetError for SignerListSet<'a> {
                     found: signer_entries.len(),
                 }
                 .into())
-            } else if signer_entries.len() > 8 {
+            } else if signer_entries.len() > MAX_SIGNER_ENTRIES {
                 Err(XRPLSignerListSetException::CollectionTooManyItems {
                     field: "signer_entries".into(),
-                    max: 8_usize,
+                    max: MAX_SIGNER_ENTRIES,
                     found: signer_entries.len(),
                 }
                 .into())
@@ -218,7 +222,9 @@ mod test_signer_list_set_error {
     use alloc::string::ToString;
     use alloc::vec;
 
-    use crate::models::Model;
+    use crate::models::{
+        transactions::exceptions::XRPLTransactionException, Model, XRPLModelException,
+    };
 
     use super::*;
 
@@ -276,48 +282,18 @@ mod test_signer_list_set_error {
             "The value of the field `\"signer_entries\"` has too few items in it (min 1, found 0)"
         );
 
-        signer_list_set.signer_entries = Some(vec![
-            SignerEntry {
-                account: "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".to_string(),
-                signer_weight: 1,
-            },
-            SignerEntry {
-                account: "rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v".to_string(),
-                signer_weight: 1,
-            },
-            SignerEntry {
-                account: "rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v".to_string(),
-                signer_weight: 2,
-            },
-            SignerEntry {
-                account: "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".to_string(),
-                signer_weight: 2,
-            },
-            SignerEntry {
-                account: "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".to_string(),
-                signer_weight: 1,
-            },
-            SignerEntry {
-                account: "rXTZ5g8X7mrAYEe7iFeM9fiS4ccueyurG".to_string(),
-                signer_weight: 1,
-            },
-            SignerEntry {
-                account: "rPbMHxs7vy5t6e19tYfqG7XJ6Fog8EPZLk".to_string(),
-                signer_weight: 2,
-            },
-            SignerEntry {
-                account: "r3rhWeE31Jt5sWmi4QiGLMZnY3ENgqw96W".to_string(),
-                signer_weight: 3,
-            },
-            SignerEntry {
-                account: "rchGBxcD1A1C2tdxF6papQYZ8kjRKMYcL".to_string(),
-                signer_weight: 2,
-            },
-        ]);
+        signer_list_set.signer_entries = Some(
+            (0..=MAX_SIGNER_ENTRIES)
+                .map(|i| SignerEntry {
+                    account: alloc::format!("rSigner{}", i),
+                    signer_weight: 1,
+                })
+                .collect(),
+        );
 
         assert_eq!(
             signer_list_set.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `\"signer_entries\"` has too many items in it (max 8, found 9)"
+            "The value of the field `\"signer_entries\"` has too many items in it (max 32, found 33)"
         );
 
         signer_list_set.signer_entries = Some(vec![
@@ -368,6 +344,81 @@ mod test_signer_list_set_error {
             "The value of the field `\"signer_entries\"` has a duplicate in it (found \"rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW\")"
         );
     }
+
+    fn signer_list_set<'a>(
+        signer_quorum: u32,
+        signer_entries: Vec<SignerEntry>,
+    ) -> SignerListSet<'a> {
+        SignerListSet::new(
+            "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            signer_quorum,
+            Some(signer_entries),
+        )
+    }
+
+    #[test]
+    fn test_unreachable_quorum() {
+        let signer_list_set = signer_list_set(
+            4,
+            vec![
+                SignerEntry::new("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".to_string(), 2),
+                SignerEntry::new("rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v".to_string(), 1),
+            ],
+        );
+
+        assert!(matches!(
+            signer_list_set.validate(),
+            Err(XRPLModelException::XRPLTransactionError(
+                XRPLTransactionException::XRPLSignerListSetError(
+                    XRPLSignerListSetException::SignerQuorumExceedsSignerWeight {
+                        max: 3,
+                        found: 4
+                    }
+                )
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_duplicate_signer() {
+        let signer_list_set = signer_list_set(
+            2,
+            vec![
+                SignerEntry::new("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".to_string(), 1),
+                SignerEntry::new("rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v".to_string(), 1),
+                SignerEntry::new("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".to_string(), 1),
+            ],
+        );
+
+        assert!(matches!(
+            signer_list_set.validate(),
+            Err(XRPLModelException::XRPLTransactionError(
+                XRPLTransactionException::XRPLSignerListSetError(
+                    XRPLSignerListSetException::CollectionItemDuplicate { .. }
+                )
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_valid_signer_list() {
+        let signer_list_set = signer_list_set(
+            3,
+            (0..MAX_SIGNER_ENTRIES)
+                .map(|i| SignerEntry::new(alloc::format!("rSigner{}", i), 1))
+                .collect(),
+        );
+
+        assert!(signer_list_set.validate().is_ok());
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,642 @@
+//! Engine result codes returned for transactions.
+//!
+//! See Transaction Results:
+//! `<https://xrpl.org/transaction-results.html>`
+
+use serde::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, Display, EnumIter, EnumString, FromRepr};
+
+/// Engine result codes (`TransactionResult`) with the numeric values
+/// used by the binary codec.
+///
+/// The serde, `Display` and `FromStr` representations are the names used
+/// by rippled (e.g. `tesSUCCESS`, `tecPATH_DRY`).
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Display,
+    AsRefStr,
+    EnumString,
+    EnumIter,
+    FromRepr,
+    PartialEq,
+    Eq,
+    Hash,
+)]
+#[repr(i16)]
+pub enum TransactionResultCode {
+    // Local error: the transaction was not applied and not relayed.
+    #[serde(rename = "telLOCAL_ERROR")]
+    #[strum(serialize = "telLOCAL_ERROR")]
+    TelLocalError = -399,
+    #[serde(rename = "telBAD_DOMAIN")]
+    #[strum(serialize = "telBAD_DOMAIN")]
+    TelBadDomain = -398,
+    #[serde(rename = "telBAD_PATH_COUNT")]
+    #[strum(serialize = "telBAD_PATH_COUNT")]
+    TelBadPathCount = -397,
+    #[serde(rename = "telBAD_PUBLIC_KEY")]
+    #[strum(serialize = "telBAD_PUBLIC_KEY")]
+    TelBadPublicKey = -396,
+    #[serde(rename = "telFAILED_PROCESSING")]
+    #[strum(serialize = "telFAILED_PROCESSING")]
+    TelFailedProcessing = -395,
+    #[serde(rename = "telINSUF_FEE_P")]
+    #[strum(serialize = "telINSUF_FEE_P")]
+    TelInsufFeeP = -394,
+    #[serde(rename = "telNO_DST_PARTIAL")]
+    #[strum(serialize = "telNO_DST_PARTIAL")]
+    TelNoDstPartial = -393,
+    #[serde(rename = "telCAN_NOT_QUEUE")]
+    #[strum(serialize = "telCAN_NOT_QUEUE")]
+    TelCanNotQueue = -392,
+    #[serde(rename = "telCAN_NOT_QUEUE_BALANCE")]
+    #[strum(serialize = "telCAN_NOT_QUEUE_BALANCE")]
+    TelCanNotQueueBalance = -391,
+    #[serde(rename = "telCAN_NOT_QUEUE_BLOCKS")]
+    #[strum(serialize = "telCAN_NOT_QUEUE_BLOCKS")]
+    TelCanNotQueueBlocks = -390,
+    #[serde(rename = "telCAN_NOT_QUEUE_BLOCKED")]
+    #[strum(serialize = "telCAN_NOT_QUEUE_BLOCKED")]
+    TelCanNotQueueBlocked = -389,
+    #[serde(rename = "telCAN_NOT_QUEUE_FEE")]
+    #[strum(serialize = "telCAN_NOT_QUEUE_FEE")]
+    TelCanNotQueueFee = -388,
+    #[serde(rename = "telCAN_NOT_QUEUE_FULL")]
+    #[strum(serialize = "telCAN_NOT_QUEUE_FULL")]
+    TelCanNotQueueFull = -387,
+    #[serde(rename = "telWRONG_NETWORK")]
+    #[strum(serialize = "telWRONG_NETWORK")]
+    TelWrongNetwork = -386,
+    #[serde(rename = "telREQUIRES_NETWORK_ID")]
+    #[strum(serialize = "telREQUIRES_NETWORK_ID")]
+    TelRequiresNetworkId = -385,
+    #[serde(rename = "telNETWORK_ID_MAKES_TX_NON_CANONICAL")]
+    #[strum(serialize = "telNETWORK_ID_MAKES_TX_NON_CANONICAL")]
+    TelNetworkIdMakesTxNonCanonical = -384,
+    #[serde(rename = "telENV_RPC_FAILED")]
+    #[strum(serialize = "telENV_RPC_FAILED")]
+    TelEnvRpcFailed = -383,
+
+    // Malformed: the transaction is invalid and can never succeed.
+    #[serde(rename = "temMALFORMED")]
+    #[strum(serialize = "temMALFORMED")]
+    TemMalformed = -299,
+    #[serde(rename = "temBAD_AMOUNT")]
+    #[strum(serialize = "temBAD_AMOUNT")]
+    TemBadAmount = -298,
+    #[serde(rename = "temBAD_CURRENCY")]
+    #[strum(serialize = "temBAD_CURRENCY")]
+    TemBadCurrency = -297,
+    #[serde(rename = "temBAD_EXPIRATION")]
+    #[strum(serialize = "temBAD_EXPIRATION")]
+    TemBadExpiration = -296,
+    #[serde(rename = "temBAD_FEE")]
+    #[strum(serialize = "temBAD_FEE")]
+    TemBadFee = -295,
+    #[serde(rename = "temBAD_ISSUER")]
+    #[strum(serialize = "temBAD_ISSUER")]
+    TemBadIssuer = -294,
+    #[serde(rename = "temBAD_LIMIT")]
+    #[strum(serialize = "temBAD_LIMIT")]
+    TemBadLimit = -293,
+    #[serde(rename = "temBAD_OFFER")]
+    #[strum(serialize = "temBAD_OFFER")]
+    TemBadOffer = -292,
+    #[serde(rename = "temBAD_PATH")]
+    #[strum(serialize = "temBAD_PATH")]
+    TemBadPath = -291,
+    #[serde(rename = "temBAD_PATH_LOOP")]
+    #[strum(serialize = "temBAD_PATH_LOOP")]
+    TemBadPathLoop = -290,
+    #[serde(rename = "temBAD_REGKEY")]
+    #[strum(serialize = "temBAD_REGKEY")]
+    TemBadRegkey = -289,
+    #[serde(rename = "temBAD_SEND_XRP_LIMIT")]
+    #[strum(serialize = "temBAD_SEND_XRP_LIMIT")]
+    TemBadSendXrpLimit = -288,
+    #[serde(rename = "temBAD_SEND_XRP_MAX")]
+    #[strum(serialize = "temBAD_SEND_XRP_MAX")]
+    TemBadSendXrpMax = -287,
+    #[serde(rename = "temBAD_SEND_XRP_NO_DIRECT")]
+    #[strum(serialize = "temBAD_SEND_XRP_NO_DIRECT")]
+    TemBadSendXrpNoDirect = -286,
+    #[serde(rename = "temBAD_SEND_XRP_PARTIAL")]
+    #[strum(serialize = "temBAD_SEND_XRP_PARTIAL")]
+    TemBadSendXrpPartial = -285,
+    #[serde(rename = "temBAD_SEND_XRP_PATHS")]
+    #[strum(serialize = "temBAD_SEND_XRP_PATHS")]
+    TemBadSendXrpPaths = -284,
+    #[serde(rename = "temBAD_SEQUENCE")]
+    #[strum(serialize = "temBAD_SEQUENCE")]
+    TemBadSequence = -283,
+    #[serde(rename = "temBAD_SIGNATURE")]
+    #[strum(serialize = "temBAD_SIGNATURE")]
+    TemBadSignature = -282,
+    #[serde(rename = "temBAD_SRC_ACCOUNT")]
+    #[strum(serialize = "temBAD_SRC_ACCOUNT")]
+    TemBadSrcAccount = -281,
+    #[serde(rename = "temBAD_TRANSFER_RATE")]
+    #[strum(serialize = "temBAD_TRANSFER_RATE")]
+    TemBadTransferRate = -280,
+    #[serde(rename = "temDST_IS_SRC")]
+    #[strum(serialize = "temDST_IS_SRC")]
+    TemDstIsSrc = -279,
+    #[serde(rename = "temDST_NEEDED")]
+    #[strum(serialize = "temDST_NEEDED")]
+    TemDstNeeded = -278,
+    #[serde(rename = "temINVALID")]
+    #[strum(serialize = "temINVALID")]
+    TemInvalid = -277,
+    #[serde(rename = "temINVALID_FLAG")]
+    #[strum(serialize = "temINVALID_FLAG")]
+    TemInvalidFlag = -276,
+    #[serde(rename = "temREDUNDANT")]
+    #[strum(serialize = "temREDUNDANT")]
+    TemRedundant = -275,
+    #[serde(rename = "temRIPPLE_EMPTY")]
+    #[strum(serialize = "temRIPPLE_EMPTY")]
+    TemRippleEmpty = -274,
+    #[serde(rename = "temDISABLED")]
+    #[strum(serialize = "temDISABLED")]
+    TemDisabled = -273,
+    #[serde(rename = "temBAD_SIGNER")]
+    #[strum(serialize = "temBAD_SIGNER")]
+    TemBadSigner = -272,
+    #[serde(rename = "temBAD_QUORUM")]
+    #[strum(serialize = "temBAD_QUORUM")]
+    TemBadQuorum = -271,
+    #[serde(rename = "temBAD_WEIGHT")]
+    #[strum(serialize = "temBAD_WEIGHT")]
+    TemBadWeight = -270,
+    #[serde(rename = "temBAD_TICK_SIZE")]
+    #[strum(serialize = "temBAD_TICK_SIZE")]
+    TemBadTickSize = -269,
+    #[serde(rename = "temINVALID_ACCOUNT_ID")]
+    #[strum(serialize = "temINVALID_ACCOUNT_ID")]
+    TemInvalidAccountId = -268,
+    #[serde(rename = "temCANNOT_PREAUTH_SELF")]
+    #[strum(serialize = "temCANNOT_PREAUTH_SELF")]
+    TemCannotPreauthSelf = -267,
+    #[serde(rename = "temINVALID_COUNT")]
+    #[strum(serialize = "temINVALID_COUNT")]
+    TemInvalidCount = -266,
+    #[serde(rename = "temUNCERTAIN")]
+    #[strum(serialize = "temUNCERTAIN")]
+    TemUncertain = -265,
+    #[serde(rename = "temUNKNOWN")]
+    #[strum(serialize = "temUNKNOWN")]
+    TemUnknown = -264,
+    #[serde(rename = "temSEQ_AND_TICKET")]
+    #[strum(serialize = "temSEQ_AND_TICKET")]
+    TemSeqAndTicket = -263,
+    #[serde(rename = "temBAD_NFTOKEN_TRANSFER_FEE")]
+    #[strum(serialize = "temBAD_NFTOKEN_TRANSFER_FEE")]
+    TemBadNftokenTransferFee = -262,
+    #[serde(rename = "temBAD_AMM_TOKENS")]
+    #[strum(serialize = "temBAD_AMM_TOKENS")]
+    TemBadAmmTokens = -261,
+    #[serde(rename = "temXCHAIN_EQUAL_DOOR_ACCOUNTS")]
+    #[strum(serialize = "temXCHAIN_EQUAL_DOOR_ACCOUNTS")]
+    TemXchainEqualDoorAccounts = -260,
+    #[serde(rename = "temXCHAIN_BAD_PROOF")]
+    #[strum(serialize = "temXCHAIN_BAD_PROOF")]
+    TemXchainBadProof = -259,
+    #[serde(rename = "temXCHAIN_BRIDGE_BAD_ISSUES")]
+    #[strum(serialize = "temXCHAIN_BRIDGE_BAD_ISSUES")]
+    TemXchainBridgeBadIssues = -258,
+    #[serde(rename = "temXCHAIN_BRIDGE_NONDOOR_OWNER")]
+    #[strum(serialize = "temXCHAIN_BRIDGE_NONDOOR_OWNER")]
+    TemXchainBridgeNondoorOwner = -257,
+    #[serde(rename = "temXCHAIN_BRIDGE_BAD_MIN_ACCOUNT_CREATE_AMOUNT")]
+    #[strum(serialize = "temXCHAIN_BRIDGE_BAD_MIN_ACCOUNT_CREATE_AMOUNT")]
+    TemXchainBridgeBadMinAccountCreateAmount = -256,
+    #[serde(rename = "temXCHAIN_BRIDGE_BAD_REWARD_AMOUNT")]
+    #[strum(serialize = "temXCHAIN_BRIDGE_BAD_REWARD_AMOUNT")]
+    TemXchainBridgeBadRewardAmount = -255,
+    #[serde(rename = "temEMPTY_DID")]
+    #[strum(serialize = "temEMPTY_DID")]
+    TemEmptyDid = -254,
+    #[serde(rename = "temARRAY_EMPTY")]
+    #[strum(serialize = "temARRAY_EMPTY")]
+    TemArrayEmpty = -253,
+    #[serde(rename = "temARRAY_TOO_LARGE")]
+    #[strum(serialize = "temARRAY_TOO_LARGE")]
+    TemArrayTooLarge = -252,
+
+    // Failure: the transaction can not be applied to the current ledger.
+    #[serde(rename = "tefFAILURE")]
+    #[strum(serialize = "tefFAILURE")]
+    TefFailure = -199,
+    #[serde(rename = "tefALREADY")]
+    #[strum(serialize = "tefALREADY")]
+    TefAlready = -198,
+    #[serde(rename = "tefBAD_ADD_AUTH")]
+    #[strum(serialize = "tefBAD_ADD_AUTH")]
+    TefBadAddAuth = -197,
+    #[serde(rename = "tefBAD_AUTH")]
+    #[strum(serialize = "tefBAD_AUTH")]
+    TefBadAuth = -196,
+    #[serde(rename = "tefBAD_LEDGER")]
+    #[strum(serialize = "tefBAD_LEDGER")]
+    TefBadLedger = -195,
+    #[serde(rename = "tefCREATED")]
+    #[strum(serialize = "tefCREATED")]
+    TefCreated = -194,
+    #[serde(rename = "tefEXCEPTION")]
+    #[strum(serialize = "tefEXCEPTION")]
+    TefException = -193,
+    #[serde(rename = "tefINTERNAL")]
+    #[strum(serialize = "tefINTERNAL")]
+    TefInternal = -192,
+    #[serde(rename = "tefNO_AUTH_REQUIRED")]
+    #[strum(serialize = "tefNO_AUTH_REQUIRED")]
+    TefNoAuthRequired = -191,
+    #[serde(rename = "tefPAST_SEQ")]
+    #[strum(serialize = "tefPAST_SEQ")]
+    TefPastSeq = -190,
+    #[serde(rename = "tefWRONG_PRIOR")]
+    #[strum(serialize = "tefWRONG_PRIOR")]
+    TefWrongPrior = -189,
+    #[serde(rename = "tefMASTER_DISABLED")]
+    #[strum(serialize = "tefMASTER_DISABLED")]
+    TefMasterDisabled = -188,
+    #[serde(rename = "tefMAX_LEDGER")]
+    #[strum(serialize = "tefMAX_LEDGER")]
+    TefMaxLedger = -187,
+    #[serde(rename = "tefBAD_SIGNATURE")]
+    #[strum(serialize = "tefBAD_SIGNATURE")]
+    TefBadSignature = -186,
+    #[serde(rename = "tefBAD_QUORUM")]
+    #[strum(serialize = "tefBAD_QUORUM")]
+    TefBadQuorum = -185,
+    #[serde(rename = "tefNOT_MULTI_SIGNING")]
+    #[strum(serialize = "tefNOT_MULTI_SIGNING")]
+    TefNotMultiSigning = -184,
+    #[serde(rename = "tefBAD_AUTH_MASTER")]
+    #[strum(serialize = "tefBAD_AUTH_MASTER")]
+    TefBadAuthMaster = -183,
+    #[serde(rename = "tefINVARIANT_FAILED")]
+    #[strum(serialize = "tefINVARIANT_FAILED")]
+    TefInvariantFailed = -182,
+    #[serde(rename = "tefTOO_BIG")]
+    #[strum(serialize = "tefTOO_BIG")]
+    TefTooBig = -181,
+    #[serde(rename = "tefNO_TICKET")]
+    #[strum(serialize = "tefNO_TICKET")]
+    TefNoTicket = -180,
+    #[serde(rename = "tefNFTOKEN_IS_NOT_TRANSFERABLE")]
+    #[strum(serialize = "tefNFTOKEN_IS_NOT_TRANSFERABLE")]
+    TefNftokenIsNotTransferable = -179,
+
+    // Retry: the transaction may succeed if submitted again later.
+    #[serde(rename = "terRETRY")]
+    #[strum(serialize = "terRETRY")]
+    TerRetry = -99,
+    #[serde(rename = "terFUNDS_SPENT")]
+    #[strum(serialize = "terFUNDS_SPENT")]
+    TerFundsSpent = -98,
+    #[serde(rename = "terINSUF_FEE_B")]
+    #[strum(serialize = "terINSUF_FEE_B")]
+    TerInsufFeeB = -97,
+    #[serde(rename = "terNO_ACCOUNT")]
+    #[strum(serialize = "terNO_ACCOUNT")]
+    TerNoAccount = -96,
+    #[serde(rename = "terNO_AUTH")]
+    #[strum(serialize = "terNO_AUTH")]
+    TerNoAuth = -95,
+    #[serde(rename = "terNO_LINE")]
+    #[strum(serialize = "terNO_LINE")]
+    TerNoLine = -94,
+    #[serde(rename = "terOWNERS")]
+    #[strum(serialize = "terOWNERS")]
+    TerOwners = -93,
+    #[serde(rename = "terPRE_SEQ")]
+    #[strum(serialize = "terPRE_SEQ")]
+    TerPreSeq = -92,
+    #[serde(rename = "terLAST")]
+    #[strum(serialize = "terLAST")]
+    TerLast = -91,
+    #[serde(rename = "terNO_RIPPLE")]
+    #[strum(serialize = "terNO_RIPPLE")]
+    TerNoRipple = -90,
+    #[serde(rename = "terQUEUED")]
+    #[strum(serialize = "terQUEUED")]
+    TerQueued = -89,
+    #[serde(rename = "terPRE_TICKET")]
+    #[strum(serialize = "terPRE_TICKET")]
+    TerPreTicket = -88,
+    #[serde(rename = "terNO_AMM")]
+    #[strum(serialize = "terNO_AMM")]
+    TerNoAmm = -87,
+
+    // Success.
+    #[serde(rename = "tesSUCCESS")]
+    #[strum(serialize = "tesSUCCESS")]
+    TesSuccess = 0,
+
+    // Claimed fee: the transaction failed but the fee was charged.
+    #[serde(rename = "tecCLAIM")]
+    #[strum(serialize = "tecCLAIM")]
+    TecClaim = 100,
+    #[serde(rename = "tecPATH_PARTIAL")]
+    #[strum(serialize = "tecPATH_PARTIAL")]
+    TecPathPartial = 101,
+    #[serde(rename = "tecUNFUNDED_ADD")]
+    #[strum(serialize = "tecUNFUNDED_ADD")]
+    TecUnfundedAdd = 102,
+    #[serde(rename = "tecUNFUNDED_OFFER")]
+    #[strum(serialize = "tecUNFUNDED_OFFER")]
+    TecUnfundedOffer = 103,
+    #[serde(rename = "tecUNFUNDED_PAYMENT")]
+    #[strum(serialize = "tecUNFUNDED_PAYMENT")]
+    TecUnfundedPayment = 104,
+    #[serde(rename = "tecFAILED_PROCESSING")]
+    #[strum(serialize = "tecFAILED_PROCESSING")]
+    TecFailedProcessing = 105,
+    #[serde(rename = "tecDIR_FULL")]
+    #[strum(serialize = "tecDIR_FULL")]
+    TecDirFull = 121,
+    #[serde(rename = "tecINSUF_RESERVE_LINE")]
+    #[strum(serialize = "tecINSUF_RESERVE_LINE")]
+    TecInsufReserveLine = 122,
+    #[serde(rename = "tecINSUF_RESERVE_OFFER")]
+    #[strum(serialize = "tecINSUF_RESERVE_OFFER")]
+    TecInsufReserveOffer = 123,
+    #[serde(rename = "tecNO_DST")]
+    #[strum(serialize = "tecNO_DST")]
+    TecNoDst = 124,
+    #[serde(rename = "tecNO_DST_INSUF_XRP")]
+    #[strum(serialize = "tecNO_DST_INSUF_XRP")]
+    TecNoDstInsufXrp = 125,
+    #[serde(rename = "tecNO_LINE_INSUF_RESERVE")]
+    #[strum(serialize = "tecNO_LINE_INSUF_RESERVE")]
+    TecNoLineInsufReserve = 126,
+    #[serde(rename = "tecNO_LINE_REDUNDANT")]
+    #[strum(serialize = "tecNO_LINE_REDUNDANT")]
+    TecNoLineRedundant = 127,
+    #[serde(rename = "tecPATH_DRY")]
+    #[strum(serialize = "tecPATH_DRY")]
+    TecPathDry = 128,
+    #[serde(rename = "tecUNFUNDED")]
+    #[strum(serialize = "tecUNFUNDED")]
+    TecUnfunded = 129,
+    #[serde(rename = "tecNO_ALTERNATIVE_KEY")]
+    #[strum(serialize = "tecNO_ALTERNATIVE_KEY")]
+    TecNoAlternativeKey = 130,
+    #[serde(rename = "tecNO_REGULAR_KEY")]
+    #[strum(serialize = "tecNO_REGULAR_KEY")]
+    TecNoRegularKey = 131,
+    #[serde(rename = "tecOWNERS")]
+    #[strum(serialize = "tecOWNERS")]
+    TecOwners = 132,
+    #[serde(rename = "tecNO_ISSUER")]
+    #[strum(serialize = "tecNO_ISSUER")]
+    TecNoIssuer = 133,
+    #[serde(rename = "tecNO_AUTH")]
+    #[strum(serialize = "tecNO_AUTH")]
+    TecNoAuth = 134,
+    #[serde(rename = "tecNO_LINE")]
+    #[strum(serialize = "tecNO_LINE")]
+    TecNoLine = 135,
+    #[serde(rename = "tecINSUFF_FEE")]
+    #[strum(serialize = "tecINSUFF_FEE")]
+    TecInsuffFee = 136,
+    #[serde(rename = "tecFROZEN")]
+    #[strum(serialize = "tecFROZEN")]
+    TecFrozen = 137,
+    #[serde(rename = "tecNO_TARGET")]
+    #[strum(serialize = "tecNO_TARGET")]
+    TecNoTarget = 138,
+    #[serde(rename = "tecNO_PERMISSION")]
+    #[strum(serialize = "tecNO_PERMISSION")]
+    TecNoPermission = 139,
+    #[serde(rename = "tecNO_ENTRY")]
+    #[strum(serialize = "tecNO_ENTRY")]
+    TecNoEntry = 140,
+    #[serde(rename = "tecINSUFFICIENT_RESERVE")]
+    #[strum(serialize = "tecINSUFFICIENT_RESERVE")]
+    TecInsufficientReserve = 141,
+    #[serde(rename = "tecNEED_MASTER_KEY")]
+    #[strum(serialize = "tecNEED_MASTER_KEY")]
+    TecNeedMasterKey = 142,
+    #[serde(rename = "tecDST_TAG_NEEDED")]
+    #[strum(serialize = "tecDST_TAG_NEEDED")]
+    TecDstTagNeeded = 143,
+    #[serde(rename = "tecINTERNAL")]
+    #[strum(serialize = "tecINTERNAL")]
+    TecInternal = 144,
+    #[serde(rename = "tecOVERSIZE")]
+    #[strum(serialize = "tecOVERSIZE")]
+    TecOversize = 145,
+    #[serde(rename = "tecCRYPTOCONDITION_ERROR")]
+    #[strum(serialize = "tecCRYPTOCONDITION_ERROR")]
+    TecCryptoconditionError = 146,
+    #[serde(rename = "tecINVARIANT_FAILED")]
+    #[strum(serialize = "tecINVARIANT_FAILED")]
+    TecInvariantFailed = 147,
+    #[serde(rename = "tecEXPIRED")]
+    #[strum(serialize = "tecEXPIRED")]
+    TecExpired = 148,
+    #[serde(rename = "tecDUPLICATE")]
+    #[strum(serialize = "tecDUPLICATE")]
+    TecDuplicate = 149,
+    #[serde(rename = "tecKILLED")]
+    #[strum(serialize = "tecKILLED")]
+    TecKilled = 150,
+    #[serde(rename = "tecHAS_OBLIGATIONS")]
+    #[strum(serialize = "tecHAS_OBLIGATIONS")]
+    TecHasObligations = 151,
+    #[serde(rename = "tecTOO_SOON")]
+    #[strum(serialize = "tecTOO_SOON")]
+    TecTooSoon = 152,
+    #[serde(rename = "tecHOOK_REJECTED")]
+    #[strum(serialize = "tecHOOK_REJECTED")]
+    TecHookRejected = 153,
+    #[serde(rename = "tecMAX_SEQUENCE_REACHED")]
+    #[strum(serialize = "tecMAX_SEQUENCE_REACHED")]
+    TecMaxSequenceReached = 154,
+    #[serde(rename = "tecNO_SUITABLE_NFTOKEN_PAGE")]
+    #[strum(serialize = "tecNO_SUITABLE_NFTOKEN_PAGE")]
+    TecNoSuitableNftokenPage = 155,
+    #[serde(rename = "tecNFTOKEN_BUY_SELL_MISMATCH")]
+    #[strum(serialize = "tecNFTOKEN_BUY_SELL_MISMATCH")]
+    TecNftokenBuySellMismatch = 156,
+    #[serde(rename = "tecNFTOKEN_OFFER_TYPE_MISMATCH")]
+    #[strum(serialize = "tecNFTOKEN_OFFER_TYPE_MISMATCH")]
+    TecNftokenOfferTypeMismatch = 157,
+    #[serde(rename = "tecCANT_ACCEPT_OWN_NFTOKEN_OFFER")]
+    #[strum(serialize = "tecCANT_ACCEPT_OWN_NFTOKEN_OFFER")]
+    TecCantAcceptOwnNftokenOffer = 158,
+    #[serde(rename = "tecINSUFFICIENT_FUNDS")]
+    #[strum(serialize = "tecINSUFFICIENT_FUNDS")]
+    TecInsufficientFunds = 159,
+    #[serde(rename = "tecOBJECT_NOT_FOUND")]
+    #[strum(serialize = "tecOBJECT_NOT_FOUND")]
+    TecObjectNotFound = 160,
+    #[serde(rename = "tecINSUFFICIENT_PAYMENT")]
+    #[strum(serialize = "tecINSUFFICIENT_PAYMENT")]
+    TecInsufficientPayment = 161,
+    #[serde(rename = "tecUNFUNDED_AMM")]
+    #[strum(serialize = "tecUNFUNDED_AMM")]
+    TecUnfundedAmm = 162,
+    #[serde(rename = "tecAMM_BALANCE")]
+    #[strum(serialize = "tecAMM_BALANCE")]
+    TecAmmBalance = 163,
+    #[serde(rename = "tecAMM_FAILED")]
+    #[strum(serialize = "tecAMM_FAILED")]
+    TecAmmFailed = 164,
+    #[serde(rename = "tecAMM_INVALID_TOKENS")]
+    #[strum(serialize = "tecAMM_INVALID_TOKENS")]
+    TecAmmInvalidTokens = 165,
+    #[serde(rename = "tecAMM_EMPTY")]
+    #[strum(serialize = "tecAMM_EMPTY")]
+    TecAmmEmpty = 166,
+    #[serde(rename = "tecAMM_NOT_EMPTY")]
+    #[strum(serialize = "tecAMM_NOT_EMPTY")]
+    TecAmmNotEmpty = 167,
+    #[serde(rename = "tecAMM_ACCOUNT")]
+    #[strum(serialize = "tecAMM_ACCOUNT")]
+    TecAmmAccount = 168,
+    #[serde(rename = "tecINCOMPLETE")]
+    #[strum(serialize = "tecINCOMPLETE")]
+    TecIncomplete = 169,
+    #[serde(rename = "tecXCHAIN_BAD_TRANSFER_ISSUE")]
+    #[strum(serialize = "tecXCHAIN_BAD_TRANSFER_ISSUE")]
+    TecXchainBadTransferIssue = 170,
+    #[serde(rename = "tecXCHAIN_NO_CLAIM_ID")]
+    #[strum(serialize = "tecXCHAIN_NO_CLAIM_ID")]
+    TecXchainNoClaimId = 171,
+    #[serde(rename = "tecXCHAIN_BAD_CLAIM_ID")]
+    #[strum(serialize = "tecXCHAIN_BAD_CLAIM_ID")]
+    TecXchainBadClaimId = 172,
+    #[serde(rename = "tecXCHAIN_CLAIM_NO_QUORUM")]
+    #[strum(serialize = "tecXCHAIN_CLAIM_NO_QUORUM")]
+    TecXchainClaimNoQuorum = 173,
+    #[serde(rename = "tecXCHAIN_PROOF_UNKNOWN_KEY")]
+    #[strum(serialize = "tecXCHAIN_PROOF_UNKNOWN_KEY")]
+    TecXchainProofUnknownKey = 174,
+    #[serde(rename = "tecXCHAIN_CREATE_ACCOUNT_NONXRP_ISSUE")]
+    #[strum(serialize = "tecXCHAIN_CREATE_ACCOUNT_NONXRP_ISSUE")]
+    TecXchainCreateAccountNonxrpIssue = 175,
+    #[serde(rename = "tecXCHAIN_WRONG_CHAIN")]
+    #[strum(serialize = "tecXCHAIN_WRONG_CHAIN")]
+    TecXchainWrongChain = 176,
+    #[serde(rename = "tecXCHAIN_REWARD_MISMATCH")]
+    #[strum(serialize = "tecXCHAIN_REWARD_MISMATCH")]
+    TecXchainRewardMismatch = 177,
+    #[serde(rename = "tecXCHAIN_NO_SIGNERS_LIST")]
+    #[strum(serialize = "tecXCHAIN_NO_SIGNERS_LIST")]
+    TecXchainNoSignersList = 178,
+    #[serde(rename = "tecXCHAIN_SENDING_ACCOUNT_MISMATCH")]
+    #[strum(serialize = "tecXCHAIN_SENDING_ACCOUNT_MISMATCH")]
+    TecXchainSendingAccountMismatch = 179,
+    #[serde(rename = "tecXCHAIN_INSUFF_CREATE_AMOUNT")]
+    #[strum(serialize = "tecXCHAIN_INSUFF_CREATE_AMOUNT")]
+    TecXchainInsuffCreateAmount = 180,
+    #[serde(rename = "tecXCHAIN_ACCOUNT_CREATE_PAST")]
+    #[strum(serialize = "tecXCHAIN_ACCOUNT_CREATE_PAST")]
+    TecXchainAccountCreatePast = 181,
+    #[serde(rename = "tecXCHAIN_ACCOUNT_CREATE_TOO_MANY")]
+    #[strum(serialize = "tecXCHAIN_ACCOUNT_CREATE_TOO_MANY")]
+    TecXchainAccountCreateTooMany = 182,
+    #[serde(rename = "tecXCHAIN_PAYMENT_FAILED")]
+    #[strum(serialize = "tecXCHAIN_PAYMENT_FAILED")]
+    TecXchainPaymentFailed = 183,
+    #[serde(rename = "tecXCHAIN_SELF_COMMIT")]
+    #[strum(serialize = "tecXCHAIN_SELF_COMMIT")]
+    TecXchainSelfCommit = 184,
+    #[serde(rename = "tecXCHAIN_BAD_PUBLIC_KEY_ACCOUNT_PAIR")]
+    #[strum(serialize = "tecXCHAIN_BAD_PUBLIC_KEY_ACCOUNT_PAIR")]
+    TecXchainBadPublicKeyAccountPair = 185,
+    #[serde(rename = "tecXCHAIN_CREATE_ACCOUNT_DISABLED")]
+    #[strum(serialize = "tecXCHAIN_CREATE_ACCOUNT_DISABLED")]
+    TecXchainCreateAccountDisabled = 186,
+    #[serde(rename = "tecEMPTY_DID")]
+    #[strum(serialize = "tecEMPTY_DID")]
+    TecEmptyDid = 187,
+    #[serde(rename = "tecINVALID_UPDATE_TIME")]
+    #[strum(serialize = "tecINVALID_UPDATE_TIME")]
+    TecInvalidUpdateTime = 188,
+    #[serde(rename = "tecTOKEN_PAIR_NOT_FOUND")]
+    #[strum(serialize = "tecTOKEN_PAIR_NOT_FOUND")]
+    TecTokenPairNotFound = 189,
+    #[serde(rename = "tecARRAY_EMPTY")]
+    #[strum(serialize = "tecARRAY_EMPTY")]
+    TecArrayEmpty = 190,
+    #[serde(rename = "tecARRAY_TOO_LARGE")]
+    #[strum(serialize = "tecARRAY_TOO_LARGE")]
+    TecArrayTooLarge = 191,
+}
+
+impl TransactionResultCode {
+    /// Returns the numeric code used in binary serialization.
+    pub fn code(&self) -> i16 {
+        *self as i16
+    }
+
+    /// Returns the result code for a numeric code, if it is known.
+    pub fn from_code(code: i16) -> Option<Self> {
+        Self::from_repr(code)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::str::FromStr;
+
+    use alloc::string::ToString;
+    use strum::IntoEnumIterator;
+
+    use super::TransactionResultCode;
+    use crate::core::binarycodec::definitions::{
+        get_transaction_result_code, get_transaction_result_name,
+    };
+
+    #[test]
+    fn test_codes_match_definitions() {
+        for result in TransactionResultCode::iter() {
+            let name = result.to_string();
+
+            assert_eq!(get_transaction_result_code(&name), Some(&result.code()));
+            assert_eq!(
+                get_transaction_result_name(&result.code()),
+                Some(&name),
+                "{}",
+                name
+            );
+            assert_eq!(TransactionResultCode::from_str(&name), Ok(result));
+            assert_eq!(
+                TransactionResultCode::from_code(result.code()),
+                Some(result)
+            );
+        }
+    }
+
+    #[test]
+    fn test_definitions_are_covered() {
+        for code in -399..=255 {
+            if let Some(name) = get_transaction_result_name(&code) {
+                let result = TransactionResultCode::from_code(code);
+
+                assert_eq!(result.map(|result| result.to_string()), Some(name.clone()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_serde() {
+        assert_eq!(
+            serde_json::to_string(&TransactionResultCode::TecPathDry).unwrap(),
+            "\"tecPATH_DRY\""
+        );
+        assert_eq!(
+            serde_json::from_str::<TransactionResultCode>("\"tesSUCCESS\"").unwrap(),
+            TransactionResultCode::TesSuccess
+        );
+        assert_eq!(TransactionResultCode::from_code(1000), None);
+    }
+}
@@ -7,7 +7,7 @@ use crate::models::{Amount, FlagCollection, Model, NoFlags, XChainBridge, XRPAmo
 use super::{CommonFields, Memo, Signer, Transaction, TransactionType};
 
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct XChainCommit<'a> {
     #[serde(flatten)]
@@ -5,4 +5,8 @@ use thiserror_no_std::Error;
 pub enum XRPLMultisignException {
     #[error("No signers set in the transaction. Use `sign` function with `multisign = true`.")]
     NoSigners,
+    #[error(
+        "The signatures do not meet the signer list quorum ({missing} more signer weight needed)"
+    )]
+    InsufficientSignerWeight { missing: u32 },
 }
@@ -1,6 +1,7 @@
 pub mod exceptions;
 mod multisign;
 
+use alloc::{borrow::Cow, vec::Vec};
 use core::fmt::Debug;
 
 use crate::{
@@ -9,15 +10,23 @@ use crate::{
         exceptions::XRPLHelperResult,
         transaction::{
             autofill as async_autofill, autofill_and_sign as async_autofill_and_sign,
+            autofill_batch as async_autofill_batch,
             calculate_fee_per_transaction_type as async_calculate_fee_per_transaction_type,
-            sign_and_submit as async_sign_and_submit, submit as async_submit,
-            submit_and_wait as async_submit_and_wait,
+            cancel_check as async_cancel_check, cancel_escrow as async_cancel_escrow,
+            cash_check as async_cash_check, claim_payment_channel as async_claim_payment_channel,
+            create_check as async_create_check, create_escrow as async_create_escrow,
+            create_tickets as async_create_tickets, estimate_cost as async_estimate_cost,
+            finish_escrow as async_finish_escrow, sign_and_submit as async_sign_and_submit,
+            simulate as async_simulate, submit as async_submit,
+            submit_and_wait as async_submit_and_wait, submit_blob as async_submit_blob,
+            xchain_claim as async_xchain_claim, xchain_commit as async_xchain_commit,
+            xchain_create_claim_id as async_xchain_create_claim_id,
         },
     },
     models::{
-        results::{submit::Submit, tx::Tx},
+        results::{simulate::Simulate, submit::Submit, tx::Tx},
         transactions::Transaction,
-        Model, XRPAmount,
+        Amount, Model, XChainBridge, XRPAmount,
     },
     wallet::Wallet,
 };
@@ -25,7 +34,10 @@ use embassy_futures::block_on;
 use serde::{de::DeserializeOwned, Serialize};
 use strum::IntoEnumIterator;
 
-pub use crate::asynch::transaction::sign;
+pub use crate::asynch::transaction::{
+    autofill_offline, multisign_with, sign, sign_with_key, validate_xaddress_tag_consistency,
+    verify_signature, FeeOptions, FeeRounding, TransactionCost,
+};
 pub use multisign::*;
 
 pub fn sign_and_submit<'a, 'b, T, F, C>(
@@ -34,6 +46,7 @@ pub fn sign_and_submit<'a, 'b, T, F, C>(
     wallet: &Wallet,
     autofill: bool,
     check_fee: bool,
+    fail_hard: Option<bool>,
 ) -> XRPLHelperResult<Submit<'a>>
 where
     F: IntoEnumIterator + Serialize + Debug + PartialEq,
@@ -46,6 +59,7 @@ where
         wallet,
         autofill,
         check_fee,
+        fail_hard,
     ))
 }
 
@@ -62,6 +76,23 @@ where
     block_on(async_autofill(transaction, client, signers_count))
 }
 
+pub fn autofill_batch<'a, 'b, F, T, C>(
+    transactions: &mut [T],
+    client: &'b C,
+    starting_sequence: Option<u32>,
+) -> XRPLHelperResult<()>
+where
+    T: Transaction<'a, F> + Model + Clone,
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    C: XRPLAsyncClient,
+{
+    block_on(async_autofill_batch(
+        transactions,
+        client,
+        starting_sequence,
+    ))
+}
+
 pub fn autofill_and_sign<'a, 'b, T, F, C>(
     transaction: &mut T,
     client: &'b C,
@@ -81,13 +112,23 @@ where
     ))
 }
 
-pub fn submit<'a, T, F, C>(transaction: &T, client: &C) -> XRPLHelperResult<Submit<'a>>
+pub fn submit<'a, T, F, C>(
+    transaction: &T,
+    client: &C,
+    check_signature: bool,
+    fail_hard: Option<bool>,
+) -> XRPLHelperResult<Submit<'a>>
 where
     F: IntoEnumIterator + Serialize + Debug + PartialEq,
     T: Transaction<'a, F> + Model + Serialize + DeserializeOwned + Clone + Debug,
     C: XRPLAsyncClient,
 {
-    block_on(async_submit(transaction, client))
+    block_on(async_submit(
+        transaction,
+        client,
+        check_signature,
+        fail_hard,
+    ))
 }
 
 pub fn submit_and_wait<'a: 'b, 'b, T, F, C>(
@@ -115,6 +156,7 @@ pub fn calculate_fee_per_transaction_type<'a, 'b, 'c, T, F, C>(
     transaction: &T,
     client: Option<&'b C>,
     signers_count: Option<u8>,
+    options: FeeOptions<'_>,
 ) -> XRPLHelperResult<XRPAmount<'c>>
 where
     T: Transaction<'a, F>,
@@ -125,5 +167,240 @@ where
         transaction,
         client,
         signers_count,
+        options,
+    ))
+}
+
+pub fn estimate_cost<'a, 'b, T, F, C>(
+    transaction: &T,
+    client: &'b C,
+) -> XRPLHelperResult<TransactionCost<'a>>
+where
+    T: Transaction<'a, F> + Serialize,
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    C: XRPLAsyncClient,
+{
+    block_on(async_estimate_cost(transaction, client))
+}
+
+pub fn create_tickets<C>(count: u32, wallet: &Wallet, client: &C) -> XRPLHelperResult<Vec<u32>>
+where
+    C: XRPLAsyncClient,
+{
+    block_on(async_create_tickets(count, wallet, client))
+}
+
+pub fn create_check<'a, C>(
+    destination: Cow<'a, str>,
+    send_max: Amount<'a>,
+    destination_tag: Option<u32>,
+    expiration: Option<u32>,
+    invoice_id: Option<Cow<'a, str>>,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    block_on(async_create_check(
+        destination,
+        send_max,
+        destination_tag,
+        expiration,
+        invoice_id,
+        wallet,
+        client,
+    ))
+}
+
+pub fn cash_check<'a, C>(
+    check_id: Cow<'a, str>,
+    amount: Option<Amount<'a>>,
+    deliver_min: Option<Amount<'a>>,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    block_on(async_cash_check(
+        check_id,
+        amount,
+        deliver_min,
+        wallet,
+        client,
+    ))
+}
+
+pub fn cancel_check<'a, C>(
+    check_id: Cow<'a, str>,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    block_on(async_cancel_check(check_id, wallet, client))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_escrow<'a, C>(
+    amount: XRPAmount<'a>,
+    destination: Cow<'a, str>,
+    finish_after: Option<i64>,
+    cancel_after: Option<i64>,
+    condition: Option<Cow<'a, str>>,
+    destination_tag: Option<u32>,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    block_on(async_create_escrow(
+        amount,
+        destination,
+        finish_after,
+        cancel_after,
+        condition,
+        destination_tag,
+        wallet,
+        client,
+    ))
+}
+
+pub fn finish_escrow<'a, C>(
+    owner: Cow<'a, str>,
+    offer_sequence: u32,
+    condition: Option<Cow<'a, str>>,
+    fulfillment: Option<Cow<'a, str>>,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    block_on(async_finish_escrow(
+        owner,
+        offer_sequence,
+        condition,
+        fulfillment,
+        wallet,
+        client,
     ))
 }
+
+pub fn cancel_escrow<'a, C>(
+    owner: Cow<'a, str>,
+    offer_sequence: u32,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    block_on(async_cancel_escrow(owner, offer_sequence, wallet, client))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn claim_payment_channel<'a, C>(
+    channel_id: Cow<'a, str>,
+    balance: Option<Cow<'a, str>>,
+    amount: Option<Cow<'a, str>>,
+    signature: Option<Cow<'a, str>>,
+    public_key: Option<Cow<'a, str>>,
+    close: bool,
+    renew: bool,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    block_on(async_claim_payment_channel(
+        channel_id, balance, amount, signature, public_key, close, renew, wallet, client,
+    ))
+}
+
+pub fn xchain_create_claim_id<'a, C>(
+    xchain_bridge: XChainBridge<'a>,
+    signature_reward: Cow<'a, str>,
+    other_chain_source: Cow<'a, str>,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    block_on(async_xchain_create_claim_id(
+        xchain_bridge,
+        signature_reward,
+        other_chain_source,
+        wallet,
+        client,
+    ))
+}
+
+pub fn xchain_commit<'a, C>(
+    xchain_bridge: XChainBridge<'a>,
+    xchain_claim_id: Cow<'a, str>,
+    amount: Amount<'a>,
+    other_chain_destination: Option<Cow<'a, str>>,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    block_on(async_xchain_commit(
+        xchain_bridge,
+        xchain_claim_id,
+        amount,
+        other_chain_destination,
+        wallet,
+        client,
+    ))
+}
+
+pub fn xchain_claim<'a, C>(
+    xchain_bridge: XChainBridge<'a>,
+    xchain_claim_id: Cow<'a, str>,
+    amount: Amount<'a>,
+    destination: Cow<'a, str>,
+    destination_tag: Option<u32>,
+    wallet: &Wallet,
+    client: &C,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    block_on(async_xchain_claim(
+        xchain_bridge,
+        xchain_claim_id,
+        amount,
+        destination,
+        destination_tag,
+        wallet,
+        client,
+    ))
+}
+
+pub fn submit_blob<'a, C>(
+    blob: &str,
+    client: &C,
+    fail_hard: Option<bool>,
+) -> XRPLHelperResult<Submit<'a>>
+where
+    C: XRPLAsyncClient,
+{
+    block_on(async_submit_blob(blob, client, fail_hard))
+}
+
+pub fn simulate<'a, 'b, T, F, C>(transaction: &T, client: &C) -> XRPLHelperResult<Simulate<'b>>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Serialize,
+    C: XRPLAsyncClient,
+{
+    block_on(async_simulate(transaction, client))
+}
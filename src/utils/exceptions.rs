@@ -24,6 +24,8 @@ pub enum XRPLUtilsException {
     SerdeJsonError(#[from] XRPLSerdeJsonError),
     #[error("Invalid Hex error: {0}")]
     FromHexError(#[from] hex::FromHexError),
+    #[error("Memo error: {0}")]
+    MemoError(#[from] XRPLMemoException),
 }
 
 #[derive(Debug, Clone, PartialEq, Error)]
@@ -54,6 +56,8 @@ pub enum XRPRangeException {
     InvalidICPrecisionTooSmall { min: i32, found: i32 },
     #[error("Invalid Issued Currency precision too large (max: {max} found: {found})")]
     InvalidICPrecisionTooLarge { max: i32, found: i32 },
+    #[error("Invalid Issued Currency mantissa has too many significant digits (max: {max} found: {found})")]
+    InvalidICMantissaTooLong { max: u8, found: usize },
     #[error("Invalid Drops amount too large (max: {max} found: {found})")]
     InvalidDropsAmountTooLarge { max: String, found: String },
     #[error("Invalid Issued Currency serialization length (expected: {expected} found: {found})")]
@@ -77,6 +81,21 @@ pub enum ISOCodeException {
     Utf8Error,
 }
 
+#[derive(Debug, Clone, PartialEq, Error)]
+#[non_exhaustive]
+pub enum XRPLMemoException {
+    #[error("Invalid memo chunk format: {0:?}")]
+    InvalidChunkFormat(String),
+    #[error("Invalid memo chunk data (index: {index})")]
+    InvalidChunkData { index: usize },
+    #[error("Inconsistent memo chunk total (expected: {expected} found: {found})")]
+    InconsistentChunkTotal { expected: usize, found: usize },
+    #[error("Duplicate memo chunk (index: {index})")]
+    DuplicateChunk { index: usize },
+    #[error("Missing memo chunk (index: {index})")]
+    MissingChunk { index: usize },
+}
+
 impl From<core::str::Utf8Error> for ISOCodeException {
     fn from(_: core::str::Utf8Error) -> Self {
         ISOCodeException::Utf8Error
@@ -98,5 +117,8 @@ impl alloc::error::Error for XRPRangeException {}
 #[cfg(feature = "std")]
 impl alloc::error::Error for ISOCodeException {}
 
+#[cfg(feature = "std")]
+impl alloc::error::Error for XRPLMemoException {}
+
 #[cfg(feature = "std")]
 impl alloc::error::Error for XRPLUtilsException {}
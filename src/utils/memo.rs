@@ -0,0 +1,180 @@
+//! Splitting data too large for a single memo across several memos,
+//! and joining it back together.
+//!
+//! Each chunk records its position as `chunk/<index>/<total>` in the
+//! memo's `memo_format`, so the chunks can be reassembled in any order,
+//! e.g. after being read back from several transactions.
+
+use alloc::{format, vec::Vec};
+
+use crate::models::transactions::Memo;
+
+use super::exceptions::{XRPLMemoException, XRPLUtilsResult};
+
+const CHUNK_FORMAT_PREFIX: &str = "chunk/";
+
+/// Splits `data` into memos of at most `chunk_size` bytes of data each,
+/// tagged with their index so [`reassemble`] can restore the original.
+///
+/// # Panics
+///
+/// Panics if `chunk_size` is 0.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::memo::{chunk_data, reassemble};
+///
+/// let memos = chunk_data(b"Hello, XRPL!", 5, Some("text/plain"));
+///
+/// assert_eq!(memos.len(), 3);
+/// assert_eq!(reassemble(&memos).unwrap(), b"Hello, XRPL!");
+/// ```
+pub fn chunk_data(data: &[u8], chunk_size: usize, memo_type: Option<&str>) -> Vec<Memo> {
+    let chunks = data.chunks(chunk_size);
+    let total = chunks.len();
+
+    chunks
+        .enumerate()
+        .map(|(index, chunk)| Memo {
+            memo_data: Some(hex::encode_upper(chunk)),
+            memo_format: Some(hex::encode_upper(format!(
+                "{}{}/{}",
+                CHUNK_FORMAT_PREFIX, index, total
+            ))),
+            memo_type: memo_type.map(hex::encode_upper),
+        })
+        .collect()
+}
+
+/// Joins memos created by [`chunk_data`] back into the original data.
+/// The memos may be given in any order, but every chunk must be present
+/// exactly once, so the total recorded in each memo must match the
+/// number of memos.
+pub fn reassemble(memos: &[Memo]) -> XRPLUtilsResult<Vec<u8>> {
+    let mut chunks: Vec<Option<Vec<u8>>> = Vec::new();
+    chunks.resize(memos.len(), None);
+    for memo in memos {
+        let (index, total) = chunk_position(memo)?;
+        // The total comes from the memo itself, so it is checked against
+        // the memos at hand instead of being trusted for the allocation.
+        if total != memos.len() {
+            return Err(XRPLMemoException::InconsistentChunkTotal {
+                expected: memos.len(),
+                found: total,
+            }
+            .into());
+        }
+        let data = memo
+            .decoded_data()
+            .ok_or(XRPLMemoException::InvalidChunkData { index })?;
+        if chunks[index].replace(data).is_some() {
+            return Err(XRPLMemoException::DuplicateChunk { index }.into());
+        }
+    }
+
+    let mut data = Vec::new();
+    for (index, chunk) in chunks.into_iter().enumerate() {
+        data.extend(chunk.ok_or(XRPLMemoException::MissingChunk { index })?);
+    }
+
+    Ok(data)
+}
+
+/// Reads the `(index, total)` of a chunk from its `memo_format`.
+fn chunk_position(memo: &Memo) -> XRPLUtilsResult<(usize, usize)> {
+    let format = memo.decoded_format().unwrap_or_default();
+    let invalid = || XRPLMemoException::InvalidChunkFormat(format.clone());
+    let (index, total) = format
+        .strip_prefix(CHUNK_FORMAT_PREFIX)
+        .and_then(|position| position.split_once('/'))
+        .ok_or_else(invalid)?;
+    let index: usize = index.parse().map_err(|_| invalid())?;
+    let total: usize = total.parse().map_err(|_| invalid())?;
+    if index >= total {
+        return Err(invalid().into());
+    }
+
+    Ok((index, total))
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    fn payload() -> Vec<u8> {
+        (0..=255u8).cycle().take(2500).collect()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let data = payload();
+        let memos = chunk_data(&data, 1000, Some("application/octet-stream"));
+
+        assert_eq!(memos.len(), 3);
+        assert_eq!(memos[2].decoded_data().unwrap().len(), 500);
+        assert_eq!(memos[1].decoded_format().unwrap(), "chunk/1/3");
+        assert_eq!(memos[0].decoded_type().unwrap(), "application/octet-stream");
+        assert_eq!(reassemble(&memos).unwrap(), data);
+    }
+
+    #[test]
+    fn test_out_of_order() {
+        let data = payload();
+        let mut memos = chunk_data(&data, 600, None);
+        memos.reverse();
+        memos.swap(0, 2);
+
+        assert_eq!(reassemble(&memos).unwrap(), data);
+    }
+
+    #[test]
+    fn test_missing_and_duplicate_chunks() {
+        let memos = chunk_data(&payload(), 1000, None);
+
+        assert_eq!(
+            reassemble(&memos[..2]),
+            Err(XRPLMemoException::InconsistentChunkTotal {
+                expected: 2,
+                found: 3
+            }
+            .into())
+        );
+        assert_eq!(
+            reassemble(&[memos[0].clone(), memos[1].clone(), memos[1].clone()]),
+            Err(XRPLMemoException::DuplicateChunk { index: 1 }.into())
+        );
+    }
+
+    #[test]
+    fn test_huge_total() {
+        let memo = Memo {
+            memo_data: Some("00".into()),
+            memo_format: Some(hex::encode_upper(format!("chunk/0/{}", usize::MAX))),
+            memo_type: None,
+        };
+
+        assert_eq!(
+            reassemble(&[memo]),
+            Err(XRPLMemoException::InconsistentChunkTotal {
+                expected: 1,
+                found: usize::MAX
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn test_not_a_chunk() {
+        let memo = Memo::from_text("hello", None, Some("text/plain"));
+
+        assert_eq!(
+            reassemble(&[memo]),
+            Err(XRPLMemoException::InvalidChunkFormat("text/plain".into()).into())
+        );
+    }
+}
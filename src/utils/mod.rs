@@ -1,6 +1,10 @@
 //! Convenience utilities for the XRP Ledger
 
 pub mod exceptions;
+#[cfg(feature = "models")]
+pub mod memo;
+#[cfg(all(feature = "models", any(test, feature = "test-util")))]
+pub mod random;
 pub mod time_conversion;
 #[cfg(feature = "models")]
 pub(crate) mod transactions;
@@ -0,0 +1,138 @@
+//! Seedable generators of valid XRP amounts and transactions, for
+//! stress tests and fuzzing. The same seed always produces the same
+//! values.
+//!
+//! Available with the `test-util` feature.
+
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+use core::ops::RangeInclusive;
+
+use rand::{Rng, SeedableRng};
+use rand_hc::Hc128Rng;
+
+use crate::models::transactions::payment::Payment;
+use crate::models::{Amount, XRPAmount};
+use crate::utils::MAX_DROPS;
+
+/// The smallest fee, in drops, that the generator picks. This is the
+/// network's reference transaction cost.
+pub const MIN_RANDOM_FEE_DROPS: u64 = 10;
+/// The largest fee, in drops, that the generator picks.
+pub const MAX_RANDOM_FEE_DROPS: u64 = 1_000_000;
+
+/// A deterministic generator of valid XRP amounts and transaction
+/// skeletons.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::random::RandomGenerator;
+///
+/// let mut first = RandomGenerator::new(7);
+/// let mut second = RandomGenerator::new(7);
+///
+/// assert_eq!(first.xrp_amount(), second.xrp_amount());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RandomGenerator {
+    rng: Hc128Rng,
+}
+
+impl RandomGenerator {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: Hc128Rng::seed_from_u64(seed),
+        }
+    }
+
+    /// Returns an amount of at least one drop and at most the total
+    /// supply of XRP.
+    pub fn xrp_amount(&mut self) -> XRPAmount<'static> {
+        self.xrp_amount_in_range(1..=MAX_DROPS)
+    }
+
+    /// Returns an amount within `drops`, clamped to the total supply
+    /// of XRP.
+    pub fn xrp_amount_in_range(&mut self, drops: RangeInclusive<u64>) -> XRPAmount<'static> {
+        let max = (*drops.end()).min(MAX_DROPS);
+        let min = (*drops.start()).min(max);
+
+        XRPAmount::from(self.rng.gen_range(min..=max).to_string())
+    }
+
+    /// Returns a fee between [`MIN_RANDOM_FEE_DROPS`] and
+    /// [`MAX_RANDOM_FEE_DROPS`].
+    pub fn fee(&mut self) -> XRPAmount<'static> {
+        self.xrp_amount_in_range(MIN_RANDOM_FEE_DROPS..=MAX_RANDOM_FEE_DROPS)
+    }
+
+    /// Returns an XRP payment from `account` to `destination` with a
+    /// random amount, fee and destination tag. The remaining common
+    /// fields are left to be autofilled.
+    pub fn payment<'a>(&mut self, account: Cow<'a, str>, destination: Cow<'a, str>) -> Payment<'a> {
+        let fee = self.fee();
+        let amount = self.xrp_amount();
+        let destination_tag = self.rng.gen::<bool>().then(|| self.rng.gen());
+
+        Payment::new(
+            account,
+            None,
+            Some(fee),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Amount::XRPAmount(amount),
+            destination,
+            None,
+            destination_tag,
+            None,
+            None,
+            None,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::Model;
+
+    const ACCOUNT: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+    const DESTINATION: &str = "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW";
+
+    #[test]
+    fn test_xrp_amounts_are_valid() {
+        let mut generator = RandomGenerator::new(42);
+        for _ in 0..1000 {
+            assert!(generator.xrp_amount().validate().is_ok());
+            assert!(generator.fee().validate().is_ok());
+        }
+        assert_eq!(
+            generator.xrp_amount_in_range(MAX_DROPS..=u64::MAX),
+            XRPAmount::from(MAX_DROPS.to_string())
+        );
+    }
+
+    #[test]
+    fn test_payments_are_deterministic() {
+        let mut first = RandomGenerator::new(42);
+        let mut second = RandomGenerator::new(42);
+        for _ in 0..100 {
+            let payment = first.payment(ACCOUNT.into(), DESTINATION.into());
+
+            assert!(payment.validate().is_ok());
+            assert_eq!(payment, second.payment(ACCOUNT.into(), DESTINATION.into()));
+        }
+        assert_ne!(
+            RandomGenerator::new(1).xrp_amount(),
+            RandomGenerator::new(2).xrp_amount()
+        );
+    }
+}
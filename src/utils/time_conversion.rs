@@ -5,6 +5,7 @@ use crate::utils::exceptions::XRPLTimeRangeException;
 use chrono::TimeZone;
 use chrono::Utc;
 use chrono::{DateTime, LocalResult};
+use core::convert::TryFrom;
 
 use super::exceptions::XRPLUtilsResult;
 
@@ -104,6 +105,63 @@ pub fn posix_to_ripple_time(timestamp: i64) -> XRPLUtilsResult<i64> {
     _ripple_check_max(ripple_time, ripple_time)
 }
 
+/// Convert a ledger time field, in seconds since the 'Ripple Epoch',
+/// to a Unix timestamp.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::ripple_to_unix;
+///
+/// assert_eq!(ripple_to_unix(0), 946684800);
+/// ```
+pub fn ripple_to_unix(ripple_time: u32) -> i64 {
+    ripple_time as i64 + RIPPLE_EPOCH
+}
+
+/// Convert a Unix timestamp to a ledger time field, in seconds since
+/// the 'Ripple Epoch'. Fails for times before the epoch or too late to
+/// fit in the field.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::unix_to_ripple;
+///
+/// assert_eq!(unix_to_ripple(946684801), Ok(1));
+/// assert!(unix_to_ripple(946684799).is_err());
+/// ```
+pub fn unix_to_ripple(timestamp: i64) -> XRPLUtilsResult<u32> {
+    let ripple_time = timestamp
+        .checked_sub(RIPPLE_EPOCH)
+        .filter(|ripple_time| *ripple_time >= 0)
+        .ok_or(XRPLTimeRangeException::InvalidTimeBeforeEpoch {
+            min: RIPPLE_EPOCH,
+            found: timestamp,
+        })?;
+
+    u32::try_from(ripple_time).map_err(|_| {
+        XRPLTimeRangeException::UnexpectedTimeOverflow {
+            max: u32::MAX as i64,
+            found: ripple_time,
+        }
+        .into()
+    })
+}
+
+/// Convert a ledger time field, in seconds since the 'Ripple Epoch',
+/// to a UTC datetime.
+/// See [`chrono::DateTime`]
+///
+/// [`chrono::DateTime`]: mod@chrono::DateTime
+pub fn ripple_to_datetime(ripple_time: u32) -> XRPLUtilsResult<DateTime<Utc>> {
+    ripple_time_to_datetime(ripple_time as i64)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -136,6 +194,14 @@ mod test {
         assert_eq!(posix_to_ripple_time(RIPPLE_EPOCH), Ok(0_i64));
     }
 
+    #[test]
+    fn test_posix_epoch_boundary() {
+        assert_eq!(posix_to_ripple_time(RIPPLE_EPOCH), Ok(0));
+        assert!(posix_to_ripple_time(RIPPLE_EPOCH - 1).is_err());
+        assert_eq!(ripple_time_to_posix(0), Ok(RIPPLE_EPOCH));
+        assert!(ripple_time_to_posix(-1).is_err());
+    }
+
     #[test]
     fn accept_posix_round_trip() {
         let current_time: i64 = Utc::now().timestamp();
@@ -220,4 +286,46 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_ripple_to_unix() {
+        assert_eq!(ripple_to_unix(0), RIPPLE_EPOCH);
+        // 2023-11-14T22:13:20Z
+        assert_eq!(ripple_to_unix(753_315_200), 1_700_000_000);
+        assert_eq!(ripple_to_unix(u32::MAX), RIPPLE_EPOCH + u32::MAX as i64);
+    }
+
+    #[test]
+    fn test_unix_to_ripple() {
+        assert_eq!(unix_to_ripple(RIPPLE_EPOCH), Ok(0));
+        assert_eq!(unix_to_ripple(1_700_000_000), Ok(753_315_200));
+        assert_eq!(
+            unix_to_ripple(RIPPLE_EPOCH - 1),
+            Err(XRPLTimeRangeException::InvalidTimeBeforeEpoch {
+                min: RIPPLE_EPOCH,
+                found: RIPPLE_EPOCH - 1,
+            }
+            .into())
+        );
+        assert!(unix_to_ripple(RIPPLE_EPOCH + u32::MAX as i64 + 1).is_err());
+        assert_eq!(
+            unix_to_ripple(i64::MIN),
+            Err(XRPLTimeRangeException::InvalidTimeBeforeEpoch {
+                min: RIPPLE_EPOCH,
+                found: i64::MIN,
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn test_ripple_to_datetime() -> XRPLUtilsResult<()> {
+        let expected = match Utc.with_ymd_and_hms(2023, 11, 14, 22, 13, 20) {
+            LocalResult::Single(dt) => dt,
+            _ => return Err(XRPLTimeRangeException::InvalidLocalTime.into()),
+        };
+        assert_eq!(Ok(expected), ripple_to_datetime(753_315_200));
+
+        Ok(())
+    }
 }
@@ -9,4 +9,19 @@ pub type XRPLWalletResult<T, E = XRPLWalletException> = core::result::Result<T,
 pub enum XRPLWalletException {
     #[error("XRPL Core error: {0}")]
     XRPLCoreError(#[from] XRPLCoreException),
+    #[cfg(feature = "mnemonic")]
+    #[error("Invalid mnemonic length (expected 12, 15, 18, 21 or 24 words, found {0})")]
+    InvalidMnemonicLength(usize),
+    #[cfg(feature = "mnemonic")]
+    #[error("The word at position {0} of the mnemonic is not in the BIP-39 English wordlist")]
+    UnknownMnemonicWord(usize),
+    #[cfg(feature = "mnemonic")]
+    #[error("Invalid mnemonic checksum")]
+    InvalidMnemonicChecksum,
+    #[cfg(feature = "mnemonic")]
+    #[error("The mnemonic passphrase must be ASCII")]
+    NonAsciiPassphrase,
+    #[cfg(feature = "mnemonic")]
+    #[error("The derived key is invalid")]
+    InvalidDerivedKey,
 }
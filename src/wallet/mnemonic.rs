@@ -0,0 +1,251 @@
+//! Wallets derived from BIP-39 mnemonics.
+
+use alloc::{string::String, vec::Vec};
+use hmac::{Hmac, Mac};
+use secp256k1::{PublicKey, Scalar, Secp256k1, SecretKey};
+use sha2::{Digest, Sha256, Sha512};
+use zeroize::Zeroize;
+
+use super::{
+    exceptions::{XRPLWalletException, XRPLWalletResult},
+    Secret, Wallet,
+};
+use crate::core::keypairs::derive_classic_address;
+
+/// The BIP-39 English wordlist, one word per line.
+static WORDLIST: &str = include_str!("bip39_english.txt");
+const PBKDF2_ROUNDS: u32 = 2048;
+const BIP32_SEED_KEY: &[u8] = b"Bitcoin seed";
+const HARDENED: u32 = 0x80000000;
+/// The BIP-44 coin type of the XRP Ledger.
+const XRPL_COIN_TYPE: u32 = 144;
+
+type HmacSha512 = Hmac<Sha512>;
+
+impl Wallet {
+    /// Creates the Wallet of a BIP-39 mnemonic, derived with secp256k1
+    /// keys along the path `m/44'/144'/{account_index}'/0/0` used by
+    /// hardware wallets and most XRPL wallets.
+    ///
+    /// The mnemonic must use the English wordlist and have a valid
+    /// checksum. The passphrase, which may be empty, must be ASCII. The
+    /// resulting wallet has no XRPL seed, so its `seed` is empty.
+    ///
+    /// Available with the `mnemonic` feature.
+    pub fn from_mnemonic(
+        phrase: &str,
+        passphrase: &str,
+        account_index: u32,
+    ) -> XRPLWalletResult<Self> {
+        if !passphrase.is_ascii() {
+            return Err(XRPLWalletException::NonAsciiPassphrase);
+        }
+        let words: Vec<&str> = phrase.split_whitespace().collect();
+        validate_mnemonic(&words)?;
+        let mut normalized = words.join(" ");
+        let mut seed = mnemonic_to_seed(&normalized, passphrase);
+        normalized.zeroize();
+
+        let path = [
+            44 | HARDENED,
+            XRPL_COIN_TYPE | HARDENED,
+            account_index | HARDENED,
+            0,
+            0,
+        ];
+        let derived = derive_path(&seed, &path);
+        seed.zeroize();
+        let private_key = derived?;
+        let public_key = PublicKey::from_secret_key(&Secp256k1::new(), &private_key);
+        let public_key = hex::encode_upper(public_key.serialize());
+        let classic_address = derive_classic_address(&public_key)?;
+
+        Ok(Wallet {
+            seed: Secret::from(String::new()),
+            public_key,
+            private_key: Secret::from(alloc::format!(
+                "00{}",
+                hex::encode_upper(private_key.secret_bytes())
+            )),
+            classic_address,
+            sequence: 0,
+        })
+    }
+}
+
+/// Checks the length, the words and the checksum of a mnemonic.
+fn validate_mnemonic(words: &[&str]) -> XRPLWalletResult<()> {
+    if ![12, 15, 18, 21, 24].contains(&words.len()) {
+        return Err(XRPLWalletException::InvalidMnemonicLength(words.len()));
+    }
+    // Each word encodes 11 bits: the entropy followed by a checksum of
+    // one bit per 32 bits of entropy.
+    let mut bits = Vec::with_capacity(words.len() * 11);
+    for (position, word) in words.iter().enumerate() {
+        let index = WORDLIST
+            .lines()
+            .position(|candidate| candidate == *word)
+            .ok_or(XRPLWalletException::UnknownMnemonicWord(position + 1))?;
+        bits.extend((0..11).rev().map(|bit| (index >> bit) & 1 == 1));
+    }
+    let checksum_length = bits.len() / 33;
+    let (entropy_bits, checksum_bits) = bits.split_at(bits.len() - checksum_length);
+    let mut entropy: Vec<u8> = entropy_bits
+        .chunks(8)
+        .map(|byte| byte.iter().fold(0, |acc, bit| acc << 1 | *bit as u8))
+        .collect();
+    let hash = Sha256::digest(&entropy);
+    entropy.zeroize();
+    let is_valid = checksum_bits
+        .iter()
+        .enumerate()
+        .all(|(i, bit)| (hash[i / 8] >> (7 - i % 8)) & 1 == *bit as u8);
+    bits.zeroize();
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(XRPLWalletException::InvalidMnemonicChecksum)
+    }
+}
+
+/// Stretches a mnemonic into a 64 byte seed with PBKDF2-HMAC-SHA512.
+/// The output is a single block, so only the first block is computed.
+fn mnemonic_to_seed(mnemonic: &str, passphrase: &str) -> [u8; 64] {
+    let mac = HmacSha512::new_from_slice(mnemonic.as_bytes()).expect("any key length is valid");
+    let mut salted = mac.clone();
+    salted.update(b"mnemonic");
+    salted.update(passphrase.as_bytes());
+    salted.update(&1u32.to_be_bytes());
+    let mut block: [u8; 64] = salted.finalize().into_bytes().into();
+    let mut seed = block;
+    for _ in 1..PBKDF2_ROUNDS {
+        let mut round = mac.clone();
+        round.update(&block);
+        block = round.finalize().into_bytes().into();
+        seed.iter_mut().zip(block.iter()).for_each(|(s, b)| *s ^= b);
+    }
+    block.zeroize();
+
+    seed
+}
+
+/// Derives the BIP-32 private key at `path` from a seed.
+fn derive_path(seed: &[u8], path: &[u32]) -> XRPLWalletResult<SecretKey> {
+    let (mut key, mut chain_code) = split_key(hmac_sha512(BIP32_SEED_KEY, &[seed]))?;
+    let secp = Secp256k1::new();
+    for index in path {
+        let index_bytes = index.to_be_bytes();
+        let output = if index & HARDENED != 0 {
+            hmac_sha512(&chain_code, &[&[0], &key.secret_bytes(), &index_bytes])
+        } else {
+            let public_key = PublicKey::from_secret_key(&secp, &key).serialize();
+            hmac_sha512(&chain_code, &[&public_key, &index_bytes])
+        };
+        let (tweak, next_chain_code) = split_key(output)?;
+        key = key
+            .add_tweak(&Scalar::from(tweak))
+            .map_err(|_| XRPLWalletException::InvalidDerivedKey)?;
+        chain_code.zeroize();
+        chain_code = next_chain_code;
+    }
+    chain_code.zeroize();
+
+    Ok(key)
+}
+
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> [u8; 64] {
+    let mut mac = HmacSha512::new_from_slice(key).expect("any key length is valid");
+    for part in data {
+        mac.update(part);
+    }
+
+    mac.finalize().into_bytes().into()
+}
+
+/// Splits an HMAC output into a key and a chain code.
+fn split_key(mut output: [u8; 64]) -> XRPLWalletResult<(SecretKey, [u8; 32])> {
+    let key =
+        SecretKey::from_slice(&output[..32]).map_err(|_| XRPLWalletException::InvalidDerivedKey);
+    let mut chain_code = [0; 32];
+    chain_code.copy_from_slice(&output[32..]);
+    output.zeroize();
+
+    Ok((key?, chain_code))
+}
+
+#[cfg(test)]
+mod test {
+    use sha2::{Digest, Sha256};
+
+    use super::{derive_path, mnemonic_to_seed, HARDENED, WORDLIST};
+    use crate::wallet::{exceptions::XRPLWalletException, Wallet};
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon \
+        abandon abandon abandon about";
+
+    #[test]
+    fn test_wordlist() {
+        assert_eq!(WORDLIST.lines().count(), 2048);
+        assert_eq!(
+            hex::encode(Sha256::digest(WORDLIST)),
+            "2f5eed53a4727b4bf8880d8f3f199efc90e58503646d9ff8eff3a2ed3b24dbda"
+        );
+    }
+
+    /// The first BIP-39 test vector.
+    #[test]
+    fn test_mnemonic_to_seed() {
+        assert_eq!(
+            hex::encode(mnemonic_to_seed(MNEMONIC, "TREZOR")),
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+        );
+    }
+
+    /// The first BIP-32 test vector.
+    #[test]
+    fn test_derive_path() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let key = derive_path(&seed, &[HARDENED, 1, 2 | HARDENED, 2, 1000000000]).unwrap();
+
+        assert_eq!(
+            hex::encode(key.secret_bytes()),
+            "471b76e389e528d6de6d816857e012c5455051cad6660850e58372a6c3e6e7c8"
+        );
+    }
+
+    #[test]
+    fn test_from_mnemonic() {
+        let wallet = Wallet::from_mnemonic(MNEMONIC, "", 0).unwrap();
+
+        assert_eq!(wallet.classic_address, "rHsMGQEkVNJmpGWs8XUBoTBiAAbwxZN5v3");
+        assert_eq!(wallet.private_key.len(), 66);
+        assert!(wallet.seed.is_empty());
+        assert_ne!(
+            Wallet::from_mnemonic(MNEMONIC, "", 1)
+                .unwrap()
+                .classic_address,
+            wallet.classic_address
+        );
+    }
+
+    #[test]
+    fn test_invalid_mnemonics() {
+        assert_eq!(
+            Wallet::from_mnemonic("abandon abandon abandon", "", 0).unwrap_err(),
+            XRPLWalletException::InvalidMnemonicLength(3)
+        );
+        assert_eq!(
+            Wallet::from_mnemonic(&MNEMONIC.replace("about", "abandon"), "", 0).unwrap_err(),
+            XRPLWalletException::InvalidMnemonicChecksum
+        );
+        assert_eq!(
+            Wallet::from_mnemonic(&MNEMONIC.replace("about", "xrpl"), "", 0).unwrap_err(),
+            XRPLWalletException::UnknownMnemonicWord(12)
+        );
+        assert_eq!(
+            Wallet::from_mnemonic(MNEMONIC, "passé", 0).unwrap_err(),
+            XRPLWalletException::NonAsciiPassphrase
+        );
+    }
+}
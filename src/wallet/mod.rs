@@ -3,6 +3,8 @@
 pub mod exceptions;
 #[cfg(feature = "helpers")]
 pub mod faucet_generation;
+#[cfg(feature = "mnemonic")]
+mod mnemonic;
 
 use crate::constants::CryptoAlgorithm;
 use crate::core::addresscodec::classic_address_to_xaddress;
@@ -12,18 +14,22 @@ use crate::core::keypairs::generate_seed;
 use alloc::string::String;
 use core::fmt::Display;
 use exceptions::XRPLWalletResult;
+use subtle::ConstantTimeEq;
 use zeroize::Zeroize;
 
+/// A secret field of a [`Wallet`]. Its buffer is cleared when it is
+/// dropped, including for clones taken out of the wallet.
+pub type Secret = zeroize::Zeroizing<String>;
+
 /// The cryptographic keys needed to control an
 /// XRP Ledger account.
 ///
 /// See Cryptographic Keys:
 /// `<https://xrpl.org/cryptographic-keys.html>`
-#[derive(Debug)]
 pub struct Wallet {
     /// The seed from which the public and private keys
     /// are derived.
-    pub seed: String,
+    pub seed: Secret,
     /// The public key that is used to identify this wallet's
     /// signatures, as a hexadecimal string.
     pub public_key: String,
@@ -31,7 +37,7 @@ pub struct Wallet {
     /// a hexadecimal string. MUST be kept secret!
     ///
     /// TODO Use seckey
-    pub private_key: String,
+    pub private_key: Secret,
     /// The address that publicly identifies this wallet, as
     /// a base58 string.
     pub classic_address: String,
@@ -43,9 +49,13 @@ pub struct Wallet {
     pub sequence: u64,
 }
 
-// Zeroize the memory where sensitive data is stored.
-impl Drop for Wallet {
-    fn drop(&mut self) {
+/// Zeroize the memory where sensitive data is stored.
+///
+/// Only the buffers currently owned by the wallet are cleared. Copies
+/// made elsewhere, such as formatted strings, `&str` views of the keys
+/// or buffers left behind when a field was reallocated, are not.
+impl Zeroize for Wallet {
+    fn zeroize(&mut self) {
         self.seed.zeroize();
         self.public_key.zeroize();
         self.private_key.zeroize();
@@ -54,6 +64,13 @@ impl Drop for Wallet {
     }
 }
 
+// Zeroize the memory where sensitive data is stored.
+impl Drop for Wallet {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
 impl Wallet {
     /// Generate a new Wallet.
     pub fn new(seed: &str, sequence: u64) -> XRPLWalletResult<Self> {
@@ -61,9 +78,9 @@ impl Wallet {
         let classic_address = derive_classic_address(&public_key)?;
 
         Ok(Wallet {
-            seed: seed.into(),
+            seed: Secret::from(String::from(seed)),
             public_key,
-            private_key,
+            private_key: Secret::from(private_key),
             classic_address,
             sequence,
         })
@@ -74,6 +91,23 @@ impl Wallet {
         Self::new(&generate_seed(None, crypto_algorithm)?, 0)
     }
 
+    /// Returns whether `private_key` is this wallet's private key.
+    ///
+    /// The keys are compared in constant time so the comparison doesn't
+    /// leak how much of the key matched.
+    pub fn private_key_eq(&self, private_key: &str) -> bool {
+        bool::from(self.private_key.as_bytes().ct_eq(private_key.as_bytes()))
+    }
+
+    /// Returns a view of the wallet's keys that borrows them.
+    pub fn signing_key(&self) -> SigningKey<'_> {
+        SigningKey {
+            classic_address: &self.classic_address,
+            public_key: &self.public_key,
+            private_key: &self.private_key,
+        }
+    }
+
     /// Returns the X-Address of the Wallet's account.
     pub fn get_xaddress(
         &self,
@@ -88,6 +122,47 @@ impl Wallet {
     }
 }
 
+/// A borrowed view of a [`Wallet`]'s keys, for signing without
+/// cloning them.
+#[derive(Clone, Copy)]
+pub struct SigningKey<'a> {
+    /// The address of the signing account, as a base58 string.
+    pub classic_address: &'a str,
+    /// The public key, as a hexadecimal string.
+    pub public_key: &'a str,
+    /// The private key, as a hexadecimal string.
+    pub private_key: &'a str,
+}
+
+impl<'a> From<&'a Wallet> for SigningKey<'a> {
+    fn from(wallet: &'a Wallet) -> Self {
+        wallet.signing_key()
+    }
+}
+
+impl core::fmt::Debug for SigningKey<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("SigningKey")
+            .field("classic_address", &self.classic_address)
+            .field("public_key", &self.public_key)
+            .field("private_key", &"-HIDDEN-")
+            .finish()
+    }
+}
+
+impl core::fmt::Debug for Wallet {
+    /// Formats the wallet with its seed and private key hidden.
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("Wallet")
+            .field("seed", &"-HIDDEN-")
+            .field("public_key", &self.public_key)
+            .field("private_key", &"-HIDDEN-")
+            .field("classic_address", &self.classic_address)
+            .field("sequence", &self.sequence)
+            .finish()
+    }
+}
+
 impl Display for Wallet {
     /// Returns a string representation of a Wallet.
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
@@ -98,3 +173,59 @@ impl Display for Wallet {
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use zeroize::Zeroize;
+
+    use alloc::format;
+
+    use super::{SigningKey, Wallet};
+
+    const SEED: &str = "sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5";
+
+    #[test]
+    fn test_private_key_eq() {
+        let wallet = Wallet::new(SEED, 0).unwrap();
+        let other = Wallet::create(None).unwrap();
+
+        assert!(wallet.private_key_eq(&wallet.private_key.clone()));
+        assert!(!wallet.private_key_eq(&other.private_key));
+        assert!(!wallet.private_key_eq(""));
+    }
+
+    #[test]
+    fn test_zeroize() {
+        let mut wallet = Wallet::new(SEED, 5).unwrap();
+        wallet.zeroize();
+
+        assert!(wallet.seed.is_empty());
+        assert!(wallet.private_key.is_empty());
+        assert!(wallet.public_key.is_empty());
+        assert!(wallet.classic_address.is_empty());
+        assert_eq!(wallet.sequence, 0);
+    }
+
+    #[test]
+    fn test_signing_key() {
+        let wallet = Wallet::new(SEED, 0).unwrap();
+        let signing_key = SigningKey::from(&wallet);
+
+        assert_eq!(signing_key.classic_address, wallet.classic_address);
+        assert_eq!(signing_key.public_key, wallet.public_key);
+        assert!(wallet.private_key_eq(signing_key.private_key));
+        assert!(!format!("{:?}", signing_key).contains(wallet.private_key.as_str()));
+    }
+
+    #[test]
+    fn test_debug_hides_secrets() {
+        let wallet = Wallet::new(SEED, 0).unwrap();
+
+        for output in [format!("{:?}", wallet), format!("{:#?}", wallet)] {
+            assert!(!output.contains(SEED));
+            assert!(!output.contains(wallet.private_key.as_str()));
+            assert!(output.contains(&wallet.classic_address));
+        }
+        assert!(!format!("{}", wallet).contains(SEED));
+    }
+}
@@ -0,0 +1,46 @@
+//! Checks that a dropped `Wallet` clears its private key. The test
+//! installs its own global allocator, so it lives in its own binary.
+#![cfg(all(feature = "std", feature = "wallet"))]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use xrpl::wallet::Wallet;
+
+const SEED: &str = "sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5";
+
+/// Records whether the buffer at `WATCHED` was zeroed when it was freed.
+struct WatchingAllocator;
+
+static WATCHED: AtomicUsize = AtomicUsize::new(0);
+static ZEROED: AtomicBool = AtomicBool::new(false);
+
+unsafe impl GlobalAlloc for WatchingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if WATCHED
+            .compare_exchange(ptr as usize, 0, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+        {
+            let buffer = core::slice::from_raw_parts(ptr, layout.size());
+            ZEROED.store(buffer.iter().all(|byte| *byte == 0), Ordering::SeqCst);
+        }
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: WatchingAllocator = WatchingAllocator;
+
+#[test]
+fn test_drop_zeroes_private_key() {
+    let wallet = Wallet::new(SEED, 0).unwrap();
+    WATCHED.store(wallet.private_key.as_ptr() as usize, Ordering::SeqCst);
+    drop(wallet);
+
+    assert_eq!(WATCHED.load(Ordering::SeqCst), 0);
+    assert!(ZEROED.load(Ordering::SeqCst));
+}